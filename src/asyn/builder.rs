@@ -0,0 +1,297 @@
+use crate::{
+    asyn::{
+        transport::{CachingTransport, ReqwestTransport, Transport},
+        Result, TraktApi,
+    },
+    cache::Cache,
+    middleware::{Middleware, MiddlewareChain},
+    oauth::oauth_url_of,
+    rate_limit::{RateLimitConfig, RateLimiter},
+    retry::RetryPolicy,
+};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, USER_AGENT},
+    Client, Proxy,
+};
+use std::{sync::Arc, time::Duration};
+
+/// A builder to configure a [TraktApi] and its HTTP client
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use trakt::asyn::TraktApiBuilder;
+///
+/// let api = TraktApiBuilder::new("CLIENT_ID".to_owned())
+///     .client_secret("CLIENT_SECRET".to_owned())
+///     .user_agent("my-app/1.0.0")
+///     .timeout(Duration::from_secs(10))
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// [TraktApi]: struct.TraktApi.html
+#[derive(Debug)]
+pub struct TraktApiBuilder {
+    base_url: String,
+    oauth_url: Option<String>,
+    client_id: String,
+    client_secret: Option<String>,
+    client: Option<Client>,
+    transport: Option<Arc<dyn Transport>>,
+    user_agent: Option<String>,
+    default_headers: HeaderMap,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    max_idle_per_host: Option<usize>,
+    rate_limit: RateLimitConfig,
+    retry_policy: RetryPolicy,
+    cache: Option<Cache>,
+    middleware: MiddlewareChain,
+}
+
+impl TraktApiBuilder {
+    /// Creates a builder for the production environment. Client ID is needed.
+    pub fn new(client_id: String) -> Self {
+        Self {
+            base_url: "https://api.trakt.tv".to_owned(),
+            oauth_url: None,
+            client_id,
+            client_secret: None,
+            client: None,
+            transport: None,
+            user_agent: None,
+            default_headers: HeaderMap::new(),
+            timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            max_idle_per_host: None,
+            rate_limit: RateLimitConfig::default(),
+            retry_policy: RetryPolicy::default(),
+            cache: None,
+            middleware: MiddlewareChain::default(),
+        }
+    }
+
+    /// Switches to the staging environment
+    pub fn staging(self) -> Self {
+        self.base_url("https://api-staging.trakt.tv")
+    }
+
+    /// Sets the url of the api
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        self
+    }
+
+    /// Sets the url of the website users are sent to for authorization.
+    /// Defaults to the website of the api url, e.g. `https://trakt.tv` for `https://api.trakt.tv`.
+    pub fn oauth_url(mut self, oauth_url: &str) -> Self {
+        self.oauth_url = Some(oauth_url.to_owned());
+        self
+    }
+
+    /// Sets the client secret, needed for authorization
+    pub fn client_secret(mut self, client_secret: String) -> Self {
+        self.client_secret = Some(client_secret);
+        self
+    }
+
+    /// Uses an already configured [reqwest::Client].
+    /// The user agent, default headers, timeouts, proxy and pool settings are ignored then.
+    ///
+    /// [reqwest::Client]: ../../reqwest/struct.Client.html
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sends all requests over the given [Transport]. All settings of the HTTP client are
+    /// ignored then.
+    ///
+    /// [Transport]: transport/trait.Transport.html
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Sets the `User-Agent` header. Defaults to `trakt-rust/<version>`.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets headers which are sent with every request
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Sets the timeout of a whole request including reading the response
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for connecting to the server
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sends all requests through a proxy
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets the maximum number of idle connections per host kept in the pool
+    pub fn max_idle_per_host(mut self, max: usize) -> Self {
+        self.max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Sets how requests that hit the rate limit are handled
+    pub fn rate_limit(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limit = config;
+        self
+    }
+
+    /// Sets which requests are retried after transient errors
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Caches the responses of read-only endpoints
+    pub fn cache(mut self, cache: Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Adds a [Middleware] which is called around every request
+    ///
+    /// [Middleware]: ../middleware/trait.Middleware.html
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Builds the [TraktApi]
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidHeader] if the user agent is not a valid header value
+    ///
+    /// Returns [Error::Connection] if the HTTP client could not be built
+    ///
+    /// [TraktApi]: struct.TraktApi.html
+    /// [Error::InvalidHeader]: ../error/enum.Error.html#variant.InvalidHeader
+    /// [Error::Connection]: ../error/enum.Error.html#variant.Connection
+    pub fn build(self) -> Result<TraktApi> {
+        let transport: Arc<dyn Transport> = match (self.transport, self.client) {
+            (Some(transport), _) => transport,
+            (None, Some(client)) => Arc::new(ReqwestTransport::new(client)),
+            (None, None) => {
+                let mut headers = self.default_headers;
+                let user_agent = self.user_agent.unwrap_or_else(|| {
+                    concat!("trakt-rust/", env!("CARGO_PKG_VERSION")).to_owned()
+                });
+                headers.insert(USER_AGENT, HeaderValue::from_str(&user_agent)?);
+
+                let mut builder = Client::builder().default_headers(headers);
+
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                if let Some(max) = self.max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(max);
+                }
+
+                Arc::new(ReqwestTransport::new(builder.build()?))
+            }
+        };
+
+        let base_url = &self.base_url;
+        let cache = self.cache.map(|cache| cache.base_url(base_url));
+        let transport: Arc<dyn Transport> = match &cache {
+            Some(cache) => Arc::new(CachingTransport::new(transport, cache.clone())),
+            None => transport,
+        };
+
+        let oauth_url = match self.oauth_url {
+            Some(oauth_url) => oauth_url,
+            None => oauth_url_of(&self.base_url),
+        };
+
+        Ok(TraktApi {
+            base_url: Arc::from(self.base_url),
+            oauth_url: Arc::from(oauth_url),
+            transport,
+            client_id: Arc::from(self.client_id),
+            client_secret: self.client_secret.map(Arc::from),
+            rate_limiter: RateLimiter::new(self.rate_limit),
+            retry_policy: self.retry_policy,
+            cache,
+            middleware: self.middleware,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        asyn::{TraktApi, TraktApiBuilder},
+        error::Error,
+        models::MediaType,
+        tests::mock,
+    };
+    use mockito::server_url;
+
+    #[test]
+    fn build() {
+        assert_eq!(
+            TraktApiBuilder::new("abc".to_owned())
+                .client_secret("def".to_owned())
+                .build()
+                .unwrap(),
+            TraktApi::new("abc".to_owned(), Some("def".to_owned()))
+        );
+        assert_eq!(
+            TraktApiBuilder::new("abc".to_owned())
+                .staging()
+                .build()
+                .unwrap(),
+            TraktApi::staging("abc".to_owned(), None)
+        );
+    }
+
+    #[tokio::test]
+    async fn user_agent() -> Result<(), Error> {
+        let m = mock("GET", "/genres/movies", "CLIENT_ID")
+            .match_header("User-Agent", "my-app/1.0.0")
+            .with_status(200)
+            .with_body_from_file("mock_data/genres_movies.json")
+            .create();
+
+        let url = server_url();
+        let api = TraktApiBuilder::new("CLIENT_ID".to_owned())
+            .base_url(&url)
+            .user_agent("my-app/1.0.0")
+            .build()?;
+
+        let genres = api.genres(MediaType::Movies).await?.len();
+
+        assert!(genres > 0);
+        m.assert();
+        Ok(())
+    }
+}
@@ -1,42 +1,93 @@
 use crate::{
-    asyn::{Result, TraktApi},
+    asyn::{Result, Session, TraktApi},
     error::Error,
-    pagination::Pagination,
+    pagination::{Page, PageCursor, Pagination},
+    request::ApiRequest,
+    transport::HttpRequest,
 };
-use reqwest::r#async::RequestBuilder;
+use futures::{future::BoxFuture, Stream};
 use serde::de::DeserializeOwned;
-use std::marker::PhantomData;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    vec,
+};
 
 /// A simple implementation of [Pagination]
 ///
 /// [Pagination]: ../trait.Pagination.html
-pub struct PaginationRequest<'a, T> {
-    client: &'a TraktApi<'a>,
-    request: RequestBuilder,
-    response_type: PhantomData<T>,
+pub struct PaginationRequest<T> {
+    client: PageClient,
+    request: ApiRequest<Vec<T>>,
 }
 
-impl<'a, T: DeserializeOwned + Send + 'static> PaginationRequest<'a, T> {
-    pub fn new(client: &'a TraktApi, request: RequestBuilder) -> Self {
+impl<T: DeserializeOwned + Send + 'static> PaginationRequest<T> {
+    pub fn new(client: &TraktApi, request: ApiRequest<Vec<T>>) -> Self {
         Self {
-            client,
+            client: PageClient::Api(client.clone()),
             request,
-            response_type: PhantomData,
         }
     }
 
-    fn apply(self, f: impl FnOnce(RequestBuilder) -> RequestBuilder) -> Self {
+    /// Creates a request whose pages are each sent with a valid access token of `session`
+    pub(crate) fn with_session(session: &Session, request: ApiRequest<Vec<T>>) -> Self {
         Self {
-            client: self.client,
-            request: f(self.request),
-            response_type: PhantomData,
+            client: PageClient::Session(session.clone()),
+            request,
+        }
+    }
+
+    pub async fn execute(self) -> Result<Vec<T>> {
+        match self.client {
+            PageClient::Api(api) => api.execute_request(self.request).await,
+            PageClient::Session(session) => session.execute_request(self.request).await,
         }
     }
 
-    pub fn execute(self) -> Result<Vec<T>> {
-        match self.request.build() {
-            Ok(req) => self.client.execute(req),
-            Err(e) => Box::new(futures::future::err(Error::from(e))),
+    /// Executes the request and returns the items together with the pagination headers
+    pub async fn execute_page(self) -> Result<Page<T>> {
+        match self.client {
+            PageClient::Api(api) => api.execute_request_page(self.request).await,
+            PageClient::Session(session) => session.execute_request_page(self.request).await,
+        }
+    }
+
+    /// Returns a stream of the items of all pages, starting at the requested page.
+    /// Pages are fetched lazily.
+    pub fn into_stream_all(self) -> PaginationStream<T> {
+        let request = self.client.api().request(&self.request);
+        PaginationStream::new(self.client, request)
+    }
+}
+
+/// The client the pages of a paginated request are sent with
+#[derive(Clone)]
+enum PageClient {
+    Api(TraktApi),
+    /// Every page gets the current access token of the session and is sent once more with a
+    /// refreshed one if it is rejected
+    Session(Session),
+}
+
+impl PageClient {
+    fn api(&self) -> &TraktApi {
+        match self {
+            PageClient::Api(api) => api,
+            PageClient::Session(session) => session.client(),
+        }
+    }
+
+    async fn execute_page<T: DeserializeOwned>(self, request: HttpRequest) -> Result<Page<T>> {
+        match self {
+            PageClient::Api(api) => api.execute_page(request).await,
+            PageClient::Session(session) => {
+                let request = &request;
+                session
+                    .call(|api, access_token| async move {
+                        api.execute_page(request.clone().auth(&access_token)?).await
+                    })
+                    .await
+            }
         }
     }
 }
@@ -45,14 +96,121 @@ impl<'a, T: DeserializeOwned + Send + 'static> PaginationRequest<'a, T> {
 ///
 /// [Pagination]: ../trait.Pagination.html
 /// [PaginationFactory]: struct.PaginationFactory.html
-impl<'a, T: DeserializeOwned + Send + 'static> Pagination for PaginationRequest<'a, T> {
+impl<T: DeserializeOwned + Send + 'static> Pagination for PaginationRequest<T> {
     /// Set requested page
-    fn page(self, page: u32) -> Self {
-        self.apply(|b| b.query(&[("page", page)]))
+    fn page(mut self, page: u32) -> Self {
+        self.request = self.request.query("page", page);
+        self
     }
 
     /// Set number of requested entries
-    fn limit(self, limit: u32) -> Self {
-        self.apply(|b| b.query(&[("limit", limit)]))
+    fn limit(mut self, limit: u32) -> Self {
+        self.request = self.request.query("limit", limit);
+        self
+    }
+}
+
+/// A [Stream] of the items of all pages of a paginated request.
+/// The next page is only fetched after all items of the previous one have been consumed.
+///
+/// [Stream]: https://docs.rs/futures/0.3/futures/stream/trait.Stream.html
+pub struct PaginationStream<T> {
+    client: PageClient,
+    request: Option<HttpRequest>,
+    error: Option<Error>,
+    pending: Option<BoxFuture<'static, Result<Page<T>>>>,
+    items: vec::IntoIter<T>,
+    cursor: PageCursor,
+}
+
+impl<T: DeserializeOwned + Send + 'static> PaginationStream<T> {
+    fn new(client: PageClient, request: Result<HttpRequest>) -> Self {
+        let (request, error, cursor) = match request {
+            Ok(request) => {
+                let cursor = PageCursor::new(&request.url);
+                (Some(request), None, cursor)
+            }
+            Err(e) => (None, Some(e), PageCursor::empty()),
+        };
+
+        Self {
+            client,
+            request,
+            error,
+            pending: None,
+            items: Vec::new().into_iter(),
+            cursor,
+        }
+    }
+
+    /// Stops after the given number of pages
+    pub fn max_pages(mut self, max_pages: u32) -> Self {
+        self.cursor.max_pages(max_pages);
+        self
+    }
+
+    /// Stops after the given number of items
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.cursor.max_items(max_items);
+        self
+    }
+}
+
+// The stream is never pinned structurally, the pending page is boxed
+impl<T> Unpin for PaginationStream<T> {}
+
+impl<T: DeserializeOwned + Send + 'static> Stream for PaginationStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Result<T>>> {
+        let this = &mut *self;
+
+        if let Some(e) = this.error.take() {
+            return Poll::Ready(Some(Err(e)));
+        }
+
+        loop {
+            if let Some(item) = this.items.next() {
+                return if this.cursor.take_item() {
+                    Poll::Ready(Some(Ok(item)))
+                } else {
+                    Poll::Ready(None)
+                };
+            }
+
+            if let Some(pending) = &mut this.pending {
+                match pending.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(page)) => {
+                        this.pending = None;
+                        this.cursor.fetched(&page);
+                        this.items = page.items.into_iter();
+                        continue;
+                    }
+                    Poll::Ready(Err(e)) => {
+                        this.pending = None;
+                        this.cursor.failed();
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+            }
+
+            let template = match &this.request {
+                Some(request) => request,
+                None => return Poll::Ready(None),
+            };
+
+            match this.cursor.next_url(&template.url) {
+                Some(url) => {
+                    let mut request = template.clone();
+                    request.url = url;
+
+                    let client = this.client.clone();
+                    this.pending =
+                        Some(Box::pin(async move { client.execute_page(request).await }));
+                }
+                None => return Poll::Ready(None),
+            }
+        }
     }
 }
@@ -1,14 +0,0 @@
-pub mod authentication;
-pub mod calendar;
-pub mod checkin;
-pub mod comments;
-pub mod episodes;
-pub mod lists;
-pub mod movies;
-pub mod people;
-pub mod recommendations;
-pub mod search;
-pub mod seasons;
-pub mod shows;
-pub mod sync;
-pub mod users;
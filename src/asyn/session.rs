@@ -0,0 +1,513 @@
+use crate::{
+    asyn::{pagination::PaginationRequest, Result, TraktApi},
+    error::{ApiErrorKind, Error},
+    models::AuthenticationTokenResponse,
+    pagination::Page,
+    request::{self, ApiRequest},
+    token_store::TokenStore,
+};
+use chrono::{DateTime, Utc};
+use futures::{channel::oneshot, future::BoxFuture};
+use serde::de::DeserializeOwned;
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// An authenticated session of a user.
+///
+/// It holds the OAuth tokens of the user and passes the access token to every request.
+/// The access token is refreshed shortly before it expires and once after a request was
+/// rejected with a 401 response. Clones share the tokens and concurrent requests wait for
+/// a running refresh instead of starting another one.
+/// Refreshed tokens are saved in the [TokenStore] of the session, if it has one.
+///
+/// Endpoints and request builders which require authentication, like [user_settings] or
+/// [checkin], are only methods of the session. Endpoints which accept it optionally, like
+/// [user_profile], are sent with the access token by the session and without one by the
+/// [TraktApi]. Other requests can be sent with [call].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use trakt::{
+///     asyn::TraktApi,
+///     selectors::{SelectIds, SelectMovie},
+/// };
+///
+/// # async fn run() {
+/// let api = TraktApi::new("CLIENT_ID".to_owned(), Some("CLIENT_SECRET".to_owned()));
+/// let redirect_uri = "urn:ietf:wg:oauth:2.0:oob";
+/// let token = api.oauth_get_token("CODE", redirect_uri).await.unwrap();
+///
+/// let session = api.session(token, redirect_uri);
+/// dbg!(session.user_settings().await.unwrap());
+/// dbg!(session
+///     .checkin()
+///     .movie(|m| m.slug("warcraft-2016"))
+///     .execute()
+///     .await
+///     .unwrap());
+/// # }
+/// ```
+///
+/// [TokenStore]: ../../token_store/trait.TokenStore.html
+/// [user_settings]: #method.user_settings
+/// [checkin]: #method.checkin
+/// [user_profile]: #method.user_profile
+/// [TraktApi]: ../struct.TraktApi.html
+/// [call]: #method.call
+#[derive(Debug, Clone)]
+pub struct Session {
+    client: TraktApi,
+    redirect_uri: Arc<str>,
+    state: Arc<Mutex<TokenState>>,
+    store: Option<Arc<dyn TokenStore>>,
+    refresh_margin: Duration,
+}
+
+#[derive(Debug)]
+struct TokenState {
+    token: AuthenticationTokenResponse,
+    /// Requests waiting for the running refresh, None if there is none
+    waiting: Option<Vec<oneshot::Sender<String>>>,
+}
+
+/// Ends a running refresh when it is dropped. Waiting requests are woken up
+/// and use their old access token if the refresh failed.
+struct Refreshing(Arc<Mutex<TokenState>>);
+
+impl Drop for Refreshing {
+    fn drop(&mut self) {
+        self.0.lock().unwrap().waiting = None;
+    }
+}
+
+impl Session {
+    /// Creates a session from a token response. The redirect uri has to be the one the token
+    /// was requested with.
+    pub fn new(client: TraktApi, token: AuthenticationTokenResponse, redirect_uri: &str) -> Self {
+        Self {
+            client,
+            redirect_uri: Arc::from(redirect_uri),
+            state: Arc::new(Mutex::new(TokenState {
+                token,
+                waiting: None,
+            })),
+            store: None,
+            refresh_margin: Duration::from_secs(60 * 60),
+        }
+    }
+
+    /// Creates a session from the token saved in `store`. Returns None if there is none.
+    /// Refreshed tokens are saved in `store`.
+    pub fn from_store(
+        client: TraktApi,
+        store: impl TokenStore + 'static,
+        redirect_uri: &str,
+    ) -> Result<Option<Self>> {
+        Ok(store
+            .load()?
+            .map(|token| Self::new(client, token, redirect_uri).token_store(store)))
+    }
+
+    /// Saves every refreshed token in `store`. The current token is not saved.
+    pub fn token_store(mut self, store: impl TokenStore + 'static) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+
+    /// Sets how long before its expiry the access token is refreshed. Defaults to one hour.
+    pub fn refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    /// The client the requests are sent with
+    pub fn client(&self) -> &TraktApi {
+        &self.client
+    }
+
+    /// The current token
+    pub fn token(&self) -> AuthenticationTokenResponse {
+        self.state.lock().unwrap().token.clone()
+    }
+
+    /// The time at which the current access token expires
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.state.lock().unwrap().token.expires_at()
+    }
+
+    /// Returns a valid access token. It is refreshed first if it is about to expire.
+    pub async fn access_token(&self) -> Result<String> {
+        let access_token = {
+            let state = self.state.lock().unwrap();
+
+            if !state.token.expires_within(self.refresh_margin) {
+                return Ok(state.token.access_token.clone());
+            }
+
+            state.token.access_token.clone()
+        };
+
+        self.refresh_rejected(access_token).await
+    }
+
+    /// Refreshes the access token
+    pub async fn refresh(&self) -> Result<()> {
+        let access_token = self.state.lock().unwrap().token.access_token.clone();
+
+        self.refresh_rejected(access_token).await.map(|_| ())
+    }
+
+    /// Revokes the access token and removes it from the token store
+    pub async fn revoke(&self) -> Result<()> {
+        let access_token = self.state.lock().unwrap().token.access_token.clone();
+        self.client.oauth_revoke_token(&access_token).await?;
+
+        match &self.store {
+            Some(store) => store.clear(),
+            None => Ok(()),
+        }
+    }
+
+    /// Refreshes the access token after `rejected` was rejected,
+    /// unless another request replaced it in the meantime or is already refreshing it
+    async fn refresh_rejected(&self, rejected: String) -> Result<String> {
+        let refresh_token = {
+            let mut state = self.state.lock().unwrap();
+
+            if state.token.access_token != rejected {
+                return Ok(state.token.access_token.clone());
+            }
+
+            match &mut state.waiting {
+                Some(waiting) => {
+                    let (tx, rx) = oneshot::channel();
+                    waiting.push(tx);
+                    Err(rx)
+                }
+                None => {
+                    state.waiting = Some(Vec::new());
+                    Ok(state.token.refresh_token.clone())
+                }
+            }
+        };
+
+        let refresh_token = match refresh_token {
+            Ok(refresh_token) => refresh_token,
+            Err(rx) => return Ok(rx.await.unwrap_or(rejected)),
+        };
+
+        let refreshing = Refreshing(self.state.clone());
+        let token = self
+            .client
+            .oauth_refresh_token(&refresh_token, &self.redirect_uri)
+            .await?;
+
+        let mut state = refreshing.0.lock().unwrap();
+        let access_token = token.access_token.clone();
+        state.token = token;
+
+        for tx in state.waiting.take().unwrap_or_default() {
+            let _ = tx.send(access_token.clone());
+        }
+
+        match &self.store {
+            Some(store) => store.save(&state.token).map(|_| access_token),
+            None => Ok(access_token),
+        }
+    }
+
+    /// Calls `f` with the client and a valid access token.
+    /// If the request is rejected with a 401 response, the token is refreshed and `f` is called
+    /// once more.
+    pub async fn call<T, F, Fut>(&self, f: F) -> Result<T>
+    where
+        F: Fn(TraktApi, String) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let access_token = self.access_token().await?;
+
+        match f(self.client.clone(), access_token.clone()).await {
+            Err(Error::Response(ref e)) if e.kind == ApiErrorKind::Unauthorized => {
+                let access_token = self.refresh_rejected(access_token).await?;
+                f(self.client.clone(), access_token).await
+            }
+            res => res,
+        }
+    }
+}
+
+/// Executes the requests of the endpoints which need an access token
+impl Session {
+    /// Executes an [ApiRequest] with the access token of the session
+    ///
+    /// [ApiRequest]: ../../request/struct.ApiRequest.html
+    pub(crate) async fn execute_request<T: DeserializeOwned>(
+        &self,
+        request: ApiRequest<T>,
+    ) -> Result<T> {
+        let request = &request;
+        self.call(|api, access_token| async move {
+            api.execute_request(request.clone().auth(&access_token))
+                .await
+        })
+        .await
+    }
+
+    /// Executes an [ApiRequest] of a paginated endpoint with the access token of the session
+    ///
+    /// [ApiRequest]: ../../request/struct.ApiRequest.html
+    pub(crate) async fn execute_request_page<T: DeserializeOwned>(
+        &self,
+        request: ApiRequest<Vec<T>>,
+    ) -> Result<Page<T>> {
+        let request = &request;
+        self.call(|api, access_token| async move {
+            api.execute_request_page(request.clone().auth(&access_token))
+                .await
+        })
+        .await
+    }
+
+    /// Executes an [ApiRequest] with the access token of the session and ignores the body of
+    /// the response
+    ///
+    /// [ApiRequest]: ../../request/struct.ApiRequest.html
+    pub(crate) async fn execute_request_no_body(&self, request: ApiRequest<()>) -> Result<()> {
+        let request = &request;
+        self.call(|api, access_token| async move {
+            api.execute_request_no_body(request.clone().auth(&access_token))
+                .await
+        })
+        .await
+    }
+
+    /// Creates a [PaginationRequest] whose pages are each sent with a valid access token
+    /// of the session
+    ///
+    /// [PaginationRequest]: ../pagination/struct.PaginationRequest.html
+    pub(crate) async fn paginate<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest<Vec<T>>,
+    ) -> Result<PaginationRequest<T>> {
+        Ok(PaginationRequest::with_session(self, request))
+    }
+}
+
+impl request::Client for Session {
+    type Output<T> = BoxFuture<'static, Result<T>>;
+
+    fn run<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest<T>,
+    ) -> BoxFuture<'static, Result<T>> {
+        let session = self.clone();
+        Box::pin(async move { session.execute_request(request).await })
+    }
+
+    fn run_page<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest<Vec<T>>,
+    ) -> BoxFuture<'static, Result<Page<T>>> {
+        let session = self.clone();
+        Box::pin(async move { session.execute_request_page(request).await })
+    }
+}
+
+impl TraktApi {
+    /// Creates a [Session] which passes the access token of `token` to the requests
+    /// and refreshes it when needed
+    ///
+    /// [Session]: struct.Session.html
+    pub fn session(&self, token: AuthenticationTokenResponse, redirect_uri: &str) -> Session {
+        Session::new(self.clone(), token, redirect_uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        asyn::{Session, TraktApi},
+        error::{ApiErrorKind, Error},
+        models::AuthenticationTokenResponse,
+        pagination::Pagination,
+        tests::mock,
+        token_store::{MemoryTokenStore, TokenStore},
+    };
+    use futures::TryStreamExt;
+    use mockito::{server_url, Matcher};
+    use std::{
+        fs,
+        sync::Arc,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    const NEW_TOKEN: &str = "dbaf9757982a9e738f05d249b7b5b4a266b3a139049317c4909f2f263572c781";
+
+    fn token(created_at: u64) -> AuthenticationTokenResponse {
+        AuthenticationTokenResponse {
+            access_token: "ACCESS_TOKEN".to_owned(),
+            token_type: "bearer".to_owned(),
+            expires_in: 7200,
+            refresh_token: "REFRESH_TOKEN".to_owned(),
+            scope: "public".to_owned(),
+            created_at,
+        }
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    fn refresh_mock() -> mockito::Mock {
+        mock("POST", "/oauth/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_refresh_token_req.json").unwrap(),
+            ))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+    }
+
+    fn api(url: &str) -> TraktApi {
+        TraktApi::with_url(
+            url,
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+    }
+
+    #[tokio::test]
+    async fn refresh_before_expiry() {
+        let refresh = refresh_mock().expect(1).create();
+        let movies = mock("GET", "/recommendations/movies", "CLIENT_ID")
+            .match_header("Authorization", format!("Bearer {}", NEW_TOKEN).as_str())
+            .with_status(200)
+            .with_body_from_file("mock_data/movies.json")
+            .create();
+        let shows = mock("GET", "/recommendations/shows", "CLIENT_ID")
+            .match_header("Authorization", format!("Bearer {}", NEW_TOKEN).as_str())
+            .with_status(200)
+            .with_body_from_file("mock_data/shows.json")
+            .create();
+
+        let url = server_url();
+        let session = api(&url).session(token(now() - 7000), "http://localhost:8080/auth");
+
+        let (movies_res, shows_res) = futures::join!(
+            session.recommendations_movie(),
+            session.recommendations_show()
+        );
+        let (movies_res, shows_res) = (movies_res.unwrap(), shows_res.unwrap());
+
+        assert!(!movies_res.is_empty());
+        assert!(!shows_res.is_empty());
+        refresh.assert();
+        movies.assert();
+        shows.assert();
+    }
+
+    #[tokio::test]
+    async fn refresh_after_unauthorized() {
+        let refresh = refresh_mock().expect(1).create();
+        let rejected = mock("GET", "/sync/last_activities", "CLIENT_ID")
+            .match_header("Authorization", "Bearer ACCESS_TOKEN")
+            .with_status(401)
+            .expect(1)
+            .create();
+        let m = mock("GET", "/sync/last_activities", "CLIENT_ID")
+            .match_header("Authorization", format!("Bearer {}", NEW_TOKEN).as_str())
+            .with_status(401)
+            .expect(1)
+            .create();
+
+        let url = server_url();
+        let session = api(&url).session(token(now()), "http://localhost:8080/auth");
+
+        match session.sync_last_activities().await {
+            Err(Error::Response(e)) => assert_eq!(e.kind, ApiErrorKind::Unauthorized),
+            res => panic!("expected an api error, got {:?}", res),
+        }
+
+        refresh.assert();
+        rejected.assert();
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn refresh_between_pages() {
+        let refresh = refresh_mock().expect(1).create();
+        let page = |page: u32, access_token: &str| {
+            mock(
+                "GET",
+                &format!("/lists/123/items?limit=5&page={}", page),
+                "CLIENT_ID",
+            )
+            .match_header("Authorization", format!("Bearer {}", access_token).as_str())
+            .with_header("X-Pagination-Page", &page.to_string())
+            .with_header("X-Pagination-Limit", "5")
+            .with_header("X-Pagination-Page-Count", "2")
+            .with_header("X-Pagination-Item-Count", "10")
+        };
+        let first = page(1, "ACCESS_TOKEN")
+            .with_status(200)
+            .with_body_from_file("mock_data/user_list_items.json")
+            .expect(1)
+            .create();
+        let rejected = page(2, "ACCESS_TOKEN").with_status(401).expect(1).create();
+        let second = page(2, NEW_TOKEN)
+            .with_status(200)
+            .with_body_from_file("mock_data/user_list_items.json")
+            .expect(1)
+            .create();
+
+        let url = server_url();
+        let session = api(&url).session(token(now()), "http://localhost:8080/auth");
+
+        let items: Vec<_> = session
+            .list_items(123, None)
+            .await
+            .unwrap()
+            .limit(5)
+            .into_stream_all()
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert!(!items.is_empty());
+        assert_eq!(session.token().access_token, NEW_TOKEN);
+        refresh.assert();
+        first.assert();
+        rejected.assert();
+        second.assert();
+    }
+
+    #[tokio::test]
+    async fn save_refreshed_token() {
+        let refresh = refresh_mock().expect(1).create();
+        let m = mock("GET", "/recommendations/movies", "CLIENT_ID")
+            .match_header("Authorization", format!("Bearer {}", NEW_TOKEN).as_str())
+            .with_status(200)
+            .with_body_from_file("mock_data/movies.json")
+            .create();
+
+        let url = server_url();
+        let store = Arc::new(MemoryTokenStore::new(Some(token(now() - 7000))));
+        let session =
+            Session::from_store(api(&url), Arc::clone(&store), "http://localhost:8080/auth")
+                .unwrap()
+                .unwrap();
+
+        session.recommendations_movie().await.unwrap();
+
+        assert_eq!(store.load().unwrap(), Some(session.token()));
+        assert_eq!(session.token().access_token, NEW_TOKEN);
+        refresh.assert();
+        m.assert();
+    }
+}
@@ -0,0 +1,805 @@
+//! Caching of responses of read-only endpoints
+//!
+//! Metadata like movies, shows, people or genres rarely changes. A [Cache] keeps the
+//! responses of those endpoints for a while and answers repeated requests without the
+//! network. Once an entry expired, it is revalidated with `If-None-Match` or
+//! `If-Modified-Since` if trakt sent an `ETag` or `Last-Modified` header.
+//!
+//! Only GET requests of endpoints with a TTL are cached. Requests with an access token
+//! bypass the cache unless [Cache::authenticated] is enabled.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use trakt::{cache::Cache, TraktApi};
+//!
+//! let cache = Cache::memory(1000).ttl("/movies/*/people", Duration::from_secs(86400));
+//! let api = TraktApi::new("CLIENT_ID".to_owned(), None).with_cache(cache);
+//! ```
+//!
+//! [Cache]: struct.Cache.html
+//! [Cache::authenticated]: struct.Cache.html#method.authenticated
+use crate::{
+    error::Error,
+    transport::{HttpRequest, HttpResponse},
+};
+use reqwest::{
+    header::{
+        HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH,
+        LAST_MODIFIED,
+    },
+    Method, StatusCode, Url,
+};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const HOUR: Duration = Duration::from_secs(60 * 60);
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A cached response together with the time it was received or last revalidated
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheEntry {
+    pub response: HttpResponse,
+    pub stored_at: SystemTime,
+}
+
+impl CacheEntry {
+    pub fn new(response: HttpResponse) -> Self {
+        Self {
+            response,
+            stored_at: SystemTime::now(),
+        }
+    }
+
+    /// Time since the response was received or last revalidated
+    pub fn age(&self) -> Duration {
+        self.stored_at.elapsed().unwrap_or_default()
+    }
+}
+
+/// A place to keep cached responses.
+///
+/// Errors of a store never fail a request, the response is fetched from the api then.
+pub trait CacheStore: fmt::Debug + Send + Sync {
+    /// Returns the entry of the key or None if there is none
+    fn get(&self, key: &str) -> Result<Option<CacheEntry>, Error>;
+
+    /// Saves the entry, replacing the one of the same key
+    fn put(&self, key: &str, entry: &CacheEntry) -> Result<(), Error>;
+}
+
+/// Keeps the entries in memory and drops the least recently used one once it is full
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    state: Mutex<LruState>,
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    /// Incremented on every access to find the least recently used entry
+    clock: u64,
+    entries: HashMap<String, (CacheEntry, u64)>,
+}
+
+impl MemoryCache {
+    /// Creates a cache keeping at most `capacity` responses
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    /// Number of cached responses
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &str) -> Result<Option<CacheEntry>, Error> {
+        let mut state = self.state.lock().unwrap();
+        state.clock += 1;
+        let clock = state.clock;
+
+        Ok(state.entries.get_mut(key).map(|(entry, used)| {
+            *used = clock;
+            entry.clone()
+        }))
+    }
+
+    fn put(&self, key: &str, entry: &CacheEntry) -> Result<(), Error> {
+        if self.capacity == 0 {
+            return Ok(());
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.clock += 1;
+        let clock = state.clock;
+
+        if !state.entries.contains_key(key) && state.entries.len() >= self.capacity {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+
+            if let Some(oldest) = oldest {
+                state.entries.remove(&oldest);
+            }
+        }
+
+        state.entries.insert(key.to_owned(), (entry.clone(), clock));
+        Ok(())
+    }
+}
+
+/// The metadata of an entry saved by the [DiskCache]
+///
+/// [DiskCache]: struct.DiskCache.html
+#[derive(Debug, Serialize, Deserialize)]
+struct DiskEntry {
+    status: u16,
+    headers: Vec<(String, String)>,
+    /// Seconds since the unix epoch
+    stored_at: u64,
+}
+
+/// Saves every entry as a file in a directory, so it outlives the process.
+///
+/// A file contains a line of JSON metadata followed by the response body.
+/// Entries are never removed, clear the directory to free the space.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    /// Creates a cache in `dir`. The directory is created on the first write.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The directory the entries are saved in
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(hex(&Sha256::digest(key.as_bytes())))
+    }
+}
+
+impl CacheStore for DiskCache {
+    fn get(&self, key: &str) -> Result<Option<CacheEntry>, Error> {
+        let file = match File::open(self.path(key)) {
+            Ok(file) => file,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(Error::from(e)),
+        };
+
+        let mut reader = BufReader::new(file);
+        let mut meta = String::new();
+        reader.read_line(&mut meta)?;
+        let meta: DiskEntry = serde_json::from_str(&meta)?;
+
+        let mut body = Vec::new();
+        reader.read_to_end(&mut body)?;
+
+        let status = match StatusCode::from_u16(meta.status) {
+            Ok(status) => status,
+            Err(_) => return Ok(None),
+        };
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in meta.headers {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(&value),
+            ) {
+                headers.append(name, value);
+            }
+        }
+
+        Ok(Some(CacheEntry {
+            response: HttpResponse {
+                status,
+                headers,
+                body,
+            },
+            stored_at: UNIX_EPOCH + Duration::from_secs(meta.stored_at),
+        }))
+    }
+
+    fn put(&self, key: &str, entry: &CacheEntry) -> Result<(), Error> {
+        let meta = DiskEntry {
+            status: entry.response.status.as_u16(),
+            headers: entry
+                .response
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.as_str().to_owned(), value.to_str().ok()?.to_owned()))
+                })
+                .collect(),
+            stored_at: entry
+                .stored_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        };
+
+        fs::create_dir_all(&self.dir)?;
+
+        // Written to a temporary file first so a reader never sees half an entry
+        let path = self.path(key);
+        let temp_path = path.with_extension("tmp");
+        let mut file = File::create(&temp_path)?;
+        serde_json::to_writer(&mut file, &meta)?;
+        file.write_all(b"\n")?;
+        file.write_all(&entry.response.body)?;
+        file.sync_all()?;
+
+        fs::rename(&temp_path, &path)?;
+        Ok(())
+    }
+}
+
+impl<T: CacheStore + ?Sized> CacheStore for Arc<T> {
+    fn get(&self, key: &str) -> Result<Option<CacheEntry>, Error> {
+        (**self).get(key)
+    }
+
+    fn put(&self, key: &str, entry: &CacheEntry) -> Result<(), Error> {
+        (**self).put(key, entry)
+    }
+}
+
+/// The TTL of the endpoints matching a route
+#[derive(Debug, Clone, PartialEq)]
+struct TtlRule {
+    segments: Vec<String>,
+    ttl: Duration,
+}
+
+impl TtlRule {
+    fn new(route: &str, ttl: Duration) -> Self {
+        Self {
+            segments: split_path(route).map(str::to_owned).collect(),
+            ttl,
+        }
+    }
+
+    /// The number of literal segments if the path matches, rules with more of them win
+    fn specificity(&self, path: &[&str]) -> Option<usize> {
+        if self.segments.len() != path.len() {
+            return None;
+        }
+
+        let mut literals = 0;
+        for (segment, part) in self.segments.iter().zip(path) {
+            if segment != "*" {
+                if segment != part {
+                    return None;
+                }
+                literals += 1;
+            }
+        }
+
+        Some(literals)
+    }
+}
+
+/// Configures which responses are cached, for how long and where.
+///
+/// By default these endpoints are cached:
+///
+/// - genres, certifications, countries, languages and networks for a day
+/// - the summaries, people, seasons and episodes of movies and shows and the summaries of
+///   people for an hour
+///
+/// Lists like trending or popular movies and the recently updated movies, shows and people
+/// are not cached.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use trakt::cache::Cache;
+///
+/// let cache = Cache::disk("/tmp/trakt-cache")
+///     // `*` matches a single segment of the path
+///     .ttl("/shows/*/seasons", Duration::from_secs(6 * 60 * 60))
+///     // a TTL of zero disables caching
+///     .ttl("/people/*", Duration::from_secs(0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cache {
+    store: Arc<dyn CacheStore>,
+    rules: Vec<TtlRule>,
+    authenticated: bool,
+    base_path: String,
+}
+
+impl Cache {
+    /// Creates a cache with the default TTLs saving the responses in `store`
+    pub fn new(store: impl CacheStore + 'static) -> Self {
+        let mut cache = Self {
+            store: Arc::new(store),
+            rules: Vec::new(),
+            authenticated: false,
+            base_path: String::new(),
+        };
+
+        for route in &[
+            "/certifications/*",
+            "/countries/*",
+            "/genres/*",
+            "/languages/*",
+            "/networks",
+        ] {
+            cache = cache.ttl(route, DAY);
+        }
+
+        for route in &[
+            "/movies/*",
+            "/movies/*/people",
+            "/shows/*",
+            "/shows/*/people",
+            "/shows/*/seasons",
+            "/shows/*/seasons/*",
+            "/shows/*/seasons/*/episodes/*",
+            "/people/*",
+        ] {
+            cache = cache.ttl(route, HOUR);
+        }
+
+        for list in &[
+            "trending",
+            "popular",
+            "recommended",
+            "played",
+            "watched",
+            "collected",
+            "anticipated",
+            "boxoffice",
+            "favorited",
+            "streaming",
+            "updates",
+        ] {
+            cache = cache
+                .ttl(&format!("/movies/{}", list), Duration::from_secs(0))
+                .ttl(&format!("/shows/{}", list), Duration::from_secs(0));
+        }
+
+        cache.ttl("/people/updates", Duration::from_secs(0))
+    }
+
+    /// Creates a cache with the default TTLs keeping at most `capacity` responses in memory
+    pub fn memory(capacity: usize) -> Self {
+        Self::new(MemoryCache::new(capacity))
+    }
+
+    /// Creates a cache with the default TTLs saving the responses in the directory `dir`
+    pub fn disk(dir: impl Into<PathBuf>) -> Self {
+        Self::new(DiskCache::new(dir))
+    }
+
+    /// Sets the TTL of the endpoints matching `route`, e.g. `/movies/*`. Each `*` matches a
+    /// single segment. If several routes match, the one with the most literal segments wins.
+    /// A TTL of zero disables caching for the route.
+    pub fn ttl(mut self, route: &str, ttl: Duration) -> Self {
+        let rule = TtlRule::new(route, ttl);
+        self.rules.retain(|r| r.segments != rule.segments);
+        self.rules.push(rule);
+        self
+    }
+
+    /// Whether requests with an access token are cached too. Defaults to false.
+    ///
+    /// Their entries are only used for requests with the same access token.
+    pub fn authenticated(mut self, authenticated: bool) -> Self {
+        self.authenticated = authenticated;
+        self
+    }
+
+    /// The base url of the client. The TTL rules are matched against the path below it.
+    ///
+    /// It is set by [TraktApi::with_cache] and only needs to be set when creating a
+    /// [CachingTransport] yourself.
+    ///
+    /// [TraktApi::with_cache]: ../struct.TraktApi.html#method.with_cache
+    /// [CachingTransport]: ../sync/transport/struct.CachingTransport.html
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_path = Url::parse(base_url)
+            .map(|url| url.path().trim_end_matches('/').to_owned())
+            .unwrap_or_default();
+        self
+    }
+
+    /// The TTL of the responses of an endpoint. None if they are not cached.
+    pub fn ttl_of(&self, path: &str) -> Option<Duration> {
+        let path: Vec<&str> = split_path(path).collect();
+
+        self.rules
+            .iter()
+            .filter_map(|rule| Some((rule.specificity(&path)?, rule.ttl)))
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, ttl)| ttl)
+            .filter(|ttl| *ttl > Duration::from_secs(0))
+    }
+
+    /// The path of `url` below the base url
+    fn route<'a>(&self, url: &'a Url) -> &'a str {
+        let path = url.path();
+
+        match path.strip_prefix(self.base_path.as_str()) {
+            Some(route) if route.starts_with('/') => route,
+            _ => path,
+        }
+    }
+
+    /// The key of a request in the store or None if it bypasses the cache
+    fn key(&self, request: &HttpRequest) -> Option<String> {
+        if request.method != Method::GET {
+            return None;
+        }
+
+        let url = sorted_query(&request.url);
+
+        match request.headers.get(AUTHORIZATION) {
+            None => Some(format!("{} {}", request.method, url)),
+            Some(token) if self.authenticated => Some(format!(
+                "{} {} {}",
+                request.method,
+                url,
+                hex(&Sha256::digest(token.as_bytes()))
+            )),
+            Some(_) => None,
+        }
+    }
+
+    /// Answers a request from the cache or prepares it to be sent
+    pub(crate) fn lookup(&self, mut request: HttpRequest) -> Lookup {
+        let (key, ttl) = match (self.key(&request), self.ttl_of(self.route(&request.url))) {
+            (Some(key), Some(ttl)) => (key, ttl),
+            _ => return Lookup::Bypass(request),
+        };
+
+        let stale = match self.store.get(&key) {
+            Ok(Some(entry)) if entry.age() < ttl => return Lookup::Hit(entry.response),
+            Ok(Some(entry)) => {
+                let headers = &entry.response.headers;
+                let validators = [(ETAG, IF_NONE_MATCH), (LAST_MODIFIED, IF_MODIFIED_SINCE)];
+
+                let mut conditional = false;
+                for (validator, condition) in validators.iter() {
+                    if let Some(value) = headers.get(validator) {
+                        request.headers.insert(condition, value.clone());
+                        conditional = true;
+                    }
+                }
+
+                if conditional {
+                    Some(entry)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
+        Lookup::Miss(request, Pending { key, stale })
+    }
+
+    /// Saves the response of a request that missed the cache. A `304 Not Modified` response
+    /// is replaced by the revalidated entry.
+    pub(crate) fn update(&self, pending: Pending, mut response: HttpResponse) -> HttpResponse {
+        match pending.stale {
+            Some(mut entry) if response.status == StatusCode::NOT_MODIFIED => {
+                entry.stored_at = SystemTime::now();
+                let _ = self.store.put(&pending.key, &entry);
+
+                // the rate limit is tracked with the headers of the actual response
+                if let Some(limit) = response.headers.remove(RATE_LIMIT) {
+                    entry.response.headers.insert(RATE_LIMIT, limit);
+                }
+                entry.response
+            }
+            _ if response.status == StatusCode::OK => {
+                let mut entry = CacheEntry::new(response.clone());
+                entry.response.headers.remove(RATE_LIMIT);
+                let _ = self.store.put(&pending.key, &entry);
+                response
+            }
+            _ => response,
+        }
+    }
+}
+
+const RATE_LIMIT: &str = "X-Ratelimit";
+
+/// The result of looking up a request in the [Cache]
+///
+/// [Cache]: struct.Cache.html
+#[derive(Debug)]
+pub(crate) enum Lookup {
+    /// A fresh response was cached
+    Hit(HttpResponse),
+    /// The request has to be sent, possibly with conditional headers. Its response is
+    /// passed to [Cache::update].
+    ///
+    /// [Cache::update]: struct.Cache.html#method.update
+    Miss(HttpRequest, Pending),
+    /// The request is not cached
+    Bypass(HttpRequest),
+}
+
+/// A request which missed the [Cache]
+///
+/// [Cache]: struct.Cache.html
+#[derive(Debug)]
+pub(crate) struct Pending {
+    key: String,
+    /// The expired entry if it is being revalidated
+    stale: Option<CacheEntry>,
+}
+
+fn split_path(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+/// The url with its query pairs sorted, so the order they were added in doesn't matter
+fn sorted_query(url: &Url) -> Url {
+    let mut pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    if pairs.is_empty() {
+        return url.clone();
+    }
+    pairs.sort();
+
+    let mut url = url.clone();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    url
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cache, CacheEntry, CacheStore, DiskCache, Lookup, MemoryCache};
+    use crate::{
+        request::ApiRequest,
+        route::Route,
+        transport::{HttpRequest, HttpResponse},
+    };
+    use reqwest::{
+        header::{HeaderValue, ETAG, IF_NONE_MATCH},
+        StatusCode, Url,
+    };
+    use std::{fs, sync::Arc, time::Duration};
+
+    fn request(path: &'static str, access_token: Option<&str>) -> HttpRequest {
+        let req: ApiRequest<()> = ApiRequest::get(Route::new(path)).optional_auth(access_token);
+        HttpRequest::new("https://api.trakt.tv", "CLIENT_ID", &req).unwrap()
+    }
+
+    fn response(etag: Option<&'static str>) -> HttpResponse {
+        let mut res = HttpResponse::new(StatusCode::OK, b"[]".to_vec());
+        if let Some(etag) = etag {
+            res.headers.insert(ETAG, HeaderValue::from_static(etag));
+        }
+        res.headers
+            .insert("X-Ratelimit", HeaderValue::from_static("{}"));
+        res
+    }
+
+    #[test]
+    fn ttl_of() {
+        let hour = Duration::from_secs(60 * 60);
+        let cache = Cache::memory(10).ttl("/movies/*/people", Duration::from_secs(10));
+
+        assert_eq!(cache.ttl_of("/genres/movies"), Some(hour * 24));
+        assert_eq!(cache.ttl_of("/movies/tron-legacy-2010"), Some(hour));
+        assert_eq!(
+            cache.ttl_of("/movies/tron-legacy-2010/people"),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(cache.ttl_of("/movies/trending"), None);
+        assert_eq!(cache.ttl_of("/movies/tron-legacy-2010/ratings"), None);
+        assert_eq!(cache.ttl_of("/sync/history"), None);
+        assert_eq!(cache.ttl_of("/people/bryan-cranston"), Some(hour));
+
+        let cache = cache.ttl("/genres/*", Duration::from_secs(0));
+        assert_eq!(cache.ttl_of("/genres/movies"), None);
+    }
+
+    #[test]
+    fn ttl_of_collections() {
+        let cache = Cache::memory(10);
+
+        for route in &[
+            "/movies/updates",
+            "/movies/updates/2020-01-01",
+            "/movies/favorited",
+            "/movies/streaming",
+            "/shows/updates",
+            "/shows/favorited/weekly",
+            "/people/updates",
+        ] {
+            assert_eq!(cache.ttl_of(route), None, "{}", route);
+        }
+    }
+
+    #[test]
+    fn key_ignores_query_order() {
+        let cache = Cache::memory(10);
+        let request = |query: &[(&str, &str)]| {
+            let mut req: ApiRequest<()> = ApiRequest::get(Route::new("/movies/tron-legacy-2010"));
+            for (key, value) in query {
+                req = req.query(key, value);
+            }
+            HttpRequest::new("https://api.trakt.tv", "CLIENT_ID", &req).unwrap()
+        };
+
+        assert_eq!(
+            cache.key(&request(&[("extended", "full"), ("page", "2")])),
+            cache.key(&request(&[("page", "2"), ("extended", "full")]))
+        );
+        assert_ne!(
+            cache.key(&request(&[("page", "2")])),
+            cache.key(&request(&[("page", "3")]))
+        );
+    }
+
+    #[test]
+    fn memory_cache() {
+        let store = MemoryCache::new(2);
+        let entry = CacheEntry::new(response(None));
+
+        store.put("a", &entry).unwrap();
+        store.put("b", &entry).unwrap();
+        store.get("a").unwrap();
+        store.put("c", &entry).unwrap();
+
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get("a").unwrap(), Some(entry.clone()));
+        assert_eq!(store.get("b").unwrap(), None);
+        assert_eq!(store.get("c").unwrap(), Some(entry));
+    }
+
+    #[test]
+    fn disk_cache() {
+        let dir = std::env::temp_dir().join(format!("trakt-cache-{}", std::process::id()));
+        let store = DiskCache::new(&dir);
+        let entry = CacheEntry::new(response(Some("\"abc\"")));
+
+        assert_eq!(store.get("a").unwrap(), None);
+        store.put("a", &entry).unwrap();
+
+        let saved = store.get("a").unwrap().unwrap();
+        assert_eq!(saved.response, entry.response);
+        assert_eq!(saved.age().as_secs(), 0);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn base_url() {
+        let req: ApiRequest<()> = ApiRequest::get(Route::new("/genres/movies"));
+        let req = HttpRequest::new("https://example.com/trakt", "CLIENT_ID", &req).unwrap();
+
+        let cache = Cache::memory(10).base_url("https://example.com/trakt/");
+        assert_eq!(cache.route(&req.url), "/genres/movies");
+        assert!(matches!(cache.lookup(req.clone()), Lookup::Miss(..)));
+        assert!(matches!(Cache::memory(10).lookup(req), Lookup::Bypass(_)));
+
+        let other = Url::parse("https://example.com/trakt-proxy/genres/movies").unwrap();
+        assert_eq!(cache.route(&other), "/trakt-proxy/genres/movies");
+    }
+
+    #[test]
+    fn lookup() {
+        let store = Arc::new(MemoryCache::new(10));
+        let cache = Cache::new(store.clone());
+
+        let pending = match cache.lookup(request("/genres/movies", None)) {
+            Lookup::Miss(_, pending) => pending,
+            lookup => panic!("expected a miss, got {:?}", lookup),
+        };
+        let res = cache.update(pending, response(None));
+        assert_eq!(res.headers["X-Ratelimit"], "{}");
+
+        match cache.lookup(request("/genres/movies", None)) {
+            Lookup::Hit(res) => {
+                assert_eq!(res.body, b"[]");
+                assert!(!res.headers.contains_key("X-Ratelimit"));
+            }
+            lookup => panic!("expected a hit, got {:?}", lookup),
+        }
+
+        assert!(matches!(
+            cache.lookup(request("/movies/trending", None)),
+            Lookup::Bypass(_)
+        ));
+        assert!(matches!(
+            cache.lookup(request("/genres/movies", Some("TOKEN"))),
+            Lookup::Bypass(_)
+        ));
+        assert_eq!(store.len(), 1);
+    }
+
+    #[test]
+    fn authenticated() {
+        let cache = Cache::memory(10).authenticated(true);
+
+        match cache.lookup(request("/genres/movies", Some("TOKEN"))) {
+            Lookup::Miss(_, pending) => {
+                cache.update(pending, response(None));
+            }
+            lookup => panic!("expected a miss, got {:?}", lookup),
+        }
+
+        assert!(matches!(
+            cache.lookup(request("/genres/movies", Some("TOKEN"))),
+            Lookup::Hit(_)
+        ));
+        assert!(matches!(
+            cache.lookup(request("/genres/movies", Some("OTHER_TOKEN"))),
+            Lookup::Miss(..)
+        ));
+        assert!(matches!(
+            cache.lookup(request("/genres/movies", None)),
+            Lookup::Miss(..)
+        ));
+    }
+
+    #[test]
+    fn revalidate() {
+        let store = Arc::new(MemoryCache::new(10));
+        let cache = Cache::new(store.clone());
+
+        let mut entry = CacheEntry::new(response(Some("\"abc\"")));
+        entry.stored_at -= Duration::from_secs(2 * 24 * 60 * 60);
+        entry.response.headers.remove("X-Ratelimit");
+        store
+            .put("GET https://api.trakt.tv/genres/movies", &entry)
+            .unwrap();
+
+        let (conditional, pending) = match cache.lookup(request("/genres/movies", None)) {
+            Lookup::Miss(conditional, pending) => (conditional, pending),
+            lookup => panic!("expected a miss, got {:?}", lookup),
+        };
+        assert_eq!(conditional.headers[IF_NONE_MATCH], "\"abc\"");
+
+        let mut not_modified = HttpResponse::new(StatusCode::NOT_MODIFIED, Vec::new());
+        not_modified
+            .headers
+            .insert("X-Ratelimit", HeaderValue::from_static("{}"));
+
+        let res = cache.update(pending, not_modified);
+        assert_eq!(res.status, StatusCode::OK);
+        assert_eq!(res.body, b"[]");
+        assert_eq!(res.headers["X-Ratelimit"], "{}");
+
+        assert!(matches!(
+            cache.lookup(request("/genres/movies", None)),
+            Lookup::Hit(_)
+        ));
+    }
+}
@@ -0,0 +1,131 @@
+//! A catalogue of all endpoints and the authentication they need
+//!
+//! Every endpoint is marked with an [Auth] requirement where it is defined. The marker decides
+//! on which client it is available:
+//!
+//! - [Auth::None] endpoints are methods of [TraktApi]
+//! - [Auth::Optional] endpoints are methods of [TraktApi], which sends them without an access
+//!   token, and of [Session], which sends them with the access token of the user
+//! - [Auth::Required] endpoints are only methods of [Session]
+//!
+//! So calling an endpoint which requires authentication without a session doesn't compile.
+//!
+//! ```rust,compile_fail
+//! use trakt::TraktApi;
+//!
+//! let api = TraktApi::new("CLIENT_ID".to_owned(), None);
+//! api.user_settings();
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use trakt::endpoint::{self, Auth};
+//!
+//! assert_eq!(endpoint::find("genres").unwrap().auth, Auth::None);
+//! assert_eq!(endpoint::find("user_profile").unwrap().auth, Auth::Optional);
+//! assert_eq!(endpoint::find("user_settings").unwrap().auth, Auth::Required);
+//! ```
+//!
+//! [Auth]: enum.Auth.html
+//! [Auth::None]: enum.Auth.html#variant.None
+//! [Auth::Optional]: enum.Auth.html#variant.Optional
+//! [Auth::Required]: enum.Auth.html#variant.Required
+//! [TraktApi]: ../struct.TraktApi.html
+//! [Session]: ../struct.Session.html
+use crate::requests;
+
+/// The authentication an endpoint needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Auth {
+    /// The endpoint doesn't use an access token
+    None,
+    /// The endpoint returns private data of the user, if an access token is sent
+    Optional,
+    /// The endpoint can't be used without an access token
+    Required,
+}
+
+/// An endpoint of the catalogue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Endpoint {
+    /// The name of the method of the endpoint
+    pub name: &'static str,
+    /// The authentication it needs
+    pub auth: Auth,
+}
+
+/// All endpoints and request builders of the clients
+pub fn catalogue() -> impl Iterator<Item = &'static Endpoint> {
+    [
+        requests::ENDPOINTS,
+        requests::authentication::BUILDERS,
+        requests::authentication::ENDPOINTS,
+        requests::calendar::BUILDERS,
+        requests::checkin::BUILDERS,
+        requests::checkin::ENDPOINTS,
+        requests::comments::BUILDERS,
+        requests::comments::ENDPOINTS,
+        requests::episodes::ENDPOINTS,
+        requests::lists::BUILDERS,
+        requests::lists::ENDPOINTS,
+        requests::movies::BUILDERS,
+        requests::movies::ENDPOINTS,
+        requests::people::ENDPOINTS,
+        requests::recommendations::ENDPOINTS,
+        requests::scrobble::BUILDERS,
+        requests::search::ENDPOINTS,
+        requests::seasons::ENDPOINTS,
+        requests::shows::ENDPOINTS,
+        requests::sync::BUILDERS,
+        requests::sync::ENDPOINTS,
+        requests::users::ENDPOINTS,
+    ]
+    .iter()
+    .flat_map(|endpoints| endpoints.iter())
+}
+
+/// Finds an endpoint by the name of its method
+pub fn find(name: &str) -> Option<&'static Endpoint> {
+    catalogue().find(|endpoint| endpoint.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{catalogue, find, Auth};
+    use std::collections::HashSet;
+
+    #[test]
+    fn auth_requirements() {
+        assert_eq!(find("networks").unwrap().auth, Auth::None);
+        assert_eq!(find("oauth_get_token").unwrap().auth, Auth::None);
+        assert_eq!(find("user_comments").unwrap().auth, Auth::Optional);
+        assert_eq!(find("sync_history").unwrap().auth, Auth::Required);
+        assert_eq!(find("checkin").unwrap().auth, Auth::Required);
+        assert_eq!(find("scrobble_stop").unwrap().auth, Auth::Required);
+        assert_eq!(find("user_lists").unwrap().auth, Auth::Optional);
+        assert_eq!(find("user_list_create").unwrap().auth, Auth::Required);
+        assert_eq!(find("user_list_items").unwrap().auth, Auth::Optional);
+        assert_eq!(find("user_list_items_add").unwrap().auth, Auth::Required);
+        assert_eq!(find("list_likes").unwrap().auth, Auth::None);
+        assert_eq!(find("list_like").unwrap().auth, Auth::Required);
+        assert_eq!(find("user_follow").unwrap().auth, Auth::Required);
+        assert_eq!(find("user_friends").unwrap().auth, Auth::Optional);
+        assert_eq!(find("calendar_my_dvd").unwrap().auth, Auth::Required);
+        assert_eq!(find("calendar_all_dvd").unwrap().auth, Auth::None);
+        assert_eq!(find("no_such_endpoint"), None);
+    }
+
+    #[test]
+    fn unique_names() {
+        let mut names = HashSet::new();
+
+        for endpoint in catalogue() {
+            assert!(
+                names.insert(endpoint.name),
+                "{} is listed twice",
+                endpoint.name
+            );
+        }
+    }
+}
@@ -1,8 +1,29 @@
+use crate::{models::DeviceCodeStatus, rate_limit::RateLimit};
+use chrono::{DateTime, Utc};
+use reqwest::{
+    header::{HeaderMap, InvalidHeaderValue},
+    StatusCode,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::{error, fmt, io};
+
+/// Maximum number of bytes of a response body kept in a [DeserializeError]
+///
+/// [DeserializeError]: struct.DeserializeError.html
+const MAX_BODY_LEN: usize = 1024;
+
 #[derive(Debug)]
 pub enum Error {
-    Response(Box<reqwest::Response>),
+    Response(Box<ApiError>),
     Connection(reqwest::Error),
     Serde(serde_json::Error),
+    Deserialize(Box<DeserializeError>),
+    InvalidHeader(InvalidHeaderValue),
+    Url(url::ParseError),
+    Io(io::Error),
+    DeviceCode(DeviceCodeStatus),
+    Redirect(RedirectError),
     NoneError,
     ClientSecretNeeded,
 }
@@ -13,9 +34,16 @@ impl From<reqwest::Error> for Error {
     }
 }
 
-impl From<reqwest::Response> for Error {
-    fn from(res: reqwest::Response) -> Self {
-        Error::Response(Box::new(res))
+#[cfg(feature = "sync")]
+impl From<reqwest::blocking::Response> for Error {
+    fn from(res: reqwest::blocking::Response) -> Self {
+        Error::from(ApiError::from(res))
+    }
+}
+
+impl From<ApiError> for Error {
+    fn from(e: ApiError) -> Self {
+        Error::Response(Box::new(e))
     }
 }
 
@@ -25,8 +53,460 @@ impl From<serde_json::Error> for Error {
     }
 }
 
-impl ToString for Error {
-    fn to_string(&self) -> String {
-        format!("{:?}", self)
+impl From<InvalidHeaderValue> for Error {
+    fn from(e: InvalidHeaderValue) -> Self {
+        Error::InvalidHeader(e)
+    }
+}
+
+impl From<url::ParseError> for Error {
+    fn from(e: url::ParseError) -> Self {
+        Error::Url(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<DeserializeError> for Error {
+    fn from(e: DeserializeError) -> Self {
+        Error::Deserialize(Box::new(e))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Response(e) => write!(f, "trakt api error: {}", e),
+            Error::Connection(e) => write!(f, "connection error: {}", e),
+            Error::Serde(e) => write!(f, "serde error: {}", e),
+            Error::Deserialize(e) => write!(f, "deserialization error: {}", e),
+            Error::InvalidHeader(e) => write!(f, "invalid header value: {}", e),
+            Error::Url(e) => write!(f, "invalid url: {}", e),
+            Error::Io(e) => write!(f, "io error: {}", e),
+            Error::DeviceCode(status) => write!(f, "device authentication failed: {}", status),
+            Error::Redirect(e) => write!(f, "oauth redirect rejected: {}", e),
+            Error::NoneError => f.write_str("a required value is missing"),
+            Error::ClientSecretNeeded => f.write_str("this request needs a client secret"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Response(e) => Some(e.as_ref()),
+            Error::Connection(e) => Some(e),
+            Error::Serde(e) => Some(e),
+            Error::Deserialize(e) => Some(e.as_ref()),
+            Error::InvalidHeader(e) => Some(e),
+            Error::Url(e) => Some(e),
+            Error::Io(e) => Some(e),
+            Error::DeviceCode(_)
+            | Error::Redirect(_)
+            | Error::NoneError
+            | Error::ClientSecretNeeded => None,
+        }
+    }
+}
+
+/// Why an OAuth redirect to a [LoopbackRedirect] was rejected
+///
+/// [LoopbackRedirect]: ../loopback/struct.LoopbackRedirect.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedirectError {
+    /// The state differs from the one sent with the authorization request
+    StateMismatch,
+    /// The authorization was denied. Contains the `error` parameter of the redirect.
+    Denied(String),
+    /// The redirect contains no code
+    MissingCode,
+}
+
+impl fmt::Display for RedirectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RedirectError::StateMismatch => f.write_str("state mismatch"),
+            RedirectError::Denied(e) => write!(f, "authorization denied: {}", e),
+            RedirectError::MissingCode => f.write_str("missing code"),
+        }
+    }
+}
+
+/// The kind of an [ApiError] as described by the [status codes] of the trakt api
+///
+/// [ApiError]: struct.ApiError.html
+/// [status codes]: https://trakt.docs.apiary.io/#introduction/status-codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorKind {
+    /// 400: request couldn't be parsed
+    BadRequest,
+    /// 401: OAuth must be provided
+    Unauthorized,
+    /// 403: invalid API key or unapproved app
+    Forbidden,
+    /// 404: method exists, but no record found
+    NotFound,
+    /// 409: resource already created
+    Conflict,
+    /// 420: account limit exceeded (list count, item count, etc)
+    AccountLimitExceeded,
+    /// 422: validation errors
+    ValidationError,
+    /// 423: user account is locked
+    LockedUser,
+    /// 426: user must upgrade to VIP
+    VipOnly,
+    /// 429: rate limit exceeded
+    RateLimited,
+    /// 5xx: server error, service unavailable or cloudflare error
+    ServerError,
+    /// Any other unsuccessful status code
+    Other,
+}
+
+impl ApiErrorKind {
+    /// Maps a status code to its kind
+    pub fn from_status(status: StatusCode) -> Self {
+        match status.as_u16() {
+            400 => ApiErrorKind::BadRequest,
+            401 => ApiErrorKind::Unauthorized,
+            403 => ApiErrorKind::Forbidden,
+            404 => ApiErrorKind::NotFound,
+            409 => ApiErrorKind::Conflict,
+            420 => ApiErrorKind::AccountLimitExceeded,
+            422 => ApiErrorKind::ValidationError,
+            423 => ApiErrorKind::LockedUser,
+            426 => ApiErrorKind::VipOnly,
+            429 => ApiErrorKind::RateLimited,
+            500..=599 => ApiErrorKind::ServerError,
+            _ => ApiErrorKind::Other,
+        }
+    }
+}
+
+impl fmt::Display for ApiErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ApiErrorKind::BadRequest => "bad request",
+            ApiErrorKind::Unauthorized => "unauthorized",
+            ApiErrorKind::Forbidden => "forbidden",
+            ApiErrorKind::NotFound => "not found",
+            ApiErrorKind::Conflict => "conflict",
+            ApiErrorKind::AccountLimitExceeded => "account limit exceeded",
+            ApiErrorKind::ValidationError => "validation error",
+            ApiErrorKind::LockedUser => "locked user account",
+            ApiErrorKind::VipOnly => "vip only",
+            ApiErrorKind::RateLimited => "rate limit exceeded",
+            ApiErrorKind::ServerError => "server error",
+            ApiErrorKind::Other => "unexpected status",
+        })
+    }
+}
+
+/// The body trakt sends along with an unsuccessful response
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApiErrorBody {
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// The body of a 409 response to a checkin or scrobble of an item which is already being
+/// watched
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WatchingConflict {
+    /// When the item was checked in or scrobbled
+    pub watched_at: DateTime<Utc>,
+    /// When it can be checked in or scrobbled again
+    pub expires_at: DateTime<Utc>,
+}
+
+/// An unsuccessful response of the trakt api
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub kind: ApiErrorKind,
+    /// The parsed body or None if the body wasn't a JSON object
+    pub body: Option<ApiErrorBody>,
+    pub headers: HeaderMap,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, headers: HeaderMap, body: &[u8]) -> Self {
+        Self {
+            status,
+            kind: ApiErrorKind::from_status(status),
+            body: serde_json::from_slice(body).ok(),
+            headers,
+        }
+    }
+
+    /// Returns the value of a header if it is present and valid UTF-8
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// Seconds to wait before retrying as sent in the `Retry-After` header
+    pub fn retry_after(&self) -> Option<u64> {
+        self.header("Retry-After")
+            .and_then(|v| v.trim().parse().ok())
+    }
+
+    /// The url to upgrade to VIP as sent in the `X-Upgrade-URL` header
+    pub fn upgrade_url(&self) -> Option<&str> {
+        self.header("X-Upgrade-URL")
+    }
+
+    /// Whether the user is VIP as sent in the `X-VIP-User` header
+    pub fn vip_user(&self) -> Option<bool> {
+        self.header("X-VIP-User").and_then(|v| v.parse().ok())
+    }
+
+    /// The limit that was exceeded as sent in the `X-Account-Limit` header
+    pub fn account_limit(&self) -> Option<u64> {
+        self.header("X-Account-Limit").and_then(|v| v.parse().ok())
+    }
+
+    /// The rate limit as sent in the `X-Ratelimit` header
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        RateLimit::from_headers(&self.headers)
+    }
+
+    /// When the item was watched and when it can be watched again, if this is the 409 response
+    /// to a duplicate checkin or scrobble
+    pub fn watching_conflict(&self) -> Option<WatchingConflict> {
+        if self.kind != ApiErrorKind::Conflict {
+            return None;
+        }
+
+        let body = self.body.as_ref()?;
+        serde_json::from_value(Value::Object(body.other.clone())).ok()
+    }
+}
+
+#[cfg(feature = "sync")]
+impl From<reqwest::blocking::Response> for ApiError {
+    fn from(mut res: reqwest::blocking::Response) -> Self {
+        use std::io::Read;
+
+        let mut body = Vec::new();
+        // the body is only used for details, a broken body should not hide the status code
+        let _ = res.read_to_end(&mut body);
+
+        ApiError::new(res.status(), res.headers().clone(), &body)
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.kind, self.status)?;
+
+        if let Some(body) = &self.body {
+            match (&body.error, &body.error_description) {
+                (_, Some(description)) => write!(f, ": {}", description)?,
+                (Some(error), None) => write!(f, ": {}", error)?,
+                (None, None) => (),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for ApiError {}
+
+/// A successful response whose body couldn't be deserialized into the expected type
+#[derive(Debug)]
+pub struct DeserializeError {
+    /// The url of the request
+    pub url: String,
+    /// The path to the field that failed (ex. `[0].movie.ids.trakt`)
+    pub path: String,
+    /// The body of the response, truncated to 1024 bytes
+    pub body: String,
+    pub source: serde_json::Error,
+}
+
+impl DeserializeError {
+    /// Deserializes a response body, keeping the details needed to debug a failure
+    pub(crate) fn deserialize<T: DeserializeOwned>(url: &str, body: &[u8]) -> Result<T, Error> {
+        let de = &mut serde_json::Deserializer::from_slice(body);
+
+        serde_path_to_error::deserialize(de).map_err(|e| {
+            let path = e.path().to_string();
+
+            Error::from(DeserializeError {
+                url: url.to_owned(),
+                path,
+                body: truncate(body),
+                source: e.into_inner(),
+            })
+        })
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at `{}` in response of {}",
+            self.source, self.path, self.url
+        )
+    }
+}
+
+impl error::Error for DeserializeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn truncate(body: &[u8]) -> String {
+    let mut body = String::from_utf8_lossy(body).into_owned();
+
+    if body.len() > MAX_BODY_LEN {
+        let mut end = MAX_BODY_LEN;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        body.truncate(end);
+        body.push_str("...");
+    }
+
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{ApiError, ApiErrorKind, DeserializeError, Error, WatchingConflict},
+        models::Genre,
+    };
+    use reqwest::{
+        header::{HeaderMap, HeaderValue},
+        StatusCode,
+    };
+    use std::error::Error as StdError;
+
+    #[test]
+    fn api_error_kind() {
+        assert_eq!(
+            ApiErrorKind::from_status(StatusCode::UNAUTHORIZED),
+            ApiErrorKind::Unauthorized
+        );
+        assert_eq!(
+            ApiErrorKind::from_status(StatusCode::from_u16(420).unwrap()),
+            ApiErrorKind::AccountLimitExceeded
+        );
+        assert_eq!(
+            ApiErrorKind::from_status(StatusCode::from_u16(426).unwrap()),
+            ApiErrorKind::VipOnly
+        );
+        assert_eq!(
+            ApiErrorKind::from_status(StatusCode::from_u16(522).unwrap()),
+            ApiErrorKind::ServerError
+        );
+        assert_eq!(
+            ApiErrorKind::from_status(StatusCode::IM_A_TEAPOT),
+            ApiErrorKind::Other
+        );
+    }
+
+    #[test]
+    fn api_error() {
+        let mut headers = HeaderMap::new();
+        headers.insert("Retry-After", HeaderValue::from_static("10"));
+
+        let e = ApiError::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            headers,
+            b"{\"error\":\"rate_limited\",\"error_description\":\"Slow down\"}",
+        );
+
+        assert_eq!(e.kind, ApiErrorKind::RateLimited);
+        assert_eq!(e.retry_after(), Some(10));
+        assert_eq!(
+            e.body.as_ref().unwrap().error,
+            Some("rate_limited".to_owned())
+        );
+        assert_eq!(
+            e.to_string(),
+            "rate limit exceeded (429 Too Many Requests): Slow down"
+        );
+
+        let e = Error::from(e);
+        assert!(e.source().is_some());
+        assert_eq!(
+            e.to_string(),
+            "trakt api error: rate limit exceeded (429 Too Many Requests): Slow down"
+        );
+    }
+
+    #[test]
+    fn api_error_html_body() {
+        let e = ApiError::new(
+            StatusCode::BAD_GATEWAY,
+            HeaderMap::new(),
+            b"<html>Bad Gateway</html>",
+        );
+
+        assert_eq!(e.kind, ApiErrorKind::ServerError);
+        assert!(e.body.is_none());
+    }
+
+    #[test]
+    fn watching_conflict() {
+        let e = ApiError::new(
+            StatusCode::CONFLICT,
+            HeaderMap::new(),
+            br#"{"watched_at":"2014-10-15T22:21:29.000Z","expires_at":"2014-10-15T23:21:29.000Z"}"#,
+        );
+
+        assert_eq!(
+            e.watching_conflict(),
+            Some(WatchingConflict {
+                watched_at: "2014-10-15T22:21:29.000Z".parse().unwrap(),
+                expires_at: "2014-10-15T23:21:29.000Z".parse().unwrap(),
+            })
+        );
+
+        let e = ApiError::new(StatusCode::NOT_FOUND, HeaderMap::new(), b"{}");
+        assert_eq!(e.watching_conflict(), None);
+    }
+
+    #[test]
+    fn deserialize_error() {
+        let res: Result<Vec<Genre>, Error> = DeserializeError::deserialize(
+            "https://api.trakt.tv/genres/movies",
+            b"[{\"name\":\"Action\",\"slug\":\"action\"},{\"name\":\"Anime\",\"slug\":3}]",
+        );
+
+        match res {
+            Err(Error::Deserialize(e)) => {
+                assert_eq!(e.url, "https://api.trakt.tv/genres/movies");
+                assert_eq!(e.path, "[1].slug");
+                assert!(e.body.starts_with("[{\"name\":\"Action\""));
+            }
+            res => panic!("expected a deserialization error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn deserialize_error_truncated_body() {
+        let body = format!("[\"{}\"]", "ä".repeat(1000));
+
+        match DeserializeError::deserialize::<Vec<u32>>("/", body.as_bytes()) {
+            Err(Error::Deserialize(e)) => {
+                assert_eq!(e.path, "[0]");
+                assert!(e.body.len() <= 1027);
+                assert!(e.body.ends_with("..."));
+            }
+            res => panic!("expected a deserialization error, got {:?}", res),
+        }
     }
 }
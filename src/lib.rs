@@ -22,24 +22,38 @@ extern crate serde_json;
 mod macros;
 #[cfg(feature = "async")]
 pub mod asyn;
+pub mod cache;
+pub mod endpoint;
 pub mod error;
 pub mod extended_info;
 pub mod filters;
+pub mod loopback;
+pub mod middleware;
 pub mod models;
+pub mod oauth;
 pub mod pagination;
 pub mod prelude;
+pub mod rate_limit;
+pub mod request;
+pub mod requests;
+pub mod retry;
+pub mod route;
 pub mod selectors;
 #[cfg(feature = "sync")]
 pub mod sync;
+pub mod token_store;
+pub mod transport;
 
 #[cfg(feature = "sync")]
-pub use crate::sync::{Result, TraktApi};
+pub use crate::sync::{builder::TraktApiBuilder, session::Session, Result, TraktApi};
 
 pub use crate::error::Error;
 
 #[cfg(test)]
 mod tests {
+    use crate::models::AuthenticationTokenResponse;
     use mockito::Mock;
+    use std::time::{SystemTime, UNIX_EPOCH};
 
     pub fn mock(method: &str, path: &str, client_id: &str) -> Mock {
         mockito::mock(method, path)
@@ -50,7 +64,34 @@ mod tests {
 
     pub fn auth_mock(method: &str, path: &str, client_id: &str, access_token: &str) -> Mock {
         mock(method, path, client_id)
-            .with_header("Authorization", &format!("Bearer {}", access_token))
+            .match_header("Authorization", format!("Bearer {}", access_token).as_str())
+    }
+
+    /// A token with the access token `ACCESS_TOKEN`, which expires in 90 days
+    pub fn token() -> AuthenticationTokenResponse {
+        AuthenticationTokenResponse {
+            access_token: "ACCESS_TOKEN".to_owned(),
+            token_type: "bearer".to_owned(),
+            expires_in: 7_776_000,
+            refresh_token: "REFRESH_TOKEN".to_owned(),
+            scope: "public".to_owned(),
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+        }
+    }
+
+    #[cfg(feature = "sync")]
+    pub fn session(url: &str) -> crate::Session {
+        crate::TraktApi::with_url(url, "CLIENT_ID".to_owned(), None)
+            .session(token(), "urn:ietf:wg:oauth:2.0:oob")
+    }
+
+    #[cfg(feature = "async")]
+    pub fn async_session(url: &str) -> crate::asyn::Session {
+        crate::asyn::TraktApi::with_url(url, "CLIENT_ID".to_owned(), None)
+            .session(token(), "urn:ietf:wg:oauth:2.0:oob")
     }
 
 }
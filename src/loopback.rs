@@ -0,0 +1,303 @@
+//! Catching the OAuth redirect on a local port, so users don't have to copy the code by hand
+use crate::{
+    error::{Error, RedirectError},
+    oauth::{random_hex, Pkce},
+};
+#[cfg(feature = "sync")]
+use crate::{models::AuthenticationTokenResponse, oauth::AuthorizeOptions, TraktApi};
+use reqwest::Url;
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    time::Duration,
+};
+
+/// A one-shot HTTP listener on `127.0.0.1` which catches the redirect of an
+/// [OAuth authorization] and checks its state.
+///
+/// The authorization is protected with [PKCE], so no client secret is needed.
+/// The redirect uri has to be registered for the app on trakt.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use trakt::{loopback::LoopbackRedirect, TraktApi};
+///
+/// let api = TraktApi::new("CLIENT_ID".to_owned(), None);
+///
+/// let token = LoopbackRedirect::bind(8080, "/auth")
+///     .unwrap()
+///     .authorize(&api, |url| println!("Open {} in your browser", url))
+///     .unwrap();
+/// ```
+///
+/// [OAuth authorization]: https://trakt.docs.apiary.io/#reference/authentication-oauth/authorize
+/// [PKCE]: ../oauth/struct.Pkce.html
+#[derive(Debug)]
+pub struct LoopbackRedirect {
+    listener: TcpListener,
+    path: String,
+    redirect_uri: String,
+    state: String,
+    pkce: Pkce,
+}
+
+impl LoopbackRedirect {
+    /// Starts listening on `127.0.0.1:port`. Pass 0 to use any free port.
+    /// `path` is the path of the redirect uri, e.g. `/auth`.
+    pub fn bind(port: u16, path: &str) -> Result<Self, Error> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        let port = listener.local_addr()?.port();
+
+        Ok(Self {
+            listener,
+            path: path.to_owned(),
+            redirect_uri: format!("http://127.0.0.1:{}{}", port, path),
+            state: random_hex(32),
+            pkce: Pkce::new(),
+        })
+    }
+
+    /// The uri trakt redirects to after the authorization
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    /// The random state which has to be sent with the authorization request
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// The PKCE verifier and challenge of the authorization
+    pub fn pkce(&self) -> &Pkce {
+        &self.pkce
+    }
+
+    /// The url of the authorization request with the redirect uri, state and PKCE challenge
+    /// of the listener
+    #[cfg(feature = "sync")]
+    pub fn authorize_url(&self, client: &TraktApi) -> String {
+        client.oauth_authorize(
+            &AuthorizeOptions::new(&self.redirect_uri)
+                .state(&self.state)
+                .pkce(&self.pkce),
+        )
+    }
+
+    /// Waits for the redirect and returns its code after checking the state.
+    /// Requests to other paths, like the browser asking for a favicon, are answered with a 404.
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::Redirect] if the state does not match, the user denied the authorization
+    /// or the code is missing
+    ///
+    /// [Error::Redirect]: ../error/enum.Error.html#variant.Redirect
+    pub fn wait_for_code(&self) -> Result<String, Error> {
+        loop {
+            let (mut stream, _) = self.listener.accept()?;
+
+            let url = match read_request(&stream) {
+                Some(url) if url.path() == self.path => url,
+                _ => {
+                    respond(&mut stream, "404 Not Found", "Not found")?;
+                    continue;
+                }
+            };
+
+            let res = self.check(&url);
+
+            match &res {
+                Ok(_) => respond(
+                    &mut stream,
+                    "200 OK",
+                    "Authorization complete. You can close this window.",
+                )?,
+                Err(e) => respond(
+                    &mut stream,
+                    "400 Bad Request",
+                    &format!("Authorization failed: {}", e),
+                )?,
+            }
+
+            return res;
+        }
+    }
+
+    /// Sends the user to trakt by calling `open` with the authorization url,
+    /// waits for the redirect and exchanges its code for a token
+    #[cfg(feature = "sync")]
+    pub fn authorize(
+        self,
+        client: &TraktApi,
+        open: impl FnOnce(&str),
+    ) -> crate::Result<AuthenticationTokenResponse> {
+        open(&self.authorize_url(client));
+
+        let code = self.wait_for_code()?;
+        client.oauth_get_token_pkce(&code, &self.redirect_uri, &self.pkce.verifier)
+    }
+
+    /// Returns the code of the redirect if its state matches
+    fn check(&self, url: &Url) -> Result<String, Error> {
+        let mut code = None;
+        let mut state = None;
+        let mut error = None;
+
+        for (key, value) in url.query_pairs() {
+            match &*key {
+                "code" => code = Some(value.into_owned()),
+                "state" => state = Some(value.into_owned()),
+                "error" => error = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+
+        if state.as_deref() != Some(&*self.state) {
+            return Err(Error::Redirect(RedirectError::StateMismatch));
+        }
+
+        if let Some(error) = error {
+            return Err(Error::Redirect(RedirectError::Denied(error)));
+        }
+
+        code.ok_or(Error::Redirect(RedirectError::MissingCode))
+    }
+}
+
+/// Reads the request line and headers of a request and returns its url
+fn read_request(stream: &TcpStream) -> Option<Url> {
+    stream.set_read_timeout(Some(Duration::from_secs(5))).ok()?;
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+
+    let mut line = String::new();
+    while reader.read_line(&mut line).ok()? > 2 {
+        line.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some("GET"), Some(target)) if target.starts_with('/') => {
+            Url::parse(&format!("http://127.0.0.1{}", target)).ok()
+        }
+        _ => None,
+    }
+}
+
+fn respond(stream: &mut TcpStream, status: &str, body: &str) -> Result<(), Error> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{Error, RedirectError},
+        loopback::LoopbackRedirect,
+        tests::mock,
+        TraktApi,
+    };
+    use mockito::Matcher;
+    use reqwest::Url;
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+        thread::{self, JoinHandle},
+    };
+
+    /// Acts like a browser following the redirect to `url` after replacing the state
+    fn redirect(url: &str, state: Option<&str>) -> JoinHandle<String> {
+        let url = Url::parse(url).unwrap();
+        let redirect_uri = url
+            .query_pairs()
+            .find(|(key, _)| key == "redirect_uri")
+            .unwrap()
+            .1
+            .into_owned();
+        let state = match state {
+            Some(state) => state.to_owned(),
+            None => url
+                .query_pairs()
+                .find(|(key, _)| key == "state")
+                .unwrap()
+                .1
+                .into_owned(),
+        };
+
+        thread::spawn(move || {
+            let redirect_uri = Url::parse(&redirect_uri).unwrap();
+            let mut stream =
+                TcpStream::connect(("127.0.0.1", redirect_uri.port().unwrap())).unwrap();
+            write!(
+                stream,
+                "GET {}?code=CODE&state={} HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n",
+                redirect_uri.path(),
+                state
+            )
+            .unwrap();
+
+            let mut res = String::new();
+            stream.read_to_string(&mut res).unwrap();
+            res
+        })
+    }
+
+    #[test]
+    fn authorize() {
+        let loopback = LoopbackRedirect::bind(0, "/auth").unwrap();
+        let m = mock("POST", "/oauth/token", "CLIENT_ID")
+            .match_body(Matcher::PartialJson(json!({
+                "code": "CODE",
+                "code_verifier": loopback.pkce().verifier,
+                "grant_type": "authorization_code"
+            })))
+            .with_status(200)
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(&url, "CLIENT_ID".to_owned(), None);
+        assert!(loopback.redirect_uri().starts_with("http://127.0.0.1:"));
+        assert_eq!(loopback.state().len(), 32);
+
+        let mut browser = None;
+        let token = loopback
+            .authorize(&api, |url| browser = Some(redirect(url, None)))
+            .unwrap();
+
+        assert_eq!(token.expires_in, 7200);
+        assert!(browser
+            .unwrap()
+            .join()
+            .unwrap()
+            .starts_with("HTTP/1.1 200 OK"));
+        m.assert();
+    }
+
+    #[test]
+    fn state_mismatch() {
+        let loopback = LoopbackRedirect::bind(0, "/auth").unwrap();
+        let api = TraktApi::new("CLIENT_ID".to_owned(), None);
+        let browser = redirect(&loopback.authorize_url(&api), Some("FORGED"));
+
+        match loopback.wait_for_code() {
+            Err(Error::Redirect(e)) => assert_eq!(e, RedirectError::StateMismatch),
+            res => panic!("expected a redirect error, got {:?}", res),
+        }
+
+        assert!(browser
+            .join()
+            .unwrap()
+            .starts_with("HTTP/1.1 400 Bad Request"));
+    }
+}
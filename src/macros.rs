@@ -1,40 +1,373 @@
-// A simple macro that creates the URL routes used to send requests
-// TODO this needs to be improved
-macro_rules! api_url {
-    (($($y:expr),+) $(,($a:expr, $b:expr))*) => {{
-        let mut string: String = String::new();
-        $(
-            string.push_str("/");
-            string.push_str(&format!("{}", $y));
-        )+
-
-        let mut _first: bool = true;
-
-        $(
-            if _first {
-                _first = false;
-                string.push_str(&format!("?{}={}", $a, $b));
-            } else {
-                string.push_str(&format!("&{}={}", $a, $b));
-            }
-
-        )*
-        string
-    }};
+// Defines endpoints once and implements them on the sync and the async client.
+//
+// Each endpoint is written as a method whose body evaluates to the ApiRequest to execute.
+// The return type is the deserialized response, `()` for responses without a body or
+// `Paginated<T>` for paginated endpoints, which return a PaginationRequest<T> instead.
+//
+// An endpoint may be marked with `#[auth(required)]` or `#[auth(optional)]` before its
+// documentation. Required endpoints are only implemented on the Session, which attaches the
+// access token. Optional ones are implemented on the TraktApi without and on the Session with
+// an access token. All endpoints are listed in the `ENDPOINTS` of the module.
+macro_rules! endpoints {
+    ($($endpoints:tt)*) => {
+        #[cfg(feature = "sync")]
+        sync_endpoints!($($endpoints)*);
+
+        #[cfg(feature = "async")]
+        async_endpoints!($($endpoints)*);
+
+        catalogue!(ENDPOINTS [] $($endpoints)*);
+    };
+}
+
+#[cfg(feature = "sync")]
+macro_rules! sync_endpoints {
+    () => {};
+    (
+        #[auth(required)]
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> Paginated<$item:ty>
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::sync::session::Session {
+            $(#[$attr])*
+            pub fn $name(
+                &$this $(, $arg: $ty)*
+            ) -> crate::sync::Result<crate::sync::pagination::PaginationRequest<$item>> {
+                $this.paginate($body)
+            }
+        }
+
+        sync_endpoints!($($rest)*);
+    };
+    (
+        #[auth(required)]
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> ()
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::sync::session::Session {
+            $(#[$attr])*
+            pub fn $name(&$this $(, $arg: $ty)*) -> crate::sync::Result<()> {
+                $this.execute_request_no_body($body)
+            }
+        }
+
+        sync_endpoints!($($rest)*);
+    };
+    (
+        #[auth(required)]
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::sync::session::Session {
+            $(#[$attr])*
+            pub fn $name(&$this $(, $arg: $ty)*) -> crate::sync::Result<$ret> {
+                $this.execute_request($body)
+            }
+        }
+
+        sync_endpoints!($($rest)*);
+    };
+    (
+        #[auth(optional)]
+        $(#[$attr:meta])*
+        pub fn $name:ident $args:tt -> Paginated<$item:ty>
+        $body:block
+        $($rest:tt)*
+    ) => {
+        sync_endpoints!(#[auth(required)] $(#[$attr])* pub fn $name $args -> Paginated<$item> $body);
+        sync_endpoints!($(#[$attr])* pub fn $name $args -> Paginated<$item> $body $($rest)*);
+    };
+    (
+        #[auth(optional)]
+        $(#[$attr:meta])*
+        pub fn $name:ident $args:tt -> ()
+        $body:block
+        $($rest:tt)*
+    ) => {
+        sync_endpoints!(#[auth(required)] $(#[$attr])* pub fn $name $args -> () $body);
+        sync_endpoints!($(#[$attr])* pub fn $name $args -> () $body $($rest)*);
+    };
+    (
+        #[auth(optional)]
+        $(#[$attr:meta])*
+        pub fn $name:ident $args:tt -> $ret:ty
+        $body:block
+        $($rest:tt)*
+    ) => {
+        sync_endpoints!(#[auth(required)] $(#[$attr])* pub fn $name $args -> $ret $body);
+        sync_endpoints!($(#[$attr])* pub fn $name $args -> $ret $body $($rest)*);
+    };
+    (
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> Paginated<$item:ty>
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::sync::TraktApi {
+            $(#[$attr])*
+            pub fn $name(&$this $(, $arg: $ty)*) -> crate::sync::pagination::PaginationRequest<$item> {
+                crate::sync::pagination::PaginationRequest::new($this, $body)
+            }
+        }
+
+        sync_endpoints!($($rest)*);
+    };
+    (
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> ()
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::sync::TraktApi {
+            $(#[$attr])*
+            pub fn $name(&$this $(, $arg: $ty)*) -> crate::sync::Result<()> {
+                $this.execute_request_no_body($body)
+            }
+        }
+
+        sync_endpoints!($($rest)*);
+    };
+    (
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::sync::TraktApi {
+            $(#[$attr])*
+            pub fn $name(&$this $(, $arg: $ty)*) -> crate::sync::Result<$ret> {
+                $this.execute_request($body)
+            }
+        }
+
+        sync_endpoints!($($rest)*);
+    };
+}
+
+#[cfg(feature = "async")]
+macro_rules! async_endpoints {
+    () => {};
+    (
+        #[auth(required)]
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> Paginated<$item:ty>
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::asyn::session::Session {
+            $(#[$attr])*
+            pub async fn $name(
+                &$this $(, $arg: $ty)*
+            ) -> crate::asyn::Result<crate::asyn::pagination::PaginationRequest<$item>> {
+                let request = $body;
+                $this.paginate(request).await
+            }
+        }
+
+        async_endpoints!($($rest)*);
+    };
+    (
+        #[auth(required)]
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> ()
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::asyn::session::Session {
+            $(#[$attr])*
+            pub async fn $name(&$this $(, $arg: $ty)*) -> crate::asyn::Result<()> {
+                let request = $body;
+                $this.execute_request_no_body(request).await
+            }
+        }
+
+        async_endpoints!($($rest)*);
+    };
+    (
+        #[auth(required)]
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::asyn::session::Session {
+            $(#[$attr])*
+            pub async fn $name(&$this $(, $arg: $ty)*) -> crate::asyn::Result<$ret> {
+                let request = $body;
+                $this.execute_request(request).await
+            }
+        }
+
+        async_endpoints!($($rest)*);
+    };
+    (
+        #[auth(optional)]
+        $(#[$attr:meta])*
+        pub fn $name:ident $args:tt -> Paginated<$item:ty>
+        $body:block
+        $($rest:tt)*
+    ) => {
+        async_endpoints!(#[auth(required)] $(#[$attr])* pub fn $name $args -> Paginated<$item> $body);
+        async_endpoints!($(#[$attr])* pub fn $name $args -> Paginated<$item> $body $($rest)*);
+    };
+    (
+        #[auth(optional)]
+        $(#[$attr:meta])*
+        pub fn $name:ident $args:tt -> ()
+        $body:block
+        $($rest:tt)*
+    ) => {
+        async_endpoints!(#[auth(required)] $(#[$attr])* pub fn $name $args -> () $body);
+        async_endpoints!($(#[$attr])* pub fn $name $args -> () $body $($rest)*);
+    };
+    (
+        #[auth(optional)]
+        $(#[$attr:meta])*
+        pub fn $name:ident $args:tt -> $ret:ty
+        $body:block
+        $($rest:tt)*
+    ) => {
+        async_endpoints!(#[auth(required)] $(#[$attr])* pub fn $name $args -> $ret $body);
+        async_endpoints!($(#[$attr])* pub fn $name $args -> $ret $body $($rest)*);
+    };
+    (
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> Paginated<$item:ty>
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::asyn::TraktApi {
+            $(#[$attr])*
+            pub fn $name(&$this $(, $arg: $ty)*) -> crate::asyn::pagination::PaginationRequest<$item> {
+                crate::asyn::pagination::PaginationRequest::new($this, $body)
+            }
+        }
+
+        async_endpoints!($($rest)*);
+    };
+    (
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> ()
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::asyn::TraktApi {
+            $(#[$attr])*
+            pub async fn $name(&$this $(, $arg: $ty)*) -> crate::asyn::Result<()> {
+                let request = $body;
+                $this.execute_request_no_body(request).await
+            }
+        }
+
+        async_endpoints!($($rest)*);
+    };
+    (
+        $(#[$attr:meta])*
+        pub fn $name:ident(&$this:ident $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty
+        $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::asyn::TraktApi {
+            $(#[$attr])*
+            pub async fn $name(&$this $(, $arg: $ty)*) -> crate::asyn::Result<$ret> {
+                let request = $body;
+                $this.execute_request(request).await
+            }
+        }
+
+        async_endpoints!($($rest)*);
+    };
+}
+
+// Implements methods which return a request builder on the sync and the async client.
+// The builders are generic over the client, so the methods use `Self` for it.
+//
+// Builders marked with `#[auth(required)]` are only implemented on the Session. All builders
+// are listed in the `BUILDERS` of the module.
+macro_rules! builders {
+    ($($builders:tt)*) => {
+        #[cfg(feature = "sync")]
+        client_builders!(sync $($builders)*);
+
+        #[cfg(feature = "async")]
+        client_builders!(asyn $($builders)*);
+
+        catalogue!(BUILDERS [] $($builders)*);
+    };
+}
+
+macro_rules! client_builders {
+    ($client:ident) => {};
+    (
+        $client:ident
+        #[auth(required)]
+        $(#[$attr:meta])*
+        pub fn $name:ident $args:tt -> $ret:ty $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::$client::session::Session {
+            $(#[$attr])*
+            pub fn $name $args -> $ret $body
+        }
+
+        client_builders!($client $($rest)*);
+    };
+    (
+        $client:ident
+        $(#[$attr:meta])*
+        pub fn $name:ident $args:tt -> $ret:ty $body:block
+        $($rest:tt)*
+    ) => {
+        impl crate::$client::TraktApi {
+            $(#[$attr])*
+            pub fn $name $args -> $ret $body
+        }
+
+        client_builders!($client $($rest)*);
+    };
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn combined_force_test() {
-        assert_eq!(
-            "/test/1/2/3?test=1&test1=2",
-            &api_url!(("test", "1", "2", "3"), ("test", "1"), ("test1", "2"))
-        )
-    }
-
-    #[test]
-    fn combined_force_test_only_url() {
-        assert_eq!("/test/1/2/3", &api_url!(("test", "1", "2", "3")))
-    }
+// Lists the endpoints of a module with their authentication in a constant
+macro_rules! catalogue {
+    ($list:ident [$($endpoints:expr,)*]) => {
+        pub(crate) const $list: &[crate::endpoint::Endpoint] = &[$($endpoints,)*];
+    };
+    (
+        $list:ident [$($endpoints:expr,)*]
+        #[auth(required)]
+        $(#[$attr:meta])*
+        pub fn $name:ident $args:tt -> $ret:ty $body:block
+        $($rest:tt)*
+    ) => {
+        catalogue!($list [$($endpoints,)* catalogue!(@endpoint $name Required),] $($rest)*);
+    };
+    (
+        $list:ident [$($endpoints:expr,)*]
+        #[auth(optional)]
+        $(#[$attr:meta])*
+        pub fn $name:ident $args:tt -> $ret:ty $body:block
+        $($rest:tt)*
+    ) => {
+        catalogue!($list [$($endpoints,)* catalogue!(@endpoint $name Optional),] $($rest)*);
+    };
+    (
+        $list:ident [$($endpoints:expr,)*]
+        $(#[$attr:meta])*
+        pub fn $name:ident $args:tt -> $ret:ty $body:block
+        $($rest:tt)*
+    ) => {
+        catalogue!($list [$($endpoints,)* catalogue!(@endpoint $name None),] $($rest)*);
+    };
+    (@endpoint $name:ident $auth:ident) => {
+        crate::endpoint::Endpoint {
+            name: stringify!($name),
+            auth: crate::endpoint::Auth::$auth,
+        }
+    };
 }
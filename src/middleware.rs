@@ -0,0 +1,346 @@
+//! Hooks around every request, e.g. for logging and metrics
+//!
+//! A [Middleware] is called before each try of a request is sent and after its response was
+//! received. It can change the headers of the request and the response. Several middlewares
+//! are called in the order they were added before sending and in the reverse order after
+//! receiving.
+//!
+//! With the `log` or `tracing` feature enabled, [LogMiddleware] and [TracingMiddleware] log
+//! every request. Both redact the `Authorization` and `trakt-api-key` headers.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//! use trakt::{
+//!     middleware::{Middleware, ResponseInfo},
+//!     transport::{HttpRequest, HttpResponse},
+//!     TraktApi,
+//! };
+//!
+//! #[derive(Debug, Default)]
+//! struct Metrics {
+//!     requests: AtomicU64,
+//!     retries: AtomicU64,
+//! }
+//!
+//! impl Middleware for Metrics {
+//!     fn after_receive(
+//!         &self,
+//!         _request: &HttpRequest,
+//!         _response: &mut HttpResponse,
+//!         info: &ResponseInfo,
+//!     ) {
+//!         self.requests.fetch_add(1, Ordering::Relaxed);
+//!         if info.retries > 0 {
+//!             self.retries.fetch_add(1, Ordering::Relaxed);
+//!         }
+//!     }
+//! }
+//!
+//! let api = TraktApi::new("CLIENT_ID".to_owned(), None).with_middleware(Metrics::default());
+//! ```
+//!
+//! [Middleware]: trait.Middleware.html
+//! [LogMiddleware]: struct.LogMiddleware.html
+//! [TracingMiddleware]: struct.TracingMiddleware.html
+use crate::{
+    error::Error,
+    transport::{HttpRequest, HttpResponse},
+};
+use reqwest::header::{HeaderMap, AUTHORIZATION};
+use std::{fmt, sync::Arc, time::Duration};
+
+/// Information about a response passed to [Middleware::after_receive]
+///
+/// [Middleware::after_receive]: trait.Middleware.html#method.after_receive
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResponseInfo {
+    /// Number of retries before this try, 0 for the first one
+    pub retries: u32,
+    /// Time between sending the request and receiving the response
+    pub latency: Duration,
+}
+
+/// Hooks called around every try of a request.
+///
+/// All methods do nothing by default.
+pub trait Middleware: fmt::Debug + Send + Sync {
+    /// Called before the request is sent. `retries` is 0 for the first try.
+    fn before_send(&self, _request: &mut HttpRequest, _retries: u32) {}
+
+    /// Called after a response was received, whatever its status code.
+    /// Changes to the response are seen by the client.
+    fn after_receive(
+        &self,
+        _request: &HttpRequest,
+        _response: &mut HttpResponse,
+        _info: &ResponseInfo,
+    ) {
+    }
+
+    /// Called if the request couldn't be sent or no response was received
+    fn on_error(&self, _request: &HttpRequest, _error: &Error, _info: &ResponseInfo) {}
+}
+
+impl<T: Middleware + ?Sized> Middleware for Arc<T> {
+    fn before_send(&self, request: &mut HttpRequest, retries: u32) {
+        (**self).before_send(request, retries)
+    }
+
+    fn after_receive(
+        &self,
+        request: &HttpRequest,
+        response: &mut HttpResponse,
+        info: &ResponseInfo,
+    ) {
+        (**self).after_receive(request, response, info)
+    }
+
+    fn on_error(&self, request: &HttpRequest, error: &Error, info: &ResponseInfo) {
+        (**self).on_error(request, error, info)
+    }
+}
+
+/// The middlewares of a client in the order they were added
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MiddlewareChain {
+    middlewares: Vec<Arc<dyn Middleware>>,
+}
+
+impl MiddlewareChain {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middlewares.push(middleware);
+    }
+
+    pub(crate) fn before_send(&self, request: &mut HttpRequest, retries: u32) {
+        for middleware in &self.middlewares {
+            middleware.before_send(request, retries);
+        }
+    }
+
+    pub(crate) fn after_receive(
+        &self,
+        request: &HttpRequest,
+        result: &mut Result<HttpResponse, Error>,
+        info: &ResponseInfo,
+    ) {
+        for middleware in self.middlewares.iter().rev() {
+            match result {
+                Ok(response) => middleware.after_receive(request, response, info),
+                Err(error) => middleware.on_error(request, error, info),
+            }
+        }
+    }
+}
+
+/// Formats headers with the values of `Authorization` and `trakt-api-key` redacted
+///
+/// # Example
+///
+/// ```rust
+/// use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+/// use trakt::middleware::RedactedHeaders;
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer ACCESS_TOKEN"));
+///
+/// assert_eq!(
+///     format!("{:?}", RedactedHeaders(&headers)),
+///     r#"{"authorization": "<redacted>"}"#
+/// );
+/// ```
+pub struct RedactedHeaders<'a>(pub &'a HeaderMap);
+
+impl fmt::Debug for RedactedHeaders<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(name, value)| {
+                let value: &dyn fmt::Debug = if is_secret(name.as_str()) {
+                    &"<redacted>"
+                } else {
+                    value
+                };
+                (name, value)
+            }))
+            .finish()
+    }
+}
+
+/// Whether the value of a header must not be logged
+fn is_secret(name: &str) -> bool {
+    name.eq_ignore_ascii_case(AUTHORIZATION.as_str()) || name.eq_ignore_ascii_case("trakt-api-key")
+}
+
+/// Logs every request with the [log] crate under the target `trakt`.
+///
+/// The method and url are logged at the debug level before sending, the headers at the trace
+/// level. Responses are logged with their status code, latency, rate limit and retries at the
+/// debug level, unsuccessful ones at the warn level.
+///
+/// [log]: https://docs.rs/log
+#[cfg(feature = "log")]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LogMiddleware;
+
+#[cfg(feature = "log")]
+impl Middleware for LogMiddleware {
+    fn before_send(&self, request: &mut HttpRequest, retries: u32) {
+        log::debug!(
+            target: "trakt",
+            "--> {} {} (retries: {})",
+            request.method,
+            request.url,
+            retries
+        );
+        log::trace!(target: "trakt", "headers: {:?}", RedactedHeaders(&request.headers));
+    }
+
+    fn after_receive(
+        &self,
+        request: &HttpRequest,
+        response: &mut HttpResponse,
+        info: &ResponseInfo,
+    ) {
+        let level = if response.status.is_success() {
+            log::Level::Debug
+        } else {
+            log::Level::Warn
+        };
+
+        log::log!(
+            target: "trakt",
+            level,
+            "<-- {} {} {} in {:?} (retries: {}, rate limit: {})",
+            request.method,
+            request.url,
+            response.status,
+            info.latency,
+            info.retries,
+            rate_limit(&response.headers)
+        );
+        log::trace!(target: "trakt", "headers: {:?}", RedactedHeaders(&response.headers));
+    }
+
+    fn on_error(&self, request: &HttpRequest, error: &Error, info: &ResponseInfo) {
+        log::warn!(
+            target: "trakt",
+            "<-- {} {} failed after {:?} (retries: {}): {}",
+            request.method,
+            request.url,
+            info.latency,
+            info.retries,
+            error
+        );
+    }
+}
+
+/// Emits an event with the [tracing] crate for every request.
+///
+/// Events before sending are emitted at the debug level, the headers at the trace level.
+/// Responses are emitted with their status code, latency, rate limit and retries at the debug
+/// level, unsuccessful ones at the warn level.
+///
+/// [tracing]: https://docs.rs/tracing
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TracingMiddleware;
+
+#[cfg(feature = "tracing")]
+impl Middleware for TracingMiddleware {
+    fn before_send(&self, request: &mut HttpRequest, retries: u32) {
+        tracing::debug!(
+            target: "trakt",
+            method = %request.method,
+            url = %request.url,
+            retries,
+            "sending request"
+        );
+        tracing::trace!(
+            target: "trakt",
+            headers = ?RedactedHeaders(&request.headers),
+            "request headers"
+        );
+    }
+
+    fn after_receive(
+        &self,
+        request: &HttpRequest,
+        response: &mut HttpResponse,
+        info: &ResponseInfo,
+    ) {
+        let latency_ms = info.latency.as_millis() as u64;
+        let rate_limit = rate_limit(&response.headers);
+
+        if response.status.is_success() {
+            tracing::debug!(
+                target: "trakt",
+                method = %request.method,
+                url = %request.url,
+                status = response.status.as_u16(),
+                latency_ms,
+                retries = info.retries,
+                rate_limit,
+                "received response"
+            );
+        } else {
+            tracing::warn!(
+                target: "trakt",
+                method = %request.method,
+                url = %request.url,
+                status = response.status.as_u16(),
+                latency_ms,
+                retries = info.retries,
+                rate_limit,
+                "received unsuccessful response"
+            );
+        }
+        tracing::trace!(
+            target: "trakt",
+            headers = ?RedactedHeaders(&response.headers),
+            "response headers"
+        );
+    }
+
+    fn on_error(&self, request: &HttpRequest, error: &Error, info: &ResponseInfo) {
+        tracing::warn!(
+            target: "trakt",
+            method = %request.method,
+            url = %request.url,
+            latency_ms = info.latency.as_millis() as u64,
+            retries = info.retries,
+            error = %error,
+            "request failed"
+        );
+    }
+}
+
+/// The `X-Ratelimit` header as a string or `-` if there is none
+#[cfg(any(feature = "log", feature = "tracing"))]
+fn rate_limit(headers: &HeaderMap) -> &str {
+    headers
+        .get("X-Ratelimit")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedactedHeaders;
+    use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+
+    #[test]
+    fn redacted_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert("trakt-api-key", HeaderValue::from_static("CLIENT_ID"));
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer TOKEN"));
+
+        let formatted = format!("{:?}", RedactedHeaders(&headers));
+        assert!(formatted.contains(r#""content-type": "application/json""#));
+        assert!(formatted.contains(r#""trakt-api-key": "<redacted>""#));
+        assert!(formatted.contains(r#""authorization": "<redacted>""#));
+        assert!(!formatted.contains("CLIENT_ID"));
+        assert!(!formatted.contains("TOKEN"));
+    }
+}
@@ -3,16 +3,23 @@
 //! [authentication]: https://trakt.docs.apiary.io/#reference/authentication-oauth
 
 #[cfg(feature = "async")]
-use crate::asyn::{Result as AsyncResult, TraktApi as AsyncTraktApi};
+use crate::asyn::{delay, Result as AsyncResult, TraktApi as AsyncTraktApi};
+use crate::error::Error;
 #[cfg(feature = "sync")]
 use crate::{Result, TraktApi};
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use std::{
+    fmt,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
 /// The device codes required for device authentication
 ///
 /// Look [here]
 ///
 /// [here]: https://trakt.docs.apiary.io/#reference/authentication-devices/device-code/generate-new-device-codes
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AuthenticationDevices {
     pub device_code: String,
     pub user_code: String,
@@ -28,8 +35,184 @@ impl AuthenticationDevices {
     }
 
     #[cfg(feature = "async")]
-    pub fn poll_async(&self, client: &AsyncTraktApi) -> AsyncResult<AuthenticationTokenResponse> {
-        client.oauth_device_token(&self.device_code)
+    pub async fn poll_async(
+        &self,
+        client: &AsyncTraktApi,
+    ) -> AsyncResult<AuthenticationTokenResponse> {
+        client.oauth_device_token(&self.device_code).await
+    }
+
+    /// Polls until the user authorized the app or the codes expired.
+    ///
+    /// It waits `interval` seconds between two polls and slows down if trakt asks for it.
+    /// `progress` is called before every wait, so the user code and the remaining time can be
+    /// shown to the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::DeviceCode] if the codes are invalid, already used or expired
+    /// or if the user denied the authorization
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use trakt::TraktApi;
+    ///
+    /// let api = TraktApi::new("CLIENT_ID".to_owned(), Some("CLIENT_SECRET".to_owned()));
+    /// let devices = api.oauth_device_code().unwrap();
+    ///
+    /// let token = devices
+    ///     .poll_until_authorized(&api, |progress| {
+    ///         println!(
+    ///             "Enter {} at {} ({}s left)",
+    ///             progress.user_code,
+    ///             progress.verification_url,
+    ///             progress.expires_in.as_secs()
+    ///         )
+    ///     })
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [Error::DeviceCode]: ../../error/enum.Error.html#variant.DeviceCode
+    #[cfg(feature = "sync")]
+    pub fn poll_until_authorized(
+        &self,
+        client: &TraktApi,
+        mut progress: impl FnMut(&DeviceCodeProgress),
+    ) -> Result<AuthenticationTokenResponse> {
+        let start = Instant::now();
+        let mut interval = Duration::from_secs(self.interval);
+
+        loop {
+            std::thread::sleep(self.next_wait(start, interval, &mut progress)?);
+
+            match client.oauth_device_token(&self.device_code) {
+                Ok(token) => return Ok(token),
+                Err(e) => interval = Self::pending_interval(e, interval)?,
+            }
+        }
+    }
+
+    /// Polls until the user authorized the app or the codes expired.
+    /// Look at [poll_until_authorized] for details.
+    ///
+    /// [poll_until_authorized]: #method.poll_until_authorized
+    #[cfg(feature = "async")]
+    pub async fn poll_until_authorized_async(
+        &self,
+        client: &AsyncTraktApi,
+        mut progress: impl FnMut(&DeviceCodeProgress),
+    ) -> AsyncResult<AuthenticationTokenResponse> {
+        let start = Instant::now();
+        let mut interval = Duration::from_secs(self.interval);
+
+        loop {
+            delay(Some(self.next_wait(start, interval, &mut progress)?)).await;
+
+            match client.oauth_device_token(&self.device_code).await {
+                Ok(token) => return Ok(token),
+                Err(e) => interval = Self::pending_interval(e, interval)?,
+            }
+        }
+    }
+
+    /// Time to wait before the next poll. Fails if the codes expire before.
+    fn next_wait(
+        &self,
+        start: Instant,
+        interval: Duration,
+        progress: &mut impl FnMut(&DeviceCodeProgress),
+    ) -> std::result::Result<Duration, Error> {
+        let expires_in = Duration::from_secs(self.expires_in)
+            .checked_sub(start.elapsed())
+            .filter(|expires_in| *expires_in > Duration::from_secs(0))
+            .ok_or(Error::DeviceCode(DeviceCodeStatus::Expired))?;
+
+        progress(&DeviceCodeProgress {
+            user_code: &self.user_code,
+            verification_url: &self.verification_url,
+            expires_in,
+            interval,
+        });
+
+        Ok(interval.min(expires_in))
+    }
+
+    /// The interval of the next poll if the authorization is still pending
+    fn pending_interval(error: Error, interval: Duration) -> std::result::Result<Duration, Error> {
+        let status = match &error {
+            Error::Response(e) => DeviceCodeStatus::from_status(e.status),
+            _ => None,
+        };
+
+        match status {
+            Some(DeviceCodeStatus::Pending) => Ok(interval),
+            Some(DeviceCodeStatus::SlowDown) => Ok((interval * 2).max(Duration::from_secs(1))),
+            Some(status) => Err(Error::DeviceCode(status)),
+            None => Err(error),
+        }
+    }
+}
+
+/// The progress of a device authentication, passed to the callback of [poll_until_authorized]
+///
+/// [poll_until_authorized]: struct.AuthenticationDevices.html#method.poll_until_authorized
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceCodeProgress<'a> {
+    /// The code the user has to enter
+    pub user_code: &'a str,
+    /// The url the user has to enter the code at
+    pub verification_url: &'a str,
+    /// Time until the codes expire
+    pub expires_in: Duration,
+    /// Time until the next poll
+    pub interval: Duration,
+}
+
+/// The [status] of a device authentication which is not authorized yet
+///
+/// [status]: https://trakt.docs.apiary.io/#reference/authentication-devices/get-token/poll-for-the-access_token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceCodeStatus {
+    /// 400: waiting for the user to authorize the app
+    Pending,
+    /// 404: invalid device code
+    InvalidCode,
+    /// 409: the code was already used
+    AlreadyUsed,
+    /// 410: the codes expired
+    Expired,
+    /// 418: the user denied the authorization
+    Denied,
+    /// 429: the app is polling too fast
+    SlowDown,
+}
+
+impl DeviceCodeStatus {
+    /// Maps a status code of a poll to its status
+    pub fn from_status(status: StatusCode) -> Option<Self> {
+        match status.as_u16() {
+            400 => Some(DeviceCodeStatus::Pending),
+            404 => Some(DeviceCodeStatus::InvalidCode),
+            409 => Some(DeviceCodeStatus::AlreadyUsed),
+            410 => Some(DeviceCodeStatus::Expired),
+            418 => Some(DeviceCodeStatus::Denied),
+            429 => Some(DeviceCodeStatus::SlowDown),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DeviceCodeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            DeviceCodeStatus::Pending => "authorization pending",
+            DeviceCodeStatus::InvalidCode => "invalid device code",
+            DeviceCodeStatus::AlreadyUsed => "device code already used",
+            DeviceCodeStatus::Expired => "device code expired",
+            DeviceCodeStatus::Denied => "authorization denied by the user",
+            DeviceCodeStatus::SlowDown => "polling too fast",
+        })
     }
 }
 
@@ -38,7 +221,7 @@ impl AuthenticationDevices {
 /// Look [here]
 ///
 /// [here]: https://trakt.docs.apiary.io/#reference/authentication-oauth/get-token/exchange-code-for-access_token
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AuthenticationTokenResponse {
     pub access_token: String,
     pub token_type: String,
@@ -47,3 +230,19 @@ pub struct AuthenticationTokenResponse {
     pub scope: String,
     pub created_at: u64,
 }
+
+impl AuthenticationTokenResponse {
+    /// The time at which the access token expires
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        DateTime::from(self.expiry())
+    }
+
+    /// Returns true if the access token is expired or expires within `margin`
+    pub fn expires_within(&self, margin: Duration) -> bool {
+        SystemTime::now() + margin >= self.expiry()
+    }
+
+    fn expiry(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.created_at + u64::from(self.expires_in))
+    }
+}
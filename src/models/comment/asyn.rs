@@ -1,50 +1,48 @@
 use crate::{
-    asyn::{
-        pagination::PaginationRequest,
-        requests::comments::comment_post_request::CommentPostRequest, Result, TraktApi,
-    },
+    asyn::{pagination::PaginationRequest, Result, Session, TraktApi},
     models::{Comment, CommentAndItem, CommentItem, FullComment, FullCommentAndItem, Like, ToId},
+    requests::comments::comment_post_request::CommentPostRequest,
 };
 
 pub trait CommentMethods<'b>: ToId<'b, u32> {
-    fn update_async<'a>(&'b self, client: &'a TraktApi, comment: String) -> CommentPostRequest<'a> {
+    fn update_async(&'b self, client: &Session, comment: String) -> CommentPostRequest<Session> {
         client.comment_update(self.id(), comment)
     }
 
-    fn delete_async(&'b self, client: &TraktApi, access_token: &str) -> Result<()> {
-        client.comment_delete(self.id(), access_token)
+    async fn delete_async(&'b self, client: &Session) -> Result<()> {
+        client.comment_delete(self.id()).await
     }
 
-    fn replies_async<'a>(&'b self, client: &'a TraktApi) -> PaginationRequest<'a, Comment> {
+    fn replies_async(&'b self, client: &TraktApi) -> PaginationRequest<Comment> {
         client.replies(self.id())
     }
 
-    fn reply_post_async<'a>(
+    fn reply_post_async(
         &'b self,
-        client: &'a TraktApi,
+        client: &Session,
         comment: String,
-    ) -> CommentPostRequest<'a> {
+    ) -> CommentPostRequest<Session> {
         client.replies_post(self.id(), comment)
     }
 
-    fn item_async(&'b self, client: &TraktApi) -> Result<CommentItem> {
-        client.comment_item(self.id())
+    async fn item_async(&'b self, client: &TraktApi) -> Result<CommentItem> {
+        client.comment_item(self.id()).await
     }
 
-    fn likes_async<'a>(&'b self, client: &'a TraktApi) -> PaginationRequest<'a, Like> {
+    fn likes_async(&'b self, client: &TraktApi) -> PaginationRequest<Like> {
         client.comment_likes(self.id())
     }
 
-    fn like_async(&'b self, client: &TraktApi, access_token: &str) -> Result<()> {
-        client.comment_like(self.id(), access_token)
+    async fn like_async(&'b self, client: &Session) -> Result<()> {
+        client.comment_like(self.id()).await
     }
 
-    fn like_delete_async(&'b self, client: &TraktApi, access_token: &str) -> Result<()> {
-        client.comment_like_delete(self.id(), access_token)
+    async fn like_delete_async(&'b self, client: &Session) -> Result<()> {
+        client.comment_like_delete(self.id()).await
     }
 
-    fn fetch(&'b self, client: &TraktApi) -> Result<Comment> {
-        client.comment(self.id())
+    async fn fetch(&'b self, client: &TraktApi) -> Result<Comment> {
+        client.comment(self.id()).await
     }
 }
 
@@ -182,6 +182,29 @@ impl fmt::Display for CommentType {
     }
 }
 
+/// For sorting [comment]s
+///
+/// [comment]: https://trakt.docs.apiary.io/#reference/comments
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentSort {
+    Newest,
+    Oldest,
+    Likes,
+    Replies,
+}
+
+impl fmt::Display for CommentSort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CommentSort::Newest => "newest",
+            CommentSort::Oldest => "oldest",
+            CommentSort::Likes => "likes",
+            CommentSort::Replies => "replies",
+        })
+    }
+}
+
 /// For specifying where a comment should be shared
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CommentSharing {
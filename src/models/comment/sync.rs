@@ -1,25 +1,24 @@
 use crate::{
     models::{Comment, CommentAndItem, CommentItem, FullComment, FullCommentAndItem, Like, ToId},
-    sync::{
-        pagination::PaginationRequest, requests::comments::comment_post_request::CommentPostRequest,
-    },
-    Result, TraktApi,
+    requests::comments::comment_post_request::CommentPostRequest,
+    sync::pagination::PaginationRequest,
+    Result, Session, TraktApi,
 };
 
 pub trait CommentMethods<'b>: ToId<'b, u32> {
-    fn update<'a>(&'b self, client: &'a TraktApi, comment: String) -> CommentPostRequest<'a> {
+    fn update(&'b self, client: &Session, comment: String) -> CommentPostRequest<Session> {
         client.comment_update(self.id(), comment)
     }
 
-    fn delete(&'b self, client: &TraktApi, access_token: &str) -> Result<()> {
-        client.comment_delete(self.id(), access_token)
+    fn delete(&'b self, client: &Session) -> Result<()> {
+        client.comment_delete(self.id())
     }
 
-    fn replies<'a>(&'b self, client: &'a TraktApi) -> PaginationRequest<'a, Comment> {
+    fn replies(&'b self, client: &TraktApi) -> PaginationRequest<Comment> {
         client.replies(self.id())
     }
 
-    fn reply_post<'a>(&'b self, client: &'a TraktApi, comment: String) -> CommentPostRequest<'a> {
+    fn reply_post(&'b self, client: &Session, comment: String) -> CommentPostRequest<Session> {
         client.replies_post(self.id(), comment)
     }
 
@@ -27,16 +26,16 @@ pub trait CommentMethods<'b>: ToId<'b, u32> {
         client.comment_item(self.id())
     }
 
-    fn likes<'a>(&'b self, client: &'a TraktApi) -> PaginationRequest<'a, Like> {
+    fn likes(&'b self, client: &TraktApi) -> PaginationRequest<Like> {
         client.comment_likes(self.id())
     }
 
-    fn like(&'b self, client: &TraktApi, access_token: &str) -> Result<()> {
-        client.comment_like(self.id(), access_token)
+    fn like(&'b self, client: &Session) -> Result<()> {
+        client.comment_like(self.id())
     }
 
-    fn like_delete(&'b self, client: &TraktApi, access_token: &str) -> Result<()> {
-        client.comment_like_delete(self.id(), access_token)
+    fn like_delete(&'b self, client: &Session) -> Result<()> {
+        client.comment_like_delete(self.id())
     }
 
     fn fetch(&'b self, client: &TraktApi) -> Result<Comment> {
@@ -8,54 +8,60 @@ use crate::{
 use std::fmt::Display;
 
 pub trait EpisodeMethods<'b>: ToId<'b, (u32, u32)> {
-    fn translations(
+    async fn translations(
         &'b self,
         client: &TraktApi,
         show: impl Display,
         language: impl Display,
     ) -> Result<Vec<Translation>> {
-        client.episode_translations(show, self.id().0, self.id().1, language)
+        client
+            .episode_translations(show, self.id().0, self.id().1, language)
+            .await
     }
 
-    fn comments<'a>(
-        &'b self,
-        client: &'a TraktApi,
-        show: impl Display,
-    ) -> PaginationRequest<'a, Comment> {
+    fn comments(&'b self, client: &TraktApi, show: impl Display) -> PaginationRequest<Comment> {
         client.episode_comments(show, self.id().0, self.id().1)
     }
 
-    fn lists<'a>(
+    fn lists(
         &'b self,
-        client: &'a TraktApi,
+        client: &TraktApi,
         show: impl Display,
         f: impl FnOnce(ListFactory) -> ListFactory,
-    ) -> PaginationRequest<'a, List> {
+    ) -> PaginationRequest<List> {
         client.episode_lists(show, self.id().0, self.id().1, f)
     }
 
-    fn ratings(&'b self, client: &TraktApi, show: impl Display) -> Result<Ratings> {
-        client.episode_ratings(show, self.id().0, self.id().1)
+    async fn ratings(&'b self, client: &TraktApi, show: impl Display) -> Result<Ratings> {
+        client.episode_ratings(show, self.id().0, self.id().1).await
     }
 
-    fn stats(&'b self, client: &TraktApi, show: impl Display) -> Result<MediaStats> {
-        client.episode_stats(show, self.id().0, self.id().1)
+    async fn stats(&'b self, client: &TraktApi, show: impl Display) -> Result<MediaStats> {
+        client.episode_stats(show, self.id().0, self.id().1).await
     }
 
-    fn watching(&'b self, client: &TraktApi, show: impl Display) -> Result<Vec<User>> {
-        client.episode_watching(show, self.id().0, self.id().1)
+    async fn watching(&'b self, client: &TraktApi, show: impl Display) -> Result<Vec<User>> {
+        client
+            .episode_watching(show, self.id().0, self.id().1)
+            .await
     }
 
-    fn watching_full(&'b self, client: &TraktApi, show: impl Display) -> Result<Vec<FullUser>> {
-        client.episode_watching_full(show, self.id().0, self.id().1)
+    async fn watching_full(
+        &'b self,
+        client: &TraktApi,
+        show: impl Display,
+    ) -> Result<Vec<FullUser>> {
+        client
+            .episode_watching_full(show, self.id().0, self.id().1)
+            .await
     }
 
-    fn fetch(&'b self, client: &TraktApi, show: impl Display) -> Result<Episode> {
-        client.episode(show, self.id().0, self.id().1)
+    async fn fetch(&'b self, client: &TraktApi, show: impl Display) -> Result<Episode> {
+        client.episode(show, self.id().0, self.id().1).await
     }
 
-    fn fetch_full(&'b self, client: &TraktApi, show: impl Display) -> Result<FullEpisode> {
-        client.episode_full(show, self.id().0, self.id().1)
+    async fn fetch_full(&'b self, client: &TraktApi, show: impl Display) -> Result<FullEpisode> {
+        client.episode_full(show, self.id().0, self.id().1).await
     }
 }
 
@@ -18,20 +18,16 @@ pub trait EpisodeMethods<'b>: ToId<'b, (u32, u32)> {
         client.episode_translations(show, self.id().0, self.id().1, language)
     }
 
-    fn comments<'a>(
-        &'b self,
-        client: &'a TraktApi,
-        show: impl Display,
-    ) -> PaginationRequest<'a, Comment> {
+    fn comments(&'b self, client: &TraktApi, show: impl Display) -> PaginationRequest<Comment> {
         client.episode_comments(show, self.id().0, self.id().1)
     }
 
-    fn lists<'a>(
+    fn lists(
         &'b self,
-        client: &'a TraktApi,
+        client: &TraktApi,
         show: impl Display,
         f: impl FnOnce(ListFactory) -> ListFactory,
-    ) -> PaginationRequest<'a, List> {
+    ) -> PaginationRequest<List> {
         client.episode_lists(show, self.id().0, self.id().1, f)
     }
 
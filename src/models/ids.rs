@@ -83,47 +83,47 @@ impl Ids {
 
 #[cfg(feature = "async")]
 impl Ids {
-    pub fn lookup_trakt_async(
+    pub async fn lookup_trakt_async(
         &self,
         client: &AsyncTraktApi,
         item_type: Option<SearchItemType>,
     ) -> AsyncResult<Vec<SearchResult>> {
         match self.trakt {
-            Some(id) => client.id_lookup(IdType::Trakt, id, item_type),
-            None => Box::new(futures::future::err(Error::NoneError)),
+            Some(id) => client.id_lookup(IdType::Trakt, id, item_type).await,
+            None => Err(Error::NoneError),
         }
     }
 
-    pub fn lookup_imdb_async(
+    pub async fn lookup_imdb_async(
         &self,
         client: &AsyncTraktApi,
         item_type: Option<SearchItemType>,
     ) -> AsyncResult<Vec<SearchResult>> {
         match &self.imdb {
-            Some(id) => client.id_lookup(IdType::IMDb, id, item_type),
-            None => Box::new(futures::future::err(Error::NoneError)),
+            Some(id) => client.id_lookup(IdType::IMDb, id, item_type).await,
+            None => Err(Error::NoneError),
         }
     }
 
-    pub fn lookup_tmdb_async(
+    pub async fn lookup_tmdb_async(
         &self,
         client: &AsyncTraktApi,
         item_type: Option<SearchItemType>,
     ) -> AsyncResult<Vec<SearchResult>> {
         match self.tmdb {
-            Some(id) => client.id_lookup(IdType::TMDb, id, item_type),
-            None => Box::new(futures::future::err(Error::NoneError)),
+            Some(id) => client.id_lookup(IdType::TMDb, id, item_type).await,
+            None => Err(Error::NoneError),
         }
     }
 
-    pub fn lookup_tvdb_async(
+    pub async fn lookup_tvdb_async(
         &self,
         client: &AsyncTraktApi,
         item_type: Option<SearchItemType>,
     ) -> AsyncResult<Vec<SearchResult>> {
         match self.tvdb {
-            Some(id) => client.id_lookup(IdType::TVDB, id, item_type),
-            None => Box::new(futures::future::err(Error::NoneError)),
+            Some(id) => client.id_lookup(IdType::TVDB, id, item_type).await,
+            None => Err(Error::NoneError),
         }
     }
 }
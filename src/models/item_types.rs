@@ -2,7 +2,7 @@ use std::fmt;
 use std::fmt::Display;
 
 /// All item types that can be put in a list
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum ListItemType {
     Movie,
@@ -12,6 +12,18 @@ pub enum ListItemType {
     Person,
 }
 
+impl Display for ListItemType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ListItemType::Movie => "movie",
+            ListItemType::Show => "show",
+            ListItemType::Season => "season",
+            ListItemType::Episode => "episode",
+            ListItemType::Person => "person",
+        })
+    }
+}
+
 /// All item types that can be commented
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -49,7 +61,7 @@ impl Display for AllCommentableItemType {
 }
 
 /// All media item types
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum ItemType {
     Movie,
@@ -70,7 +82,7 @@ impl Display for ItemType {
 }
 
 /// All media item types and an All variant
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum AllItemType {
     Movie,
@@ -93,7 +105,7 @@ impl Display for AllItemType {
 }
 
 /// All item types that can be watched
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum WatchableType {
     #[serde(rename = "movie")]
     Movie,
@@ -111,7 +123,7 @@ impl Display for WatchableType {
 }
 
 /// Movies or Shows enum
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum MediaType {
     #[serde(rename = "movies")]
     Movies,
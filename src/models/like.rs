@@ -4,7 +4,7 @@ use std::fmt;
 use std::fmt::Display;
 
 /// All items that can be liked (not rated)
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum LikeableType {
     Comment,
@@ -0,0 +1,63 @@
+//! [ListMethods] for the async client
+//!
+//! [ListMethods]: trait.ListMethods.html
+use crate::{
+    asyn::{pagination::PaginationRequest, Result, Session, TraktApi},
+    models::{Comment, CommentSort, FullList, Like, List, ListItem, ListItemType, ToId},
+};
+
+/// Requests about a [List] or [FullList] that take the list's trakt id from the list itself
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use trakt::{asyn::TraktApi, models::list::asyn::ListMethods};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let api = TraktApi::new("...".to_owned(), None);
+///
+///     let list = api.list(55).await.unwrap();
+///     dbg!(list.likes(&api).execute().await.unwrap());
+/// }
+/// ```
+///
+/// [List]: ../struct.List.html
+/// [FullList]: ../struct.FullList.html
+#[allow(async_fn_in_trait)]
+pub trait ListMethods<'b>: ToId<'b, u64> {
+    fn items(
+        &'b self,
+        client: &TraktApi,
+        item_type: Option<ListItemType>,
+    ) -> PaginationRequest<ListItem> {
+        client.list_items(self.id(), item_type)
+    }
+
+    fn likes(&'b self, client: &TraktApi) -> PaginationRequest<Like> {
+        client.list_likes(self.id())
+    }
+
+    fn comments(&'b self, client: &TraktApi, sort: CommentSort) -> PaginationRequest<Comment> {
+        client.list_comments(self.id(), sort)
+    }
+
+    async fn like(&'b self, client: &Session) -> Result<()> {
+        client.list_like(self.id()).await
+    }
+
+    async fn unlike(&'b self, client: &Session) -> Result<()> {
+        client.list_unlike(self.id()).await
+    }
+
+    async fn fetch(&'b self, client: &TraktApi) -> Result<List> {
+        client.list(self.id()).await
+    }
+
+    async fn fetch_full(&'b self, client: &TraktApi) -> Result<FullList> {
+        client.list_full(self.id()).await
+    }
+}
+
+impl<'a> ListMethods<'a> for List {}
+impl<'a> ListMethods<'a> for FullList {}
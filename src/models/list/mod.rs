@@ -1,10 +1,18 @@
 //! All models related to [lists]
 //!
 //! [lists]: https://trakt.docs.apiary.io/#reference/lists
-use crate::models::{FullEpisode, FullMovie, FullSeason, FullShow};
+#[cfg(feature = "async")]
+pub mod asyn;
+#[cfg(feature = "sync")]
+pub mod sync;
+
+use crate::models::{FullEpisode, FullMovie, FullSeason, FullShow, ToId};
 use crate::{
     extended_info::{WithFull, WithNone},
-    models::{Episode, FullUser, Ids, ListItemType, Movie, OptionUser, Person, Season, Show, User},
+    models::{
+        Episode, FullUser, Ids, ListItemType, Movie, OptionEpisode, OptionMovie, OptionPerson,
+        OptionSeason, OptionShow, OptionUser, Person, Season, Show, User,
+    },
 };
 use chrono::{DateTime, Utc};
 use std::fmt;
@@ -30,6 +38,12 @@ pub struct List {
     pub user: User,
 }
 
+impl<'a> ToId<'a, u64> for List {
+    fn id(&'a self) -> u64 {
+        self.ids.trakt.unwrap()
+    }
+}
+
 impl PartialEq for List {
     fn eq(&self, other: &List) -> bool {
         self.name == other.name
@@ -67,6 +81,12 @@ pub struct FullList {
     pub user: FullUser,
 }
 
+impl<'a> ToId<'a, u64> for FullList {
+    fn id(&'a self) -> u64 {
+        self.ids.trakt.unwrap()
+    }
+}
+
 impl PartialEq for FullList {
     fn eq(&self, other: &FullList) -> bool {
         self.name == other.name
@@ -197,7 +217,9 @@ pub struct ListInfo {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ListItem {
     pub rank: u32,
+    pub id: Option<u64>,
     pub listed_at: DateTime<Utc>,
+    pub notes: Option<String>,
     #[serde(rename = "type")]
     pub item_type: ListItemType,
     pub movie: Option<Movie>,
@@ -218,7 +240,9 @@ impl WithFull for ListItem {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FullListItem {
     pub rank: u32,
+    pub id: Option<u64>,
     pub listed_at: DateTime<Utc>,
+    pub notes: Option<String>,
     #[serde(rename = "type")]
     pub item_type: ListItemType,
     pub movie: Option<FullMovie>,
@@ -232,6 +256,72 @@ impl WithNone for FullListItem {
     type None = ListItem;
 }
 
+/// Number of affected items in a [ListItemsAddResponse] or [ListItemsRemoveResponse]
+///
+/// [ListItemsAddResponse]: struct.ListItemsAddResponse.html
+/// [ListItemsRemoveResponse]: struct.ListItemsRemoveResponse.html
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ListItemsNumbers {
+    pub movies: u32,
+    pub shows: u32,
+    pub seasons: u32,
+    pub episodes: u32,
+    pub people: u32,
+}
+
+/// Items of a [ListItemsAddResponse] or [ListItemsRemoveResponse] which weren't found
+///
+/// [ListItemsAddResponse]: struct.ListItemsAddResponse.html
+/// [ListItemsRemoveResponse]: struct.ListItemsRemoveResponse.html
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListItemsNotFound {
+    pub movies: Vec<OptionMovie>,
+    pub shows: Vec<OptionShow>,
+    pub seasons: Vec<OptionSeason>,
+    pub episodes: Vec<OptionEpisode>,
+    pub people: Vec<OptionPerson>,
+}
+
+/// The state of a [list] after changing its items
+///
+/// [list]: https://trakt.docs.apiary.io/#reference/users/list-items
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ListItemsUpdate {
+    pub updated_at: DateTime<Utc>,
+    pub item_count: u64,
+}
+
+/// [API docs]
+///
+/// [API docs]: https://trakt.docs.apiary.io/#reference/users/add-list-items/add-items-to-personal-list
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListItemsAddResponse {
+    pub added: ListItemsNumbers,
+    pub existing: ListItemsNumbers,
+    pub not_found: ListItemsNotFound,
+    pub list: ListItemsUpdate,
+}
+
+/// [API docs]
+///
+/// [API docs]: https://trakt.docs.apiary.io/#reference/users/remove-list-items/remove-items-from-personal-list
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListItemsRemoveResponse {
+    pub deleted: ListItemsNumbers,
+    pub not_found: ListItemsNotFound,
+    pub list: ListItemsUpdate,
+}
+
+/// [API docs]
+///
+/// [API docs]: https://trakt.docs.apiary.io/#reference/users/reorder-list-items/reorder-items-on-a-list
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ListItemsReorderResponse {
+    pub updated: u32,
+    pub skipped_ids: Vec<u64>,
+    pub list: ListItemsUpdate,
+}
+
 /// The type of a [list]
 ///
 /// [list]: https://trakt.docs.apiary.io/#reference/lists
@@ -255,6 +345,33 @@ impl fmt::Display for ListType {
     }
 }
 
+/// Who can see a [list]
+///
+/// [list]: https://trakt.docs.apiary.io/#reference/users/lists
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ListPrivacy {
+    /// Only the owner
+    Private,
+    /// Everyone who has the link
+    Link,
+    /// The friends of the owner
+    Friends,
+    /// Everyone
+    Public,
+}
+
+impl fmt::Display for ListPrivacy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ListPrivacy::Private => "private",
+            ListPrivacy::Link => "link",
+            ListPrivacy::Friends => "friends",
+            ListPrivacy::Public => "public",
+        })
+    }
+}
+
 /// An enum for filtering [list]s
 ///
 /// [list]: https://trakt.docs.apiary.io/#reference/lists
@@ -0,0 +1,60 @@
+//! [ListMethods] for the blocking client
+//!
+//! [ListMethods]: trait.ListMethods.html
+use crate::{
+    models::{Comment, CommentSort, FullList, Like, List, ListItem, ListItemType, ToId},
+    sync::pagination::PaginationRequest,
+    Result, Session, TraktApi,
+};
+
+/// Requests about a [List] or [FullList] that take the list's trakt id from the list itself
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use trakt::{models::list::sync::ListMethods, TraktApi};
+///
+/// let api = TraktApi::new("...".to_owned(), None);
+///
+/// let list = api.list(55).unwrap();
+/// dbg!(list.likes(&api).execute().unwrap());
+/// ```
+///
+/// [List]: ../struct.List.html
+/// [FullList]: ../struct.FullList.html
+pub trait ListMethods<'b>: ToId<'b, u64> {
+    fn items(
+        &'b self,
+        client: &TraktApi,
+        item_type: Option<ListItemType>,
+    ) -> PaginationRequest<ListItem> {
+        client.list_items(self.id(), item_type)
+    }
+
+    fn likes(&'b self, client: &TraktApi) -> PaginationRequest<Like> {
+        client.list_likes(self.id())
+    }
+
+    fn comments(&'b self, client: &TraktApi, sort: CommentSort) -> PaginationRequest<Comment> {
+        client.list_comments(self.id(), sort)
+    }
+
+    fn like(&'b self, client: &Session) -> Result<()> {
+        client.list_like(self.id())
+    }
+
+    fn unlike(&'b self, client: &Session) -> Result<()> {
+        client.list_unlike(self.id())
+    }
+
+    fn fetch(&'b self, client: &TraktApi) -> Result<List> {
+        client.list(self.id())
+    }
+
+    fn fetch_full(&'b self, client: &TraktApi) -> Result<FullList> {
+        client.list_full(self.id())
+    }
+}
+
+impl<'a> ListMethods<'a> for List {}
+impl<'a> ListMethods<'a> for FullList {}
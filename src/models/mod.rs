@@ -26,7 +26,9 @@ pub mod watched;
 pub mod watching;
 
 pub use crate::models::{
-    authentication::{AuthenticationDevices, AuthenticationTokenResponse},
+    authentication::{
+        AuthenticationDevices, AuthenticationTokenResponse, DeviceCodeProgress, DeviceCodeStatus,
+    },
     calendar::{CalendarMovie, CalendarShow, FullCalendarMovie, FullCalendarShow},
     certifications::{Certification, Certifications, CertificationsType},
     collection::{
@@ -34,8 +36,8 @@ pub use crate::models::{
         FullCollectionShow,
     },
     comment::{
-        Comment, CommentAndItem, CommentItem, CommentSharing, CommentType, FullComment,
-        FullCommentAndItem,
+        Comment, CommentAndItem, CommentItem, CommentSharing, CommentSort, CommentType,
+        FullComment, FullCommentAndItem,
     },
     episode::{Episode, FullEpisode, OptionEpisode},
     genre::Genre,
@@ -47,15 +49,18 @@ pub use crate::models::{
     },
     like::Like,
     list::{
-        FullList, FullListItem, List, ListFactory, ListFilter, ListInfo, ListItem, ListSort,
-        ListType,
+        FullList, FullListItem, List, ListFactory, ListFilter, ListInfo, ListItem,
+        ListItemsAddResponse, ListItemsNotFound, ListItemsNumbers, ListItemsRemoveResponse,
+        ListItemsReorderResponse, ListItemsUpdate, ListPrivacy, ListSort, ListType,
     },
     localization::{Alias, Country, Language, Translation},
     movie::{
         AnticipatedMovie, FullMovie, Movie, MovieInfo, OptionMovie, UpdatedMovie, WatchedMovie,
     },
     network::Network,
-    people::{CastPerson, Character, Credits, Crew, CrewMember, CrewPerson, People, Person},
+    people::{
+        CastPerson, Character, Credits, Crew, CrewMember, CrewPerson, OptionPerson, People, Person,
+    },
     progress::{
         CollectionProgress, CollectionProgressEpisode, CollectionProgressSeason, WatchedProgress,
         WatchedProgressEpisode, WatchedProgressSeason,
@@ -74,7 +79,9 @@ pub use crate::models::{
         LastActivities, LastActivitiesElement, Playback, SyncAddResponse, SyncRemoveResponse,
         SyncResponseNotFound, SyncResponseNumbers,
     },
-    user::{FullUser, OptionUser, User},
+    user::{
+        Follow, FollowedUser, Friend, FullFollowedUser, FullFriend, FullUser, OptionUser, User,
+    },
     watched::{FullWatchedEntry, WatchedEntry, WatchedEpisode, WatchedSeason},
     watching::Watching,
 };
@@ -12,6 +12,17 @@ pub struct Person {
     pub ids: Ids,
 }
 
+/// A [Person] with only optional fields
+///
+/// [Person]: struct.Person.html
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OptionPerson {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Ids>,
+}
+
 /// [Credits] of a [person]
 ///
 /// [Credits]: https://trakt.docs.apiary.io/#reference/people/movies/get-movie-credits
@@ -45,26 +45,20 @@ impl SearchType {
 
 impl Display for SearchType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut s = String::new();
+        let types = [
+            (self.movie, "movie"),
+            (self.show, "show"),
+            (self.episode, "episode"),
+            (self.person, "person"),
+            (self.list, "list"),
+        ];
 
-        if self.movie {
-            s.push_str("movie,");
-        }
-        if self.show {
-            s.push_str("show,");
-        }
-        if self.episode {
-            s.push_str("episode,");
-        }
-        if self.person {
-            s.push_str("person,");
-        }
-        if self.list {
-            s.push_str("list,");
-        }
-
-        //s.remove(s.len()-1);
+        let types: Vec<&str> = types
+            .iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, name)| *name)
+            .collect();
 
-        f.write_str(s.as_str())
+        f.write_str(&types.join(","))
     }
 }
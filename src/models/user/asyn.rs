@@ -1,42 +1,88 @@
+//! [UserMethods] for the async client
+//!
+//! [UserMethods]: trait.UserMethods.html
 use crate::{
-    asyn::{pagination::PaginationRequest, Result, TraktApi},
+    asyn::{pagination::PaginationRequest, Result, Session, TraktApi},
     models::{
-        comment::GetComments, CollectionMovie, CollectionShow, CommentAndItem, FullUser, ToId, User,
+        comment::GetComments, CollectionMovie, CollectionShow, CommentAndItem, Follow,
+        FollowedUser, Friend, FullFollowedUser, FullFriend, FullUser, ToId, User,
     },
 };
 
+/// Requests about an [User] or [FullUser] that take the user's slug from the user itself
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use trakt::{asyn::TraktApi, models::user::asyn::UserMethods};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let api = TraktApi::new("...".to_owned(), None);
+///
+///     let user = api.user_profile("sean").await.unwrap();
+///     dbg!(user.followers(&api).await.unwrap());
+/// }
+/// ```
+///
+/// [User]: ../struct.User.html
+/// [FullUser]: ../struct.FullUser.html
+#[allow(async_fn_in_trait)]
 pub trait UserMethods<'a>: ToId<'a, &'a str> {
-    fn fetch(&'a self, client: &TraktApi, access_token: Option<&str>) -> Result<User> {
-        client.user_profile(self.id(), access_token)
+    async fn fetch(&'a self, client: &TraktApi) -> Result<User> {
+        client.user_profile(self.id()).await
     }
 
-    fn fetch_full(&'a self, client: &TraktApi, access_token: Option<&str>) -> Result<FullUser> {
-        client.user_profile_full(self.id(), access_token)
+    async fn fetch_full(&'a self, client: &TraktApi) -> Result<FullUser> {
+        client.user_profile_full(self.id()).await
     }
 
-    fn collection_movies(
-        &'a self,
-        client: &TraktApi,
-        access_token: Option<&str>,
-    ) -> Result<Vec<CollectionMovie>> {
-        client.user_collection_movies(self.id(), access_token)
+    async fn collection_movies(&'a self, client: &TraktApi) -> Result<Vec<CollectionMovie>> {
+        client.user_collection_movies(self.id()).await
     }
 
-    fn collection_shows(
-        &'a self,
-        client: &TraktApi,
-        access_token: Option<&str>,
-    ) -> Result<Vec<CollectionShow>> {
-        client.user_collection_shows(self.id(), access_token)
+    async fn collection_shows(&'a self, client: &TraktApi) -> Result<Vec<CollectionShow>> {
+        client.user_collection_shows(self.id()).await
     }
 
     fn comments(
         &'a self,
-        client: &'a TraktApi,
-        access_token: Option<&str>,
+        client: &TraktApi,
         f: impl FnOnce(GetComments) -> GetComments,
     ) -> PaginationRequest<CommentAndItem> {
-        client.user_comments(self.id(), f, access_token)
+        client.user_comments(self.id(), f)
+    }
+
+    async fn follow(&'a self, client: &Session) -> Result<Follow> {
+        client.user_follow(self.id()).await
+    }
+
+    async fn unfollow(&'a self, client: &Session) -> Result<()> {
+        client.user_unfollow(self.id()).await
+    }
+
+    async fn followers(&'a self, client: &TraktApi) -> Result<Vec<FollowedUser>> {
+        client.user_followers(self.id()).await
+    }
+
+    async fn followers_full(&'a self, client: &TraktApi) -> Result<Vec<FullFollowedUser>> {
+        client.user_followers_full(self.id()).await
+    }
+
+    async fn following(&'a self, client: &TraktApi) -> Result<Vec<FollowedUser>> {
+        client.user_following(self.id()).await
+    }
+
+    async fn following_full(&'a self, client: &TraktApi) -> Result<Vec<FullFollowedUser>> {
+        client.user_following_full(self.id()).await
+    }
+
+    async fn friends(&'a self, client: &TraktApi) -> Result<Vec<Friend>> {
+        client.user_friends(self.id()).await
+    }
+
+    async fn friends_full(&'a self, client: &TraktApi) -> Result<Vec<FullFriend>> {
+        client.user_friends_full(self.id()).await
     }
 }
 
@@ -2,11 +2,14 @@
 //!
 //! [users]: https://trakt.docs.apiary.io/#reference/users
 #[cfg(feature = "async")]
-mod asyn;
+pub mod asyn;
 #[cfg(feature = "sync")]
-mod sync;
+pub mod sync;
 
-use crate::models::{Ids, ToId};
+use crate::{
+    extended_info::{WithFull, WithNone},
+    models::{Ids, ToId},
+};
 use chrono::{DateTime, Utc};
 
 /// An [user]
@@ -197,3 +200,94 @@ pub struct FollowRequestApprove {
     pub followed_at: DateTime<Utc>,
     pub user: User,
 }
+
+/// Response after [following an user]
+///
+/// Following a private user only sends a follow request, which is pending until the user
+/// approves it
+///
+/// [following an user]: https://trakt.docs.apiary.io/#reference/users/follow/follow-this-user
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(from = "FollowResponse")]
+pub enum Follow {
+    /// The user is followed since `approved_at`
+    Approved {
+        approved_at: DateTime<Utc>,
+        user: User,
+    },
+    /// The follow request wasn't approved yet
+    Pending { user: User },
+}
+
+#[derive(Deserialize)]
+struct FollowResponse {
+    approved_at: Option<DateTime<Utc>>,
+    user: User,
+}
+
+impl From<FollowResponse> for Follow {
+    fn from(res: FollowResponse) -> Self {
+        match res.approved_at {
+            Some(approved_at) => Follow::Approved {
+                approved_at,
+                user: res.user,
+            },
+            None => Follow::Pending { user: res.user },
+        }
+    }
+}
+
+/// A [follower] or a [followed user] of an user
+///
+/// [follower]: https://trakt.docs.apiary.io/#reference/users/followers/get-followers
+/// [followed user]: https://trakt.docs.apiary.io/#reference/users/following/get-following
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct FollowedUser {
+    pub followed_at: DateTime<Utc>,
+    pub user: User,
+}
+
+impl WithFull for FollowedUser {
+    type Full = FullFollowedUser;
+}
+
+/// A [FollowedUser] with full [extended info]
+///
+/// [FollowedUser]: struct.FollowedUser.html
+/// [extended info]: https://trakt.docs.apiary.io/#introduction/extended-info
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct FullFollowedUser {
+    pub followed_at: DateTime<Utc>,
+    pub user: FullUser,
+}
+
+impl WithNone for FullFollowedUser {
+    type None = FollowedUser;
+}
+
+/// A [friend] of an user, who follows the user and is followed back
+///
+/// [friend]: https://trakt.docs.apiary.io/#reference/users/friends/get-friends
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Friend {
+    pub friends_at: DateTime<Utc>,
+    pub user: User,
+}
+
+impl WithFull for Friend {
+    type Full = FullFriend;
+}
+
+/// A [Friend] with full [extended info]
+///
+/// [Friend]: struct.Friend.html
+/// [extended info]: https://trakt.docs.apiary.io/#introduction/extended-info
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct FullFriend {
+    pub friends_at: DateTime<Utc>,
+    pub user: FullUser,
+}
+
+impl WithNone for FullFriend {
+    type None = Friend;
+}
@@ -1,43 +1,85 @@
+//! [UserMethods] for the blocking client
+//!
+//! [UserMethods]: trait.UserMethods.html
 use crate::{
     models::{
-        comment::GetComments, CollectionMovie, CollectionShow, CommentAndItem, FullUser, ToId, User,
+        comment::GetComments, CollectionMovie, CollectionShow, CommentAndItem, Follow,
+        FollowedUser, Friend, FullFollowedUser, FullFriend, FullUser, ToId, User,
     },
     sync::pagination::PaginationRequest,
-    Result, TraktApi,
+    Result, Session, TraktApi,
 };
 
+/// Requests about an [User] or [FullUser] that take the user's slug from the user itself
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use trakt::{models::user::sync::UserMethods, TraktApi};
+///
+/// let api = TraktApi::new("...".to_owned(), None);
+///
+/// let user = api.user_profile("sean").unwrap();
+/// dbg!(user.followers(&api).unwrap());
+/// ```
+///
+/// [User]: ../struct.User.html
+/// [FullUser]: ../struct.FullUser.html
 pub trait UserMethods<'a>: ToId<'a, &'a str> {
-    fn fetch(&'a self, client: &TraktApi, access_token: Option<&str>) -> Result<User> {
-        client.user_profile(self.id(), access_token)
+    fn fetch(&'a self, client: &TraktApi) -> Result<User> {
+        client.user_profile(self.id())
     }
 
-    fn fetch_full(&'a self, client: &TraktApi, access_token: Option<&str>) -> Result<FullUser> {
-        client.user_profile_full(self.id(), access_token)
+    fn fetch_full(&'a self, client: &TraktApi) -> Result<FullUser> {
+        client.user_profile_full(self.id())
     }
 
-    fn collection_movies(
-        &'a self,
-        client: &TraktApi,
-        access_token: Option<&str>,
-    ) -> Result<Vec<CollectionMovie>> {
-        client.user_collection_movies(self.id(), access_token)
+    fn collection_movies(&'a self, client: &TraktApi) -> Result<Vec<CollectionMovie>> {
+        client.user_collection_movies(self.id())
     }
 
-    fn collection_shows(
-        &'a self,
-        client: &TraktApi,
-        access_token: Option<&str>,
-    ) -> Result<Vec<CollectionShow>> {
-        client.user_collection_shows(self.id(), access_token)
+    fn collection_shows(&'a self, client: &TraktApi) -> Result<Vec<CollectionShow>> {
+        client.user_collection_shows(self.id())
     }
 
     fn comments(
         &'a self,
-        client: &'a TraktApi,
-        access_token: Option<&str>,
+        client: &TraktApi,
         f: impl FnOnce(GetComments) -> GetComments,
     ) -> PaginationRequest<CommentAndItem> {
-        client.user_comments(self.id(), f, access_token)
+        client.user_comments(self.id(), f)
+    }
+
+    fn follow(&'a self, client: &Session) -> Result<Follow> {
+        client.user_follow(self.id())
+    }
+
+    fn unfollow(&'a self, client: &Session) -> Result<()> {
+        client.user_unfollow(self.id())
+    }
+
+    fn followers(&'a self, client: &TraktApi) -> Result<Vec<FollowedUser>> {
+        client.user_followers(self.id())
+    }
+
+    fn followers_full(&'a self, client: &TraktApi) -> Result<Vec<FullFollowedUser>> {
+        client.user_followers_full(self.id())
+    }
+
+    fn following(&'a self, client: &TraktApi) -> Result<Vec<FollowedUser>> {
+        client.user_following(self.id())
+    }
+
+    fn following_full(&'a self, client: &TraktApi) -> Result<Vec<FullFollowedUser>> {
+        client.user_following_full(self.id())
+    }
+
+    fn friends(&'a self, client: &TraktApi) -> Result<Vec<Friend>> {
+        client.user_friends(self.id())
+    }
+
+    fn friends_full(&'a self, client: &TraktApi) -> Result<Vec<FullFriend>> {
+        client.user_friends_full(self.id())
     }
 }
 
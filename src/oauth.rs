@@ -0,0 +1,233 @@
+//! Options of the [OAuth authorization] and [PKCE]
+//!
+//! [OAuth authorization]: https://trakt.docs.apiary.io/#reference/authentication-oauth/authorize
+//! [PKCE]: https://tools.ietf.org/html/rfc7636
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+
+/// The parameters of the authorization page the user is sent to
+///
+/// # Example
+///
+/// ```rust
+/// use trakt::{
+///     oauth::{AuthorizeOptions, Pkce},
+///     TraktApi,
+/// };
+///
+/// let api = TraktApi::new("CLIENT_ID".to_owned(), None);
+/// let pkce = Pkce::new();
+///
+/// let url = api.oauth_authorize(
+///     &AuthorizeOptions::new("urn:ietf:wg:oauth:2.0:oob")
+///         .state("STATE")
+///         .pkce(&pkce),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorizeOptions {
+    pub redirect_uri: String,
+    pub state: Option<String>,
+    /// The S256 challenge of a PKCE code verifier
+    pub code_challenge: Option<String>,
+    /// Shows the signup page instead of the login page
+    pub signup: bool,
+    /// Asks the user to log in even if already logged in
+    pub prompt_login: bool,
+}
+
+impl AuthorizeOptions {
+    pub fn new(redirect_uri: &str) -> Self {
+        Self {
+            redirect_uri: redirect_uri.to_owned(),
+            state: None,
+            code_challenge: None,
+            signup: false,
+            prompt_login: false,
+        }
+    }
+
+    /// Sets the state which trakt sends back with the redirect
+    pub fn state(mut self, state: &str) -> Self {
+        self.state = Some(state.to_owned());
+        self
+    }
+
+    /// Sends the challenge of `pkce`. Its verifier is needed to get the token then.
+    pub fn pkce(mut self, pkce: &Pkce) -> Self {
+        self.code_challenge = Some(pkce.challenge.clone());
+        self
+    }
+
+    pub fn signup(mut self, signup: bool) -> Self {
+        self.signup = signup;
+        self
+    }
+
+    pub fn prompt_login(mut self, prompt_login: bool) -> Self {
+        self.prompt_login = prompt_login;
+        self
+    }
+
+    /// The url of the authorization page on `oauth_url`
+    pub(crate) fn url(&self, oauth_url: &str, client_id: &str) -> String {
+        let mut params = vec![
+            ("response_type", "code"),
+            ("client_id", client_id),
+            ("redirect_uri", &self.redirect_uri),
+        ];
+
+        if let Some(state) = &self.state {
+            params.push(("state", state));
+        }
+        if let Some(code_challenge) = &self.code_challenge {
+            params.push(("code_challenge", code_challenge));
+            params.push(("code_challenge_method", "S256"));
+        }
+        if self.signup {
+            params.push(("signup", "true"));
+        }
+        if self.prompt_login {
+            params.push(("prompt", "login"));
+        }
+
+        Url::parse_with_params(&format!("{}/oauth/authorize", oauth_url), &params)
+            .map(String::from)
+            .unwrap_or_default()
+    }
+}
+
+/// A code verifier and its S256 challenge for [PKCE].
+///
+/// The challenge is sent with the authorization request and the verifier with the token request,
+/// so public clients don't need a client secret.
+///
+/// [PKCE]: https://tools.ietf.org/html/rfc7636
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pkce {
+    pub verifier: String,
+    pub challenge: String,
+}
+
+impl Pkce {
+    /// Generates a verifier from 32 random bytes of the operating system, encoded as base64url
+    ///
+    /// Panics if the operating system can't provide random bytes
+    pub fn new() -> Self {
+        Self::from_verifier(base64::encode_config(
+            &random_bytes(32),
+            base64::URL_SAFE_NO_PAD,
+        ))
+    }
+
+    /// Computes the challenge of a verifier of 43 to 128 unreserved characters
+    pub fn from_verifier(verifier: String) -> Self {
+        let challenge = base64::encode_config(
+            &Sha256::digest(verifier.as_bytes()),
+            base64::URL_SAFE_NO_PAD,
+        );
+
+        Self {
+            verifier,
+            challenge,
+        }
+    }
+}
+
+impl Default for Pkce {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The url of the authorization page belonging to an api url. The urls of the trakt api and
+/// its staging environment are mapped to their websites, all others are used as they are.
+/// Use [TraktApiBuilder::oauth_url] to set another one.
+///
+/// [TraktApiBuilder::oauth_url]: ../struct.TraktApiBuilder.html#method.oauth_url
+pub(crate) fn oauth_url_of(base_url: &str) -> String {
+    match base_url.trim_end_matches('/') {
+        "https://api.trakt.tv" => "https://trakt.tv".to_owned(),
+        "https://api-staging.trakt.tv" => "https://staging.trakt.tv".to_owned(),
+        base_url => base_url.to_owned(),
+    }
+}
+
+/// `len` random bytes of the random number generator of the operating system
+///
+/// Panics if the operating system can't provide random bytes
+pub(crate) fn random_bytes(len: usize) -> Vec<u8> {
+    let mut bytes = vec![0; len];
+    getrandom::getrandom(&mut bytes).expect("the operating system can't provide random bytes");
+    bytes
+}
+
+/// `len` random hex digits, `len` has to be a multiple of 2
+pub(crate) fn random_hex(len: usize) -> String {
+    random_bytes(len / 2)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::oauth::{oauth_url_of, random_hex, AuthorizeOptions, Pkce};
+
+    #[test]
+    fn pkce() {
+        let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mJ0kT8RdZs6B1tY7GkAIlJs4vaSgQA".to_owned());
+        assert_eq!(
+            pkce.challenge,
+            "JimqZqDDturtkIAd1tRTt-y5_p38TXOuxh-fW8_uupA"
+        );
+
+        let pkce = Pkce::new();
+        assert_eq!(pkce.verifier.len(), 43);
+        assert_eq!(
+            base64::decode_config(&pkce.verifier, base64::URL_SAFE_NO_PAD)
+                .unwrap()
+                .len(),
+            32
+        );
+        assert_ne!(pkce, Pkce::new());
+        assert_eq!(random_hex(32).len(), 32);
+        assert_ne!(random_hex(32), random_hex(32));
+    }
+
+    #[test]
+    fn authorize_url() {
+        assert_eq!(
+            AuthorizeOptions::new("http://localhost:8080/auth")
+                .state("a b")
+                .pkce(&Pkce::from_verifier(
+                    "dBjftJeZ4CVP-mJ0kT8RdZs6B1tY7GkAIlJs4vaSgQA".to_owned()
+                ))
+                .signup(true)
+                .prompt_login(true)
+                .url("https://trakt.tv", "CLIENT_ID"),
+            "https://trakt.tv/oauth/authorize?response_type=code&client_id=CLIENT_ID&redirect_uri=http%3A%2F%2Flocalhost%3A8080%2Fauth&state=a+b&code_challenge=JimqZqDDturtkIAd1tRTt-y5_p38TXOuxh-fW8_uupA&code_challenge_method=S256&signup=true&prompt=login"
+        );
+    }
+
+    #[test]
+    fn oauth_url() {
+        assert_eq!(oauth_url_of("https://api.trakt.tv"), "https://trakt.tv");
+        assert_eq!(
+            oauth_url_of("https://api-staging.trakt.tv/"),
+            "https://staging.trakt.tv"
+        );
+        assert_eq!(
+            oauth_url_of("http://127.0.0.1:1234"),
+            "http://127.0.0.1:1234"
+        );
+        assert_eq!(
+            oauth_url_of("https://api.mycorp.example"),
+            "https://api.mycorp.example"
+        );
+        assert_eq!(
+            oauth_url_of("https://api-staging.trakt.tv.example"),
+            "https://api-staging.trakt.tv.example"
+        );
+    }
+}
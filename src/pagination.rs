@@ -1,3 +1,5 @@
+use reqwest::{header::HeaderMap, Url};
+
 /// A trait that allows for pagination being added to a request. [More]
 ///
 /// [More]: https://trakt.docs.apiary.io/#introduction/pagination
@@ -8,3 +10,240 @@ pub trait Pagination {
     /// Set requested entry
     fn limit(self, limit: u32) -> Self;
 }
+
+/// A single page of a paginated response together with the [pagination headers]
+///
+/// [pagination headers]: https://trakt.docs.apiary.io/#introduction/pagination
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Current page, starting at 1
+    pub page: u32,
+    /// Items per page
+    pub limit: u32,
+    /// Total number of pages
+    pub page_count: u32,
+    /// Total number of items
+    pub item_count: u32,
+}
+
+impl<T> Page<T> {
+    /// Creates a page from the `X-Pagination-*` headers.
+    /// Missing headers are treated as if all items are on a single page.
+    pub(crate) fn from_headers(items: Vec<T>, headers: &HeaderMap) -> Self {
+        let header = |name: &str| -> Option<u32> {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse().ok())
+        };
+        let len = items.len() as u32;
+
+        Self {
+            page: header("X-Pagination-Page").unwrap_or(1),
+            limit: header("X-Pagination-Limit").unwrap_or(len),
+            page_count: header("X-Pagination-Page-Count").unwrap_or(1),
+            item_count: header("X-Pagination-Item-Count").unwrap_or(len),
+            items,
+        }
+    }
+
+    /// Returns true if there are pages after this one
+    pub fn has_next_page(&self) -> bool {
+        self.page < self.page_count
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// Keeps track of the pages fetched while walking over all pages of a request
+#[derive(Debug)]
+pub(crate) struct PageCursor {
+    next_page: Option<u32>,
+    pages: u32,
+    items: usize,
+    max_pages: Option<u32>,
+    max_items: Option<usize>,
+}
+
+impl PageCursor {
+    /// Starts at the page requested in the url or at the first page
+    pub(crate) fn new(url: &Url) -> Self {
+        let page = url
+            .query_pairs()
+            .find(|(k, _)| k == "page")
+            .and_then(|(_, v)| v.parse().ok())
+            .unwrap_or(1);
+
+        Self {
+            next_page: Some(page),
+            pages: 0,
+            items: 0,
+            max_pages: None,
+            max_items: None,
+        }
+    }
+
+    /// A cursor without any pages
+    pub(crate) fn empty() -> Self {
+        Self {
+            next_page: None,
+            pages: 0,
+            items: 0,
+            max_pages: None,
+            max_items: None,
+        }
+    }
+
+    pub(crate) fn max_pages(&mut self, max_pages: u32) {
+        self.max_pages = Some(max_pages);
+    }
+
+    pub(crate) fn max_items(&mut self, max_items: usize) {
+        self.max_items = Some(max_items);
+    }
+
+    /// Counts an item and returns false if the item limit has been reached before
+    pub(crate) fn take_item(&mut self) -> bool {
+        if matches!(self.max_items, Some(max) if self.items >= max) {
+            return false;
+        }
+
+        self.items += 1;
+        true
+    }
+
+    /// The url of the next page to fetch or None if all pages have been fetched
+    pub(crate) fn next_url(&self, url: &Url) -> Option<Url> {
+        if matches!(self.max_pages, Some(max) if self.pages >= max)
+            || matches!(self.max_items, Some(max) if self.items >= max)
+        {
+            return None;
+        }
+
+        let page = self.next_page?;
+        let query: Vec<(String, String)> = url
+            .query_pairs()
+            .into_owned()
+            .filter(|(k, _)| k != "page")
+            .collect();
+
+        let mut url = url.clone();
+        url.query_pairs_mut()
+            .clear()
+            .extend_pairs(query)
+            .append_pair("page", &page.to_string());
+
+        Some(url)
+    }
+
+    /// Remembers a fetched page
+    pub(crate) fn fetched<T>(&mut self, page: &Page<T>) {
+        self.pages += 1;
+        self.next_page = if page.has_next_page() && !page.items.is_empty() {
+            Some(page.page + 1)
+        } else {
+            None
+        };
+    }
+
+    /// Stops after a page could not be fetched
+    pub(crate) fn failed(&mut self) {
+        self.next_page = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pagination::{Page, PageCursor};
+    use reqwest::{
+        header::{HeaderMap, HeaderValue},
+        Url,
+    };
+
+    #[test]
+    fn page_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Pagination-Page", HeaderValue::from_static("2"));
+        headers.insert("X-Pagination-Limit", HeaderValue::from_static("10"));
+        headers.insert("X-Pagination-Page-Count", HeaderValue::from_static("3"));
+        headers.insert("X-Pagination-Item-Count", HeaderValue::from_static("25"));
+
+        let page = Page::from_headers(vec![1, 2, 3], &headers);
+
+        assert_eq!(
+            page,
+            Page {
+                items: vec![1, 2, 3],
+                page: 2,
+                limit: 10,
+                page_count: 3,
+                item_count: 25,
+            }
+        );
+        assert!(page.has_next_page());
+    }
+
+    #[test]
+    fn page_without_headers() {
+        let page = Page::from_headers(vec![1, 2], &HeaderMap::new());
+
+        assert_eq!(page.page, 1);
+        assert_eq!(page.page_count, 1);
+        assert_eq!(page.item_count, 2);
+        assert!(!page.has_next_page());
+    }
+
+    #[test]
+    fn page_cursor() {
+        let url = Url::parse("https://api.trakt.tv/lists/trending?page=2&limit=5").unwrap();
+        let mut cursor = PageCursor::new(&url);
+        cursor.max_pages(2);
+
+        assert_eq!(
+            cursor.next_url(&url).unwrap().as_str(),
+            "https://api.trakt.tv/lists/trending?limit=5&page=2"
+        );
+
+        let mut page = Page {
+            items: vec![1, 2, 3, 4, 5],
+            page: 2,
+            limit: 5,
+            page_count: 5,
+            item_count: 25,
+        };
+        cursor.fetched(&page);
+
+        assert_eq!(
+            cursor.next_url(&url).unwrap().as_str(),
+            "https://api.trakt.tv/lists/trending?limit=5&page=3"
+        );
+
+        page.page = 3;
+        cursor.fetched(&page);
+
+        assert_eq!(cursor.next_url(&url), None);
+    }
+
+    #[test]
+    fn page_cursor_max_items() {
+        let url = Url::parse("https://api.trakt.tv/lists/trending").unwrap();
+        let mut cursor = PageCursor::new(&url);
+        cursor.max_items(1);
+
+        assert_eq!(
+            cursor.next_url(&url).unwrap().as_str(),
+            "https://api.trakt.tv/lists/trending?page=1"
+        );
+        assert!(cursor.take_item());
+        assert!(!cursor.take_item());
+        assert_eq!(cursor.next_url(&url), None);
+    }
+}
@@ -0,0 +1,277 @@
+//! Tracking and handling of the [rate limits] of the trakt api
+//!
+//! [rate limits]: https://trakt.docs.apiary.io/#introduction/rate-limiting
+use crate::error::{ApiError, ApiErrorKind};
+use chrono::{DateTime, Utc};
+use reqwest::{
+    header::{HeaderMap, AUTHORIZATION},
+    Method,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// The limit a request is counted against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitBucket {
+    /// GET requests without an access token
+    UnauthedGet,
+    /// GET requests with an access token
+    AuthedGet,
+    /// POST, PUT and DELETE requests
+    Post,
+}
+
+impl RateLimitBucket {
+    /// Returns the bucket of a request with the given method and headers
+    pub fn of(method: &Method, headers: &HeaderMap) -> Self {
+        if method != Method::GET {
+            RateLimitBucket::Post
+        } else if headers.contains_key(AUTHORIZATION) {
+            RateLimitBucket::AuthedGet
+        } else {
+            RateLimitBucket::UnauthedGet
+        }
+    }
+}
+
+/// The state of a rate limit as sent in the `X-Ratelimit` header
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RateLimit {
+    pub name: String,
+    pub period: u64,
+    pub limit: u64,
+    pub remaining: u64,
+    pub until: DateTime<Utc>,
+}
+
+impl RateLimit {
+    /// Parses the `X-Ratelimit` header
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        headers
+            .get("X-Ratelimit")
+            .and_then(|v| serde_json::from_slice(v.as_bytes()).ok())
+    }
+
+    /// Time until the limit resets
+    pub fn reset_in(&self) -> Duration {
+        (self.until - Utc::now()).to_std().unwrap_or_default()
+    }
+}
+
+/// Configures how rate limited requests are handled
+///
+/// By default nothing is retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitConfig {
+    /// How often a GET request is retried after a 429 response
+    pub get_retries: u32,
+    /// How often a POST, PUT or DELETE request is retried after a 429 response
+    pub post_retries: u32,
+    /// The longest time to wait for a retry. Requests which would have to wait longer fail.
+    pub max_wait: Duration,
+}
+
+impl RateLimitConfig {
+    pub fn new(get_retries: u32, post_retries: u32) -> Self {
+        Self {
+            get_retries,
+            post_retries,
+            ..Self::default()
+        }
+    }
+
+    fn retries(&self, bucket: RateLimitBucket) -> u32 {
+        match bucket {
+            RateLimitBucket::Post => self.post_retries,
+            _ => self.get_retries,
+        }
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            get_retries: 0,
+            post_retries: 0,
+            max_wait: Duration::from_secs(300),
+        }
+    }
+}
+
+/// Keeps track of the rate limits seen in responses.
+/// Clones share their state.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RateLimiter {
+    pub(crate) config: RateLimitConfig,
+    limits: Arc<Mutex<HashMap<RateLimitBucket, RateLimit>>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            limits: Arc::default(),
+        }
+    }
+
+    /// The last known state of a bucket
+    pub(crate) fn get(&self, bucket: RateLimitBucket) -> Option<RateLimit> {
+        self.limits.lock().unwrap().get(&bucket).cloned()
+    }
+
+    /// Remembers the rate limit sent in a response
+    pub(crate) fn update(&self, bucket: RateLimitBucket, headers: &HeaderMap) {
+        if let Some(limit) = RateLimit::from_headers(headers) {
+            self.limits.lock().unwrap().insert(bucket, limit);
+        }
+    }
+
+    /// Time to wait before sending a request if the bucket is known to be exhausted.
+    /// Only waits if retrying is enabled for the bucket.
+    pub(crate) fn wait_time(&self, bucket: RateLimitBucket) -> Option<Duration> {
+        if self.config.retries(bucket) == 0 {
+            return None;
+        }
+
+        self.get(bucket)
+            .filter(|limit| limit.remaining == 0)
+            .map(|limit| limit.reset_in())
+            .filter(|wait| *wait > Duration::from_secs(0) && *wait <= self.config.max_wait)
+    }
+
+    /// Time to wait before retrying a request that failed with `error` in its `attempt`th try.
+    /// Returns None if it should not be retried.
+    pub(crate) fn retry_delay(
+        &self,
+        bucket: RateLimitBucket,
+        attempt: u32,
+        error: &ApiError,
+    ) -> Option<Duration> {
+        if error.kind != ApiErrorKind::RateLimited || attempt >= self.config.retries(bucket) {
+            return None;
+        }
+
+        let wait = match (error.retry_after(), error.rate_limit()) {
+            (Some(secs), _) => Duration::from_secs(secs),
+            (None, Some(limit)) => limit.reset_in(),
+            (None, None) => Duration::from_secs(1),
+        };
+
+        if wait <= self.config.max_wait {
+            Some(wait)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ApiError,
+        rate_limit::{RateLimit, RateLimitBucket, RateLimitConfig, RateLimiter},
+    };
+    use chrono::{Duration as ChronoDuration, Utc};
+    use reqwest::{
+        header::{HeaderMap, HeaderValue},
+        Method, StatusCode,
+    };
+    use std::time::Duration;
+
+    fn rate_limited(retry_after: &'static str) -> ApiError {
+        let mut headers = HeaderMap::new();
+        headers.insert("Retry-After", HeaderValue::from_static(retry_after));
+        headers.insert(
+            "X-Ratelimit",
+            HeaderValue::from_static("{\"name\":\"AUTHED_API_POST_LIMIT\",\"period\":1,\"limit\":1,\"remaining\":0,\"until\":\"2020-10-10T00:24:00Z\"}"),
+        );
+
+        ApiError::new(StatusCode::TOO_MANY_REQUESTS, headers, b"")
+    }
+
+    #[test]
+    fn bucket() {
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(
+            RateLimitBucket::of(&Method::GET, &headers),
+            RateLimitBucket::UnauthedGet
+        );
+        assert_eq!(
+            RateLimitBucket::of(&Method::DELETE, &headers),
+            RateLimitBucket::Post
+        );
+
+        headers.insert("Authorization", HeaderValue::from_static("Bearer TOKEN"));
+
+        assert_eq!(
+            RateLimitBucket::of(&Method::GET, &headers),
+            RateLimitBucket::AuthedGet
+        );
+    }
+
+    #[test]
+    fn rate_limit_header() {
+        assert_eq!(
+            rate_limited("1").rate_limit(),
+            Some(RateLimit {
+                name: "AUTHED_API_POST_LIMIT".to_owned(),
+                period: 1,
+                limit: 1,
+                remaining: 0,
+                until: "2020-10-10T00:24:00Z".parse().unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn retry_delay() {
+        let limiter = RateLimiter::new(RateLimitConfig::new(0, 2));
+
+        assert_eq!(
+            limiter.retry_delay(RateLimitBucket::Post, 0, &rate_limited("2")),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            limiter.retry_delay(RateLimitBucket::Post, 2, &rate_limited("2")),
+            None
+        );
+        assert_eq!(
+            limiter.retry_delay(RateLimitBucket::AuthedGet, 0, &rate_limited("2")),
+            None
+        );
+        assert_eq!(
+            limiter.retry_delay(RateLimitBucket::Post, 0, &rate_limited("1000")),
+            None
+        );
+    }
+
+    #[test]
+    fn wait_time() {
+        let limiter = RateLimiter::new(RateLimitConfig::new(1, 0));
+        let until = (Utc::now() + ChronoDuration::seconds(30)).to_rfc3339();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-Ratelimit",
+            HeaderValue::from_str(&format!(
+                "{{\"name\":\"AUTHED_API_GET_LIMIT\",\"period\":300,\"limit\":1000,\"remaining\":0,\"until\":\"{}\"}}",
+                until
+            ))
+            .unwrap(),
+        );
+
+        limiter.update(RateLimitBucket::AuthedGet, &headers);
+        limiter.update(RateLimitBucket::Post, &headers);
+
+        assert_eq!(
+            limiter.get(RateLimitBucket::AuthedGet).unwrap().remaining,
+            0
+        );
+        assert!(limiter.wait_time(RateLimitBucket::AuthedGet).unwrap() <= Duration::from_secs(30));
+        assert_eq!(limiter.wait_time(RateLimitBucket::Post), None);
+        assert_eq!(limiter.wait_time(RateLimitBucket::UnauthedGet), None);
+    }
+}
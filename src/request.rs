@@ -0,0 +1,238 @@
+//! Requests shared by the sync and async clients
+//!
+//! Every endpoint is described once as an [ApiRequest]. The clients only differ in how
+//! they execute it.
+//!
+//! [ApiRequest]: struct.ApiRequest.html
+use crate::{pagination::Page, route::Route};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::{fmt, marker::PhantomData};
+
+/// A single request to the Trakt API.
+///
+/// It contains the method, route, query, body and access token of the request together with
+/// the type its response is deserialized into. Creating it doesn't send anything, that is up
+/// to the client executing it.
+pub struct ApiRequest<T> {
+    method: Method,
+    route: Route,
+    query: Vec<(String, String)>,
+    body: Option<String>,
+    access_token: Option<String>,
+    idempotent: bool,
+    incomplete: bool,
+    response_type: PhantomData<fn() -> T>,
+}
+
+impl<T> ApiRequest<T> {
+    /// Creates a request to the route, which is relative to the base url of the client
+    pub fn new(method: Method, route: Route) -> Self {
+        Self {
+            method,
+            route,
+            query: Vec::new(),
+            body: None,
+            access_token: None,
+            idempotent: false,
+            incomplete: false,
+            response_type: PhantomData,
+        }
+    }
+
+    /// Creates a request to a route which couldn't be built if it is None.
+    /// Executing such a request fails with [Error::NoneError].
+    ///
+    /// [Error::NoneError]: ../error/enum.Error.html#variant.NoneError
+    pub(crate) fn try_new(method: Method, route: Option<Route>) -> Self {
+        match route {
+            Some(route) => Self::new(method, route),
+            None => Self {
+                incomplete: true,
+                ..Self::new(method, Route::new("/"))
+            },
+        }
+    }
+
+    /// Creates a GET request
+    pub fn get(route: Route) -> Self {
+        Self::new(Method::GET, route)
+    }
+
+    /// Creates a POST request with the given body
+    pub fn post(route: Route, body: String) -> Self {
+        Self::new(Method::POST, route).with_body(body)
+    }
+
+    /// Creates a PUT request with the given body
+    pub fn put(route: Route, body: String) -> Self {
+        Self::new(Method::PUT, route).with_body(body)
+    }
+
+    /// Creates a DELETE request
+    pub fn delete(route: Route) -> Self {
+        Self::new(Method::DELETE, route)
+    }
+
+    /// Appends a query parameter. It is percent-encoded when the request is sent.
+    pub fn query(mut self, key: &str, value: impl ToString) -> Self {
+        self.query.push((key.to_owned(), value.to_string()));
+        self
+    }
+
+    /// Appends a query parameter if a value is given
+    pub fn optional_query(self, key: &str, value: Option<impl ToString>) -> Self {
+        match value {
+            Some(value) => self.query(key, value),
+            None => self,
+        }
+    }
+
+    /// Sets the body
+    pub fn with_body(mut self, body: String) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Authorizes the request with an OAuth access token
+    pub fn auth(mut self, access_token: &str) -> Self {
+        self.access_token = Some(access_token.to_owned());
+        self
+    }
+
+    /// Authorizes the request if an access token is given
+    pub fn optional_auth(mut self, access_token: Option<&str>) -> Self {
+        self.access_token = access_token.map(str::to_owned);
+        self
+    }
+
+    /// Marks a POST, PUT or DELETE request as safe to send more than once, so it is retried
+    /// even if the [RetryPolicy] doesn't retry mutating requests
+    ///
+    /// [RetryPolicy]: ../retry/struct.RetryPolicy.html
+    pub fn idempotent(mut self, idempotent: bool) -> Self {
+        self.idempotent = idempotent;
+        self
+    }
+
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    pub fn route(&self) -> &Route {
+        &self.route
+    }
+
+    pub fn query_pairs(&self) -> &[(String, String)] {
+        &self.query
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    pub fn access_token(&self) -> Option<&str> {
+        self.access_token.as_deref()
+    }
+
+    pub fn is_idempotent(&self) -> bool {
+        self.idempotent
+    }
+
+    /// Whether a required value is missing, e.g. the user of a selected list
+    pub fn is_incomplete(&self) -> bool {
+        self.incomplete
+    }
+}
+
+impl<T> Clone for ApiRequest<T> {
+    fn clone(&self) -> Self {
+        Self {
+            method: self.method.clone(),
+            route: self.route.clone(),
+            query: self.query.clone(),
+            body: self.body.clone(),
+            access_token: self.access_token.clone(),
+            idempotent: self.idempotent,
+            incomplete: self.incomplete,
+            response_type: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for ApiRequest<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.method == other.method
+            && self.route == other.route
+            && self.query == other.query
+            && self.body == other.body
+            && self.access_token == other.access_token
+            && self.idempotent == other.idempotent
+            && self.incomplete == other.incomplete
+    }
+}
+
+impl<T> fmt::Debug for ApiRequest<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ApiRequest")
+            .field("method", &self.method)
+            .field("route", &self.route)
+            .field("query", &self.query)
+            .field("body", &self.body)
+            .field("idempotent", &self.idempotent)
+            .field("incomplete", &self.incomplete)
+            .finish()
+    }
+}
+
+/// A client which can execute [ApiRequest]s.
+///
+/// The request builders are generic over it, so they are the same for the sync and the async
+/// [TraktApi]. It is implemented by both.
+///
+/// [ApiRequest]: struct.ApiRequest.html
+/// [TraktApi]: ../struct.TraktApi.html
+pub trait Client: Clone {
+    /// What executing a request returns. A [Result] for the sync client and a future
+    /// resolving to one for the async client.
+    ///
+    /// [Result]: ../sync/type.Result.html
+    type Output<T>;
+
+    /// Executes the request and deserializes the response
+    fn run<T: DeserializeOwned + Send + 'static>(&self, request: ApiRequest<T>) -> Self::Output<T>;
+
+    /// Executes the request of a paginated endpoint and deserializes the response together
+    /// with its pagination headers
+    fn run_page<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest<Vec<T>>,
+    ) -> Self::Output<Page<T>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApiRequest;
+    use crate::route::Route;
+    use reqwest::Method;
+
+    #[test]
+    fn api_request() {
+        let req: ApiRequest<()> = ApiRequest::post(Route::new("/checkin"), "{}".to_owned())
+            .query("extended", "full")
+            .optional_query("type", None::<&str>)
+            .auth("ACCESS_TOKEN");
+
+        assert_eq!(req.method(), &Method::POST);
+        assert_eq!(req.route().as_str(), "/checkin");
+        assert_eq!(
+            req.query_pairs(),
+            &[("extended".to_owned(), "full".to_owned())][..]
+        );
+        assert_eq!(req.body(), Some("{}"));
+        assert_eq!(req.access_token(), Some("ACCESS_TOKEN"));
+        assert_eq!(req.clone().optional_auth(None).access_token(), None);
+        assert!(!req.is_idempotent());
+        assert!(req.idempotent(true).is_idempotent());
+    }
+}
@@ -0,0 +1,733 @@
+use crate::{
+    error::Error,
+    models::{AuthenticationDevices, AuthenticationTokenResponse},
+    oauth::AuthorizeOptions,
+    request::ApiRequest,
+    route::Route,
+};
+use serde_json::json;
+
+builders! {
+    /// The url of the authorization page the user has to be sent to
+    pub fn oauth_authorize(&self, options: &AuthorizeOptions) -> String {
+        options.url(&self.oauth_url, &self.client_id)
+    }
+}
+
+endpoints! {
+    pub fn oauth_get_token(&self, code: &str, redirect_uri: &str) -> AuthenticationTokenResponse {
+        if self.client_secret == None {
+            return Err(Error::ClientSecretNeeded);
+        }
+
+        ApiRequest::post(
+            Route::new("/oauth/token"),
+            json!({
+                "code": code,
+                "client_id": &*self.client_id,
+                "client_secret": self.client_secret.as_deref(),
+                "redirect_uri": redirect_uri,
+                "grant_type": "authorization_code"
+            })
+            .to_string(),
+        )
+    }
+
+    /// Exchanges the code for a token using the verifier of the [PKCE] challenge sent with the
+    /// authorization request. The client secret is optional then.
+    ///
+    /// [PKCE]: ../oauth/struct.Pkce.html
+    pub fn oauth_get_token_pkce(
+        &self,
+        code: &str,
+        redirect_uri: &str,
+        code_verifier: &str,
+    ) -> AuthenticationTokenResponse {
+        let mut body = json!({
+            "code": code,
+            "code_verifier": code_verifier,
+            "client_id": &*self.client_id,
+            "redirect_uri": redirect_uri,
+            "grant_type": "authorization_code"
+        });
+
+        if let Some(client_secret) = &self.client_secret {
+            body["client_secret"] = json!(&**client_secret);
+        }
+
+        ApiRequest::post(Route::new("/oauth/token"), body.to_string())
+    }
+
+    /// Exchanges the refresh token for a new token. The client secret is optional, so public
+    /// clients which logged in with [PKCE] can refresh their tokens too.
+    ///
+    /// [PKCE]: ../oauth/struct.Pkce.html
+    pub fn oauth_refresh_token(
+        &self,
+        refresh_token: &str,
+        redirect_uri: &str,
+    ) -> AuthenticationTokenResponse {
+        let mut body = json!({
+            "refresh_token": refresh_token,
+            "client_id": &*self.client_id,
+            "redirect_uri": redirect_uri,
+            "grant_type": "refresh_token"
+        });
+
+        if let Some(client_secret) = &self.client_secret {
+            body["client_secret"] = json!(&**client_secret);
+        }
+
+        ApiRequest::post(Route::new("/oauth/token"), body.to_string())
+    }
+
+    pub fn oauth_revoke_token(&self, token: &str) -> () {
+        if self.client_secret == None {
+            return Err(Error::ClientSecretNeeded);
+        }
+
+        ApiRequest::post(
+            Route::new("/oauth/revoke"),
+            json!({
+                "token": token,
+                "client_id": &*self.client_id,
+                "client_secret": self.client_secret.as_deref(),
+            })
+            .to_string(),
+        )
+    }
+
+    pub fn oauth_device_code(&self) -> AuthenticationDevices {
+        ApiRequest::post(
+            Route::new("/oauth/device/code"),
+            json!({"client_id": &*self.client_id}).to_string(),
+        )
+    }
+
+    pub fn oauth_device_token(&self, device_code: &str) -> AuthenticationTokenResponse {
+        if self.client_secret == None {
+            return Err(Error::ClientSecretNeeded);
+        }
+
+        ApiRequest::post(
+            Route::new("/oauth/device/token"),
+            json!({
+                "code": device_code,
+                "client_id": &*self.client_id,
+                "client_secret": self.client_secret.as_deref()
+            })
+            .to_string(),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod sync_tests {
+    use crate::{
+        error::Error,
+        models::{AuthenticationDevices, AuthenticationTokenResponse, DeviceCodeStatus},
+        oauth::AuthorizeOptions,
+        tests::mock,
+        TraktApi,
+    };
+    use mockito::Matcher;
+    use std::{fs, time::Duration};
+
+    fn devices(expires_in: u64, interval: u64) -> AuthenticationDevices {
+        AuthenticationDevices {
+            device_code: "fd0847dbb559752d932dd3c1ac34ff98d27b11fe2fea5a864f44740cd7919ad0"
+                .to_owned(),
+            user_code: "5055CC52".to_owned(),
+            verification_url: "https://trakt.tv/activate".to_owned(),
+            expires_in,
+            interval,
+        }
+    }
+
+    #[test]
+    fn oauth_authorize() {
+        let c = TraktApi::new("...".to_owned(), None);
+
+        assert_eq!(
+            c.oauth_authorize(&AuthorizeOptions::new("http://localhost:8080/auth")),
+            "https://trakt.tv/oauth/authorize?response_type=code&client_id=...&redirect_uri=http%3A%2F%2Flocalhost%3A8080%2Fauth".to_owned()
+        );
+        assert!(TraktApi::staging("...".to_owned(), None)
+            .oauth_authorize(&AuthorizeOptions::new("http://localhost:8080/auth"))
+            .starts_with("https://staging.trakt.tv/oauth/authorize?"));
+    }
+
+    #[test]
+    fn oauth_get_token() {
+        let m = mock("POST", "/oauth/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_get_token_req.json").unwrap(),
+            ))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let res = TraktApi::with_url(
+            &mockito::server_url(),
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+        .oauth_get_token("CODE", "http://localhost:8080/auth")
+        .unwrap();
+
+        assert_eq!(
+            res,
+            AuthenticationTokenResponse {
+                access_token: "dbaf9757982a9e738f05d249b7b5b4a266b3a139049317c4909f2f263572c781"
+                    .to_string(),
+                token_type: "bearer".to_string(),
+                expires_in: 7200,
+                refresh_token: "76ba4c5c75c96f6087f58a4de10be6c00b29ea1ddc3b2022ee2016d1363e3a7c"
+                    .to_string(),
+                scope: "public".to_string(),
+                created_at: 1487889741
+            }
+        );
+
+        m.assert();
+    }
+
+    /*#[test]
+    #[should_panic]
+    fn oauth_get_token_panic() {
+        let _m = mock("POST", "oauth/token")
+            .with_status(200)
+            .match_body(Matcher::JsonString(fs::read_to_string("mock_data/oauth_get_token_req.json").unwrap()))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let fut = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .oauth_get_token("CODE", "http://localhost:8080/auth")
+            .map(|_res| {
+                ()
+            })
+            .map_err(|e| {
+                println!("{}", e);
+                match e {
+                    Error::ClientSecretNeeded => panic!("{}", e),
+                    _ => (),
+                }
+            });
+
+        tokio::run(fut);
+    }*/
+
+    #[test]
+    fn oauth_get_token_pkce() {
+        let m = mock("POST", "/oauth/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::Json(json!({
+                "code": "CODE",
+                "code_verifier": "VERIFIER",
+                "client_id": "CLIENT_ID",
+                "redirect_uri": "http://localhost:8080/auth",
+                "grant_type": "authorization_code"
+            })))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let url = mockito::server_url();
+        let token = TraktApi::with_url(&url, "CLIENT_ID".to_owned(), None)
+            .oauth_get_token_pkce("CODE", "http://localhost:8080/auth", "VERIFIER")
+            .unwrap();
+
+        assert_eq!(token.expires_in, 7200);
+        m.assert();
+    }
+
+    #[test]
+    fn oauth_refresh_token() {
+        let m = mock("POST", "/oauth/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_refresh_token_req.json").unwrap(),
+            ))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let res = TraktApi::with_url(
+            &mockito::server_url(),
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+        .oauth_refresh_token("REFRESH_TOKEN", "http://localhost:8080/auth")
+        .unwrap();
+
+        assert_eq!(
+            res,
+            AuthenticationTokenResponse {
+                access_token: "dbaf9757982a9e738f05d249b7b5b4a266b3a139049317c4909f2f263572c781"
+                    .to_string(),
+                token_type: "bearer".to_string(),
+                expires_in: 7200,
+                refresh_token: "76ba4c5c75c96f6087f58a4de10be6c00b29ea1ddc3b2022ee2016d1363e3a7c"
+                    .to_string(),
+                scope: "public".to_string(),
+                created_at: 1487889741
+            }
+        );
+
+        m.assert();
+    }
+
+    #[test]
+    fn oauth_revoke_token() {
+        let m = mock("POST", "/oauth/revoke", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_revoke_token_req.json").unwrap(),
+            ))
+            .with_body("{}")
+            .create();
+
+        TraktApi::with_url(
+            &mockito::server_url(),
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+        .oauth_revoke_token("TOKEN")
+        .unwrap();
+
+        m.assert();
+    }
+
+    #[test]
+    fn oauth_device_code() {
+        let m = mock("POST", "/oauth/device/code", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                "{\"client_id\": \"CLIENT_ID\"}".to_owned(),
+            ))
+            .with_body_from_file("mock_data/oauth_device_code.json")
+            .create();
+
+        let res = TraktApi::with_url(
+            &mockito::server_url(),
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+        .oauth_device_code()
+        .unwrap();
+
+        assert_eq!(
+            res,
+            AuthenticationDevices {
+                device_code: "d9c126a7706328d808914cfd1e40274b6e009f684b1aca271b9b3f90b3630d64"
+                    .to_string(),
+                user_code: "5055CC52".to_string(),
+                verification_url: "https://trakt.tv/activate".to_string(),
+                expires_in: 600,
+                interval: 5
+            }
+        );
+
+        m.assert();
+    }
+
+    #[test]
+    fn oauth_device_token() {
+        let m = mock("POST", "/oauth/device/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_device_token_req.json").unwrap(),
+            ))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let res = TraktApi::with_url(
+            &mockito::server_url(),
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+        .oauth_device_token("fd0847dbb559752d932dd3c1ac34ff98d27b11fe2fea5a864f44740cd7919ad0")
+        .unwrap();
+
+        assert_eq!(
+            res,
+            AuthenticationTokenResponse {
+                access_token: "dbaf9757982a9e738f05d249b7b5b4a266b3a139049317c4909f2f263572c781"
+                    .to_string(),
+                token_type: "bearer".to_string(),
+                expires_in: 7200,
+                refresh_token: "76ba4c5c75c96f6087f58a4de10be6c00b29ea1ddc3b2022ee2016d1363e3a7c"
+                    .to_string(),
+                scope: "public".to_string(),
+                created_at: 1487889741
+            }
+        );
+
+        m.assert();
+    }
+
+    #[test]
+    fn poll_until_authorized() {
+        let m = mock("POST", "/oauth/device/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_device_token_req.json").unwrap(),
+            ))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(
+            &url,
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        );
+        let mut codes = Vec::new();
+
+        let token = devices(600, 0)
+            .poll_until_authorized(&api, |progress| codes.push(progress.user_code.to_owned()))
+            .unwrap();
+
+        assert_eq!(token.expires_in, 7200);
+        assert_eq!(codes, vec!["5055CC52".to_owned()]);
+        m.assert();
+    }
+
+    #[test]
+    fn poll_until_denied() {
+        let m = mock("POST", "/oauth/device/token", "CLIENT_ID")
+            .with_status(418)
+            .expect(1)
+            .create();
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(
+            &url,
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        );
+
+        match devices(600, 0).poll_until_authorized(&api, |_| {}) {
+            Err(Error::DeviceCode(status)) => assert_eq!(status, DeviceCodeStatus::Denied),
+            res => panic!("expected a device code error, got {:?}", res),
+        }
+
+        m.assert();
+    }
+
+    #[test]
+    fn poll_slow_down_until_expired() {
+        let m = mock("POST", "/oauth/device/token", "CLIENT_ID")
+            .with_status(429)
+            .expect(2)
+            .create();
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(
+            &url,
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        );
+        let mut intervals = Vec::new();
+
+        match devices(1, 0).poll_until_authorized(&api, |progress| {
+            assert!(progress.expires_in <= Duration::from_secs(1));
+            intervals.push(progress.interval);
+        }) {
+            Err(Error::DeviceCode(status)) => assert_eq!(status, DeviceCodeStatus::Expired),
+            res => panic!("expected a device code error, got {:?}", res),
+        }
+
+        assert_eq!(
+            intervals,
+            vec![Duration::from_secs(0), Duration::from_secs(1)]
+        );
+        m.assert();
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use crate::{
+        asyn::TraktApi,
+        error::Error,
+        models::{AuthenticationDevices, AuthenticationTokenResponse, DeviceCodeStatus},
+        oauth::AuthorizeOptions,
+        tests::mock,
+    };
+    use mockito::Matcher;
+    use std::{
+        fs,
+        sync::{Arc, Mutex},
+    };
+
+    fn devices(expires_in: u64, interval: u64) -> AuthenticationDevices {
+        AuthenticationDevices {
+            device_code: "fd0847dbb559752d932dd3c1ac34ff98d27b11fe2fea5a864f44740cd7919ad0"
+                .to_owned(),
+            user_code: "5055CC52".to_owned(),
+            verification_url: "https://trakt.tv/activate".to_owned(),
+            expires_in,
+            interval,
+        }
+    }
+
+    #[test]
+    fn oauth_authorize() {
+        let c = TraktApi::new("...".to_owned(), None);
+
+        assert_eq!(
+            c.oauth_authorize(&AuthorizeOptions::new("http://localhost:8080/auth")),
+            "https://trakt.tv/oauth/authorize?response_type=code&client_id=...&redirect_uri=http%3A%2F%2Flocalhost%3A8080%2Fauth".to_owned()
+        );
+        assert!(TraktApi::staging("...".to_owned(), None)
+            .oauth_authorize(&AuthorizeOptions::new("http://localhost:8080/auth"))
+            .starts_with("https://staging.trakt.tv/oauth/authorize?"));
+    }
+
+    #[tokio::test]
+    async fn oauth_get_token() -> Result<(), Error> {
+        let m = mock("POST", "/oauth/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_get_token_req.json").unwrap(),
+            ))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let res = TraktApi::with_url(
+            &mockito::server_url(),
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+        .oauth_get_token("CODE", "http://localhost:8080/auth")
+        .await?;
+
+        assert_eq!(
+            res,
+            AuthenticationTokenResponse {
+                access_token: "dbaf9757982a9e738f05d249b7b5b4a266b3a139049317c4909f2f263572c781"
+                    .to_string(),
+                token_type: "bearer".to_string(),
+                expires_in: 7200,
+                refresh_token: "76ba4c5c75c96f6087f58a4de10be6c00b29ea1ddc3b2022ee2016d1363e3a7c"
+                    .to_string(),
+                scope: "public".to_string(),
+                created_at: 1487889741
+            }
+        );
+        m.assert();
+
+        Ok(())
+    }
+
+    /*#[test]
+    #[should_panic]
+    fn oauth_get_token_panic() {
+        let _m = mock("POST", "oauth/token")
+            .with_status(200)
+            .match_body(Matcher::JsonString(fs::read_to_string("mock_data/oauth_get_token_req.json").unwrap()))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let fut = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .oauth_get_token("CODE", "http://localhost:8080/auth")
+            .map(|_res| {
+                ()
+            })
+            .map_err(|e| {
+                println!("{}", e);
+                match e {
+                    Error::ClientSecretNeeded => panic!("{}", e),
+                    _ => (),
+                }
+            });
+
+        tokio::run(fut);
+    }*/
+
+    #[tokio::test]
+    async fn oauth_refresh_token() -> Result<(), Error> {
+        let m = mock("POST", "/oauth/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_refresh_token_req.json").unwrap(),
+            ))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let res = TraktApi::with_url(
+            &mockito::server_url(),
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+        .oauth_refresh_token("REFRESH_TOKEN", "http://localhost:8080/auth")
+        .await?;
+
+        assert_eq!(
+            res,
+            AuthenticationTokenResponse {
+                access_token: "dbaf9757982a9e738f05d249b7b5b4a266b3a139049317c4909f2f263572c781"
+                    .to_string(),
+                token_type: "bearer".to_string(),
+                expires_in: 7200,
+                refresh_token: "76ba4c5c75c96f6087f58a4de10be6c00b29ea1ddc3b2022ee2016d1363e3a7c"
+                    .to_string(),
+                scope: "public".to_string(),
+                created_at: 1487889741
+            }
+        );
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn oauth_revoke_token() -> Result<(), Error> {
+        let m = mock("POST", "/oauth/revoke", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_revoke_token_req.json").unwrap(),
+            ))
+            .with_body("{}")
+            .create();
+
+        TraktApi::with_url(
+            &mockito::server_url(),
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+        .oauth_revoke_token("TOKEN")
+        .await?;
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn oauth_device_code() -> Result<(), Error> {
+        let m = mock("POST", "/oauth/device/code", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                "{\"client_id\": \"CLIENT_ID\"}".to_owned(),
+            ))
+            .with_body_from_file("mock_data/oauth_device_code.json")
+            .create();
+
+        let res = TraktApi::with_url(
+            &mockito::server_url(),
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+        .oauth_device_code()
+        .await?;
+
+        assert_eq!(
+            res,
+            AuthenticationDevices {
+                device_code: "d9c126a7706328d808914cfd1e40274b6e009f684b1aca271b9b3f90b3630d64"
+                    .to_string(),
+                user_code: "5055CC52".to_string(),
+                verification_url: "https://trakt.tv/activate".to_string(),
+                expires_in: 600,
+                interval: 5
+            }
+        );
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn oauth_device_token() -> Result<(), Error> {
+        let m = mock("POST", "/oauth/device/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_device_token_req.json").unwrap(),
+            ))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let res = TraktApi::with_url(
+            &mockito::server_url(),
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+        .oauth_device_token("fd0847dbb559752d932dd3c1ac34ff98d27b11fe2fea5a864f44740cd7919ad0")
+        .await?;
+
+        assert_eq!(
+            res,
+            AuthenticationTokenResponse {
+                access_token: "dbaf9757982a9e738f05d249b7b5b4a266b3a139049317c4909f2f263572c781"
+                    .to_string(),
+                token_type: "bearer".to_string(),
+                expires_in: 7200,
+                refresh_token: "76ba4c5c75c96f6087f58a4de10be6c00b29ea1ddc3b2022ee2016d1363e3a7c"
+                    .to_string(),
+                scope: "public".to_string(),
+                created_at: 1487889741
+            }
+        );
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn poll_until_authorized() {
+        let m = mock("POST", "/oauth/device/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_device_token_req.json").unwrap(),
+            ))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .create();
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(
+            &url,
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        );
+        let codes = Arc::new(Mutex::new(Vec::new()));
+        let progress_codes = Arc::clone(&codes);
+
+        let token = devices(600, 0)
+            .poll_until_authorized_async(&api, move |progress| {
+                progress_codes
+                    .lock()
+                    .unwrap()
+                    .push(progress.user_code.to_owned())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(token.expires_in, 7200);
+        assert_eq!(*codes.lock().unwrap(), vec!["5055CC52".to_owned()]);
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn poll_until_already_used() {
+        let m = mock("POST", "/oauth/device/token", "CLIENT_ID")
+            .with_status(409)
+            .expect(1)
+            .create();
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(
+            &url,
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        );
+
+        match devices(600, 0)
+            .poll_until_authorized_async(&api, |_| {})
+            .await
+        {
+            Err(Error::DeviceCode(status)) => assert_eq!(status, DeviceCodeStatus::AlreadyUsed),
+            res => panic!("expected a device code error, got {:?}", res),
+        }
+
+        m.assert();
+    }
+}
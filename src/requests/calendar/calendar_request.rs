@@ -2,10 +2,10 @@ use crate::{
     extended_info::{ExtendedInfoFull, ExtendedInfoNone, WithFull, WithNone},
     filters::Filters,
     models::ShowStatus,
-    Error, Result, TraktApi,
+    request::{ApiRequest, Client},
+    route::Route,
 };
 use chrono::{Date, Utc};
-use reqwest::{Method, Request};
 use serde::de::DeserializeOwned;
 use std::{collections::HashMap, marker::PhantomData};
 
@@ -35,24 +35,22 @@ use std::{collections::HashMap, marker::PhantomData};
 /// }
 /// ```
 #[derive(Debug, Clone)]
-pub struct CalendarRequest<'a, T: DeserializeOwned> {
-    client: &'a TraktApi<'a>,
-    url: &'a str,
+pub struct CalendarRequest<C, T: DeserializeOwned> {
+    client: C,
+    route: Route,
     start_date: Option<Date<Utc>>,
     days: Option<u32>,
-    access_token: Option<&'a str>,
     query: HashMap<String, String>,
     response_type: PhantomData<T>,
 }
 
-impl<'a, T: DeserializeOwned> CalendarRequest<'a, T> {
-    pub fn new(client: &'a TraktApi, url: &'a str, access_token: Option<&'a str>) -> Self {
+impl<C: Client, T: DeserializeOwned + Send + 'static> CalendarRequest<C, T> {
+    pub fn new(client: &C, route: Route) -> Self {
         Self {
-            client,
-            url,
+            client: client.clone(),
+            route,
             start_date: None,
             days: None,
-            access_token,
             query: HashMap::new(),
             response_type: PhantomData,
         }
@@ -72,96 +70,90 @@ impl<'a, T: DeserializeOwned> CalendarRequest<'a, T> {
         self
     }
 
-    pub fn build(&self) -> std::result::Result<Request, Error> {
-        let mut url = "/calendars/".to_owned();
-        url.push_str(self.url);
+    /// Build the [ApiRequest]
+    ///
+    /// [ApiRequest]: ../../request/struct.ApiRequest.html
+    pub fn build(&self) -> ApiRequest<Vec<T>> {
+        let mut route = self.route.clone();
 
         if let Some(start_date) = &self.start_date {
-            url = format!("{}/{}", url, start_date.format("%Y-%m-%d"));
-
-            if let Some(days) = self.days {
-                url = format!("{}/{}", url, days);
-            }
+            route = route
+                .segment(start_date.format("%Y-%m-%d"))
+                .optional_segment(self.days);
         }
 
-        let mut req = self.client.builder(Method::GET, url);
-
-        if let Some(access_token) = self.access_token {
-            req = req.bearer_auth(access_token);
-        }
+        let mut req = ApiRequest::get(route);
 
-        if !self.query.is_empty() {
-            req = req.query(&self.query);
+        for (key, value) in &self.query {
+            req = req.query(key, value);
         }
 
-        req.build().map_err(Error::from)
+        req
     }
 
     /// Execute this request
-    pub fn execute(self) -> Result<Vec<T>> {
-        self.client.execute(self.build()?)
+    pub fn execute(self) -> C::Output<Vec<T>> {
+        self.client.run(self.build())
     }
 }
 
-impl<'a, T> WithFull for CalendarRequest<'a, T>
+impl<C, T> WithFull for CalendarRequest<C, T>
 where
     T: DeserializeOwned + WithFull,
     T::Full: DeserializeOwned,
 {
-    type Full = CalendarRequest<'a, T::Full>;
+    type Full = CalendarRequest<C, T::Full>;
 }
 
-impl<'a, T> WithNone for CalendarRequest<'a, T>
+impl<C, T> WithNone for CalendarRequest<C, T>
 where
     T: DeserializeOwned + WithNone,
     T::None: DeserializeOwned,
 {
-    type None = CalendarRequest<'a, T::None>;
+    type None = CalendarRequest<C, T::None>;
 }
 
-impl<'a, T> ExtendedInfoFull for CalendarRequest<'a, T>
+impl<C, T> ExtendedInfoFull for CalendarRequest<C, T>
 where
     T: DeserializeOwned + WithFull,
     T::Full: DeserializeOwned,
 {
     /// Request full extended info
-    fn full(mut self) -> CalendarRequest<'a, T::Full> {
+    fn full(mut self) -> CalendarRequest<C, T::Full> {
         self.query.insert("extended".to_owned(), "full".to_owned());
 
         CalendarRequest {
             client: self.client,
-            url: self.url,
+            route: self.route,
             start_date: self.start_date,
             days: self.days,
-            access_token: self.access_token,
             query: self.query,
             response_type: PhantomData,
         }
     }
 }
 
-impl<'a, T> ExtendedInfoNone for CalendarRequest<'a, T>
+impl<C, T> ExtendedInfoNone for CalendarRequest<C, T>
 where
     T: DeserializeOwned + WithNone,
     T::None: DeserializeOwned,
 {
     /// Request no extended info
-    fn none(mut self) -> CalendarRequest<'a, T::None> {
+    fn none(mut self) -> CalendarRequest<C, T::None> {
         self.query.remove("extended");
 
         CalendarRequest {
             client: self.client,
-            url: self.url,
+            route: self.route,
             start_date: self.start_date,
             days: self.days,
-            access_token: self.access_token,
             query: self.query,
             response_type: PhantomData,
         }
     }
 }
 
-impl<'a, T: DeserializeOwned> Filters for CalendarRequest<'a, T> {
+impl<C, T: DeserializeOwned> Filters for CalendarRequest<C, T> {
     fn query(mut self, query: &str) -> Self {
         self.query.insert("query".to_owned(), query.to_owned());
         self
@@ -220,26 +212,28 @@ impl<'a, T: DeserializeOwned> Filters for CalendarRequest<'a, T> {
     }
 }
 
-impl<'a, T> PartialEq<CalendarRequest<'a, T>> for CalendarRequest<'a, T>
+impl<C, T> PartialEq<CalendarRequest<C, T>> for CalendarRequest<C, T>
 where
+    C: PartialEq,
     T: DeserializeOwned,
 {
-    fn eq(&self, other: &CalendarRequest<'a, T>) -> bool {
+    fn eq(&self, other: &CalendarRequest<C, T>) -> bool {
         self.client == other.client
-            && self.url == other.url
+            && self.route == other.route
             && self.days == other.days
             && self.start_date == other.start_date
             && self.query == other.query
     }
 }
 
-#[cfg(test)]
-mod tests {
+#[cfg(all(test, feature = "sync"))]
+mod sync_tests {
     use crate::{
         extended_info::ExtendedInfoFull,
         filters::Filters,
         models::{CalendarShow, FullCalendarShow},
-        sync::requests::calendar::calendar_request::CalendarRequest,
+        requests::calendar::calendar_request::CalendarRequest,
+        route::Route,
         TraktApi,
     };
     use chrono::Utc;
@@ -256,19 +250,61 @@ mod tests {
         query.insert("countries".to_owned(), "us".to_owned());
 
         assert_eq!(
-            CalendarRequest::<CalendarShow>::new(&api.clone(), "some_url", None)
+            CalendarRequest::<_, CalendarShow>::new(&api.clone(), Route::new("/some_url"))
+                .start_date(Utc::today())
+                .days(3)
+                .language("de")
+                .query("tron")
+                .country("us")
+                .full(),
+            CalendarRequest::<_, FullCalendarShow> {
+                client: api,
+                route: Route::new("/some_url"),
+                start_date: Some(Utc::today()),
+                days: Some(3),
+                query,
+                response_type: PhantomData
+            }
+        )
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use crate::{
+        asyn::TraktApi,
+        extended_info::ExtendedInfoFull,
+        filters::Filters,
+        models::{CalendarShow, FullCalendarShow},
+        requests::calendar::calendar_request::CalendarRequest,
+        route::Route,
+    };
+    use chrono::Utc;
+    use std::{collections::HashMap, marker::PhantomData};
+
+    #[test]
+    fn calendar_request() {
+        let api = TraktApi::staging("...".to_owned(), Some("...".to_owned()));
+
+        let mut query = HashMap::new();
+        query.insert("extended".to_owned(), "full".to_owned());
+        query.insert("languages".to_owned(), "de".to_owned());
+        query.insert("query".to_owned(), "tron".to_owned());
+        query.insert("countries".to_owned(), "us".to_owned());
+
+        assert_eq!(
+            CalendarRequest::<_, CalendarShow>::new(&api.clone(), Route::new("/some_url"))
                 .start_date(Utc::today())
                 .days(3)
                 .language("de")
                 .query("tron")
                 .country("us")
                 .full(),
-            CalendarRequest::<FullCalendarShow> {
-                client: &api,
-                url: "some_url",
+            CalendarRequest::<_, FullCalendarShow> {
+                client: api,
+                route: Route::new("/some_url"),
                 start_date: Some(Utc::today()),
                 days: Some(3),
-                access_token: None,
                 query,
                 response_type: PhantomData
             }
@@ -0,0 +1,55 @@
+pub mod calendar_request;
+
+pub use crate::requests::calendar::calendar_request::CalendarRequest;
+
+use crate::{
+    models::{CalendarMovie, CalendarShow},
+    route::Route,
+};
+
+builders! {
+    pub fn calendar_all_shows(&self) -> CalendarRequest<Self, CalendarShow> {
+        CalendarRequest::new(self, Route::new("/calendars/all/shows"))
+    }
+
+    #[auth(required)]
+    pub fn calendar_my_shows(&self) -> CalendarRequest<Self, CalendarShow> {
+        CalendarRequest::new(self, Route::new("/calendars/my/shows"))
+    }
+
+    pub fn calendar_all_new_shows(&self) -> CalendarRequest<Self, CalendarShow> {
+        CalendarRequest::new(self, Route::new("/calendars/all/shows/new"))
+    }
+
+    #[auth(required)]
+    pub fn calendar_my_new_shows(&self) -> CalendarRequest<Self, CalendarShow> {
+        CalendarRequest::new(self, Route::new("/calendars/my/shows/new"))
+    }
+
+    pub fn calendar_all_season_premieres(&self) -> CalendarRequest<Self, CalendarShow> {
+        CalendarRequest::new(self, Route::new("/calendars/all/shows/premieres"))
+    }
+
+    #[auth(required)]
+    pub fn calendar_my_season_premieres(&self) -> CalendarRequest<Self, CalendarShow> {
+        CalendarRequest::new(self, Route::new("/calendars/my/shows/premieres"))
+    }
+
+    pub fn calendar_all_movies(&self) -> CalendarRequest<Self, CalendarMovie> {
+        CalendarRequest::new(self, Route::new("/calendars/all/movies"))
+    }
+
+    #[auth(required)]
+    pub fn calendar_my_movies(&self) -> CalendarRequest<Self, CalendarMovie> {
+        CalendarRequest::new(self, Route::new("/calendars/my/movies"))
+    }
+
+    pub fn calendar_all_dvd(&self) -> CalendarRequest<Self, CalendarMovie> {
+        CalendarRequest::new(self, Route::new("/calendars/all/dvd"))
+    }
+
+    #[auth(required)]
+    pub fn calendar_my_dvd(&self) -> CalendarRequest<Self, CalendarMovie> {
+        CalendarRequest::new(self, Route::new("/calendars/my/dvd"))
+    }
+}
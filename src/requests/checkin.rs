@@ -1,8 +1,8 @@
 use crate::{
-    error::Error,
     models::{Episode, Movie, Show},
+    request::{ApiRequest, Client},
+    route::Route,
     selectors::{SelectEpisode, SelectMovie, SelectShow},
-    Result, TraktApi,
 };
 use chrono::{DateTime, NaiveDate, Utc};
 use serde_json::{Map, Value};
@@ -20,31 +20,32 @@ use serde_json::{Map, Value};
 /// fn main() {
 ///     let api = TraktApi::new(
 ///         "...".to_owned(),
-///         None,
+///         Some("...".to_owned()),
 ///     );
 ///
-///     let access_token = "";
+///     let token = api.oauth_get_token("CODE", "urn:ietf:wg:oauth:2.0:oob").unwrap();
+///     let session = api.session(token, "urn:ietf:wg:oauth:2.0:oob");
 ///
-///     dbg!(api
+///     dbg!(session
 ///         .checkin()
 ///         .movie(|m| m.slug("warcraft-2016"))
-///         .execute(access_token)
+///         .execute()
 ///         .unwrap());
 /// }
 /// ```
 ///
 /// [More]: https://trakt.docs.apiary.io/#reference/checkin/checkin/check-into-an-item
 #[derive(Debug, PartialEq)]
-pub struct Checkin<'a> {
-    pub client: &'a TraktApi<'a>,
+pub struct Checkin<C> {
+    pub client: C,
     pub body: Map<String, Value>,
     pub sharing: CheckinSharing,
 }
 
-impl<'a> Checkin<'a> {
-    fn new(client: &'a TraktApi) -> Self {
+impl<C: Client> Checkin<C> {
+    fn new(client: &C) -> Self {
         Self {
-            client,
+            client: client.clone(),
             body: Map::new(),
             sharing: CheckinSharing::new(false, false, false),
         }
@@ -98,22 +99,21 @@ impl<'a> Checkin<'a> {
     /// Executes the checkin
     ///
     /// Panics if self.sharing or self.body can't be serialized
-    pub fn execute(mut self, access_token: &str) -> Result<CheckinResponse> {
+    pub fn execute(mut self) -> C::Output<CheckinResponse> {
         self.body.insert(
             "sharing".to_owned(),
             serde_json::to_value(self.sharing).unwrap(),
         );
 
-        self.client.auth_post(
-            api_url!(("checkin")),
+        self.client.run(ApiRequest::post(
+            Route::new("/checkin"),
             serde_json::to_string(&self.body).unwrap(),
-            access_token,
-        )
+        ))
     }
 }
 
 /// Select the movie of a checkin
-impl<'a> SelectMovie for Checkin<'a> {
+impl<C> SelectMovie for Checkin<C> {
     fn movie_value(mut self, movie: Value) -> Self {
         self.body.insert("movie".to_owned(), movie);
         self
@@ -121,7 +121,7 @@ impl<'a> SelectMovie for Checkin<'a> {
 }
 
 /// Select the episode of a checkin
-impl<'a> SelectEpisode for Checkin<'a> {
+impl<C> SelectEpisode for Checkin<C> {
     fn episode_value(mut self, episode: Value) -> Self {
         self.body.insert("episode".to_owned(), episode);
         self
@@ -129,7 +129,7 @@ impl<'a> SelectEpisode for Checkin<'a> {
 }
 
 /// Select the show of a checkin
-impl<'a> SelectShow for Checkin<'a> {
+impl<C> SelectShow for Checkin<C> {
     fn show_value(mut self, show: Value) -> Self {
         self.body.insert("show".to_owned(), show);
         self
@@ -163,40 +163,27 @@ pub struct CheckinResponse {
     pub show: Option<Show>,
 }
 
-impl<'a> TraktApi<'a> {
-    pub fn checkin(&self) -> Checkin {
-        Checkin::new(self)
+endpoints! {
+    #[auth(required)]
+    pub fn checkout(&self) -> () {
+        ApiRequest::delete(Route::new("/checkin"))
     }
+}
 
-    pub fn checkout(&self, access_token: &str) -> Result<()> {
-        match self
-            .client
-            .delete(&format!("{}/checkin", self.base_url))
-            .header("Content-Type", "application/json")
-            .header("Authorization", format!("Bearer {}", access_token))
-            .header("trakt-api-version", 2)
-            .header("trakt-api-key", self.client_id.as_str())
-            .send()
-        {
-            Ok(res) => {
-                if res.status().is_success() {
-                    Ok(())
-                } else {
-                    Err(Error::from(res))
-                }
-            }
-            Err(e) => Err(Error::from(e)),
-        }
+builders! {
+    #[auth(required)]
+    pub fn checkin(&self) -> Checkin<Self> {
+        Checkin::new(self)
     }
 }
 
-#[cfg(test)]
-mod tests {
+#[cfg(all(test, feature = "sync"))]
+mod sync_tests {
     use crate::{
         models::{Ids, Movie},
+        requests::checkin::{Checkin, CheckinResponse, CheckinSharing},
         selectors::{SelectIds, SelectMovie},
-        sync::requests::checkin::{Checkin, CheckinResponse, CheckinSharing},
-        tests::mock,
+        tests::{auth_mock, session},
         TraktApi,
     };
     use chrono::Utc;
@@ -234,7 +221,7 @@ mod tests {
         assert_eq!(
             c,
             Checkin {
-                client: &client,
+                client,
                 body,
                 sharing: CheckinSharing {
                     twitter: true,
@@ -247,7 +234,7 @@ mod tests {
 
     #[test]
     fn checkin() {
-        let m = mock("POST", "/checkin", "CLIENT_ID")
+        let m = auth_mock("POST", "/checkin", "CLIENT_ID", "ACCESS_TOKEN")
             .with_status(201)
             .with_body_from_file("mock_data/checkin.json")
             .match_body(Matcher::JsonString(
@@ -255,12 +242,12 @@ mod tests {
             ))
             .create();
 
-        TraktApi::with_url(&server_url(), "CLIENT_ID".to_string(), None)
+        session(&server_url())
             .checkin()
             .movie(|movie| movie.slug("guardians-of-the-galaxy-2014"))
             .twitter()
             .app_version("0.1.0")
-            .execute("ACCESS_TOKEN")
+            .execute()
             .map(|res| {
                 assert_eq!(
                     res,
@@ -296,18 +283,136 @@ mod tests {
 
     #[test]
     fn checkout() {
-        let m = mock("DELETE", "/checkin", "CLIENT_ID")
+        let m = auth_mock("DELETE", "/checkin", "CLIENT_ID", "ACCESS_TOKEN")
             .with_status(204)
             .create();
 
-        TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
-            .checkout("ACCESS_TOKEN")
+        session(&server_url())
+            .checkout()
             .map_err(|e| {
                 println!("{:#?}", e);
-                panic!(e);
+                panic!("{:?}", e)
             })
             .unwrap();
 
         m.assert();
     }
 }
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use crate::{
+        asyn::TraktApi,
+        error::Error,
+        models::{Ids, Movie},
+        requests::checkin::{Checkin, CheckinResponse, CheckinSharing},
+        selectors::{SelectIds, SelectMovie},
+        tests::{async_session, auth_mock},
+    };
+    use chrono::Utc;
+    use mockito::{server_url, Matcher};
+    use serde_json::{Map, Value};
+    use std::fs;
+
+    #[test]
+    fn checkin_struct() {
+        let client = TraktApi::staging("_".to_owned(), Some("_".to_owned()));
+        let c = Checkin::new(&client)
+            .twitter()
+            .message("MSG")
+            .movie(|movie| movie.slug("warcraft-2016"))
+            .app_date(Utc::today().naive_utc())
+            .app_version("0.1.0");
+
+        let mut body = Map::new();
+
+        body.insert("app_version".to_owned(), Value::String("0.1.0".to_owned()));
+        body.insert(
+            "app_date".to_owned(),
+            serde_json::to_value(Utc::today().naive_utc()).unwrap(),
+        );
+        body.insert("message".to_owned(), Value::String("MSG".to_owned()));
+        body.insert(
+            "movie".to_owned(),
+            json!({
+                "ids": {
+                    "slug": "warcraft-2016"
+                }
+            }),
+        );
+
+        assert_eq!(
+            c,
+            Checkin {
+                client,
+                body,
+                sharing: CheckinSharing {
+                    twitter: true,
+                    tumblr: false,
+                    facebook: false
+                }
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn checkin() -> Result<(), Error> {
+        let m = auth_mock("POST", "/checkin", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(201)
+            .with_body_from_file("mock_data/checkin.json")
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/checkin_req.json").unwrap(),
+            ))
+            .create();
+
+        let res = async_session(&server_url())
+            .checkin()
+            .movie(|movie| movie.slug("guardians-of-the-galaxy-2014"))
+            .twitter()
+            .app_version("0.1.0")
+            .execute()
+            .await?;
+
+        assert_eq!(
+            res,
+            CheckinResponse {
+                id: 3373536619,
+                watched_at: "2014-08-06T01:11:37.000Z".parse().unwrap(),
+                sharing: CheckinSharing {
+                    twitter: true,
+                    tumblr: false,
+                    facebook: false
+                },
+                movie: Some(Movie {
+                    title: "Guardians of the Galaxy".to_string(),
+                    year: Some(2014),
+                    ids: Ids {
+                        trakt: Some(28),
+                        slug: Some("guardians-of-the-galaxy-2014".to_owned()),
+                        tvdb: None,
+                        imdb: Some("tt2015381".to_owned()),
+                        tmdb: Some(118340),
+                        tvrage: None
+                    }
+                }),
+                episode: None,
+                show: None
+            }
+        );
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn checkout() -> Result<(), Error> {
+        let m = auth_mock("DELETE", "/checkin", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(204)
+            .create();
+
+        async_session(&server_url()).checkout().await?;
+        m.assert();
+
+        Ok(())
+    }
+}
@@ -1,24 +1,24 @@
 use crate::{
     models::Comment,
+    request::{ApiRequest, Client},
+    route::Route,
     selectors::{SelectEpisode, SelectList, SelectMovie, SelectSeason, SelectShow},
-    Error, Result, TraktApi,
 };
-use reqwest::{Method, Request};
 use serde_json::{Map, Value};
 
-pub struct CommentCreateRequest<'a> {
-    client: &'a TraktApi<'a>,
-    url: String,
+pub struct CommentCreateRequest<C> {
+    client: C,
+    route: Route,
     body: Map<String, Value>,
 }
 
-impl<'a> CommentCreateRequest<'a> {
-    pub fn new(client: &'a TraktApi, url: String, comment: &'a str) -> Self {
+impl<C: Client> CommentCreateRequest<C> {
+    pub fn new(client: &C, route: Route, comment: &str) -> Self {
         let mut m = Map::new();
         m.insert("comment".to_owned(), Value::String(comment.to_owned()));
         Self {
-            client,
-            url,
+            client: client.clone(),
+            route,
             body: m,
         }
     }
@@ -60,49 +60,47 @@ impl<'a> CommentCreateRequest<'a> {
         self.sharing("medium".to_owned())
     }
 
-    pub fn build(&mut self, access_token: &str) -> std::result::Result<Request, Error> {
-        self.client
-            .builder(Method::POST, self.url.to_owned())
-            .header("Authorization", format!("Bearer {}", access_token))
-            .body(serde_json::to_string(&self.body).unwrap())
-            .build()
-            .map_err(Error::from)
+    pub fn build(&self) -> ApiRequest<Comment> {
+        ApiRequest::post(
+            self.route.clone(),
+            serde_json::to_string(&self.body).unwrap(),
+        )
     }
 
-    pub fn execute(mut self, access_token: &str) -> Result<Comment> {
-        self.client.execute(self.build(access_token)?)
+    pub fn execute(self) -> C::Output<Comment> {
+        self.client.run(self.build())
     }
 }
 
-impl<'a> SelectMovie for CommentCreateRequest<'a> {
+impl<C> SelectMovie for CommentCreateRequest<C> {
     fn movie_value(mut self, movie: Value) -> Self {
         self.body.insert("movie".to_owned(), movie);
         self
     }
 }
 
-impl<'a> SelectEpisode for CommentCreateRequest<'a> {
+impl<C> SelectEpisode for CommentCreateRequest<C> {
     fn episode_value(mut self, episode: Value) -> Self {
         self.body.insert("episode".to_ascii_lowercase(), episode);
         self
     }
 }
 
-impl<'a> SelectShow for CommentCreateRequest<'a> {
+impl<C> SelectShow for CommentCreateRequest<C> {
     fn show_value(mut self, show: Value) -> Self {
         self.body.insert("show".to_owned(), show);
         self
     }
 }
 
-impl<'a> SelectSeason for CommentCreateRequest<'a> {
+impl<C> SelectSeason for CommentCreateRequest<C> {
     fn season_value(mut self, season: Value) -> Self {
         self.body.insert("season".to_owned(), season);
         self
     }
 }
 
-impl<'a> SelectList for CommentCreateRequest<'a> {
+impl<C> SelectList for CommentCreateRequest<C> {
     fn list_value(mut self, list: Value) -> Self {
         self.body.insert("list".to_owned(), list);
         self
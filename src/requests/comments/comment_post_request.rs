@@ -0,0 +1,53 @@
+use crate::{
+    models::Comment,
+    request::{ApiRequest, Client},
+    route::Route,
+};
+use reqwest::Method;
+use serde_json::{Map, Value};
+
+pub struct CommentPostRequest<C> {
+    client: C,
+    route: Route,
+    // false => POST, true => PUT
+    method: bool,
+    comment: String,
+    spoiler: bool,
+}
+
+impl<C: Client> CommentPostRequest<C> {
+    pub fn new(client: &C, route: Route, method: bool, comment: String) -> Self {
+        Self {
+            client: client.clone(),
+            route,
+            method,
+            comment,
+            spoiler: false,
+        }
+    }
+
+    pub fn spoiler(mut self) -> Self {
+        self.spoiler = true;
+        self
+    }
+
+    pub fn build(&self) -> ApiRequest<Comment> {
+        let mut m = Map::new();
+        m.insert("comment".to_owned(), Value::String(self.comment.clone()));
+        m.insert("spoiler".to_owned(), Value::Bool(self.spoiler));
+
+        ApiRequest::new(
+            if self.method {
+                Method::PUT
+            } else {
+                Method::POST
+            },
+            self.route.clone(),
+        )
+        .with_body(Value::Object(m).to_string())
+    }
+
+    pub fn execute(self) -> C::Output<Comment> {
+        self.client.run(self.build())
+    }
+}
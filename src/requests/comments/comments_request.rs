@@ -1,28 +1,27 @@
 use crate::{
-    asyn::{Result, TraktApi},
     extended_info::{ExtendedInfoFull, ExtendedInfoNone, WithFull, WithNone},
     models::{AllCommentableItemType, CommentType},
-    pagination::Pagination,
-    Error,
+    pagination::{Page, Pagination},
+    request::{ApiRequest, Client},
+    route::Route,
 };
-use reqwest::{r#async::Request, Method};
 use serde::de::DeserializeOwned;
 use std::{collections::HashMap, marker::PhantomData};
 
-pub struct CommentsRequest<'a, T> {
-    client: &'a TraktApi<'a>,
-    url: &'a str,
+pub struct CommentsRequest<C, T> {
+    client: C,
+    route: Route,
     comment_type: CommentType,
     item_type: AllCommentableItemType,
     query: HashMap<String, String>,
     response_type: PhantomData<T>,
 }
 
-impl<'a, T: DeserializeOwned + Send + 'static> CommentsRequest<'a, T> {
-    pub fn new(client: &'a TraktApi, url: &'a str) -> Self {
+impl<C: Client, T: DeserializeOwned + Send + 'static> CommentsRequest<C, T> {
+    pub fn new(client: &C, route: Route) -> Self {
         Self {
-            client,
-            url,
+            client: client.clone(),
+            route,
             comment_type: CommentType::All,
             item_type: AllCommentableItemType::All,
             query: HashMap::new(),
@@ -74,30 +73,52 @@ impl<'a, T: DeserializeOwned + Send + 'static> CommentsRequest<'a, T> {
         self
     }
 
-    pub fn build(&self) -> std::result::Result<Request, Error> {
-        let url = format!(
-            "/comments/{}/{}/{}",
-            self.url, self.comment_type, self.item_type
-        );
+    pub fn build(&self) -> ApiRequest<Vec<T>> {
+        let route = self
+            .route
+            .clone()
+            .segment(&self.comment_type)
+            .segment(&self.item_type);
 
-        let mut req = self.client.builder(Method::GET, url);
+        let mut req = ApiRequest::get(route);
 
-        if !self.query.is_empty() {
-            req = req.query(&self.query);
+        for (key, value) in &self.query {
+            req = req.query(key, value);
         }
 
-        req.build().map_err(Error::from)
+        req
     }
 
-    pub fn execute(self) -> Result<Vec<T>> {
-        match self.build() {
-            Ok(req) => self.client.execute(req),
-            Err(e) => Box::new(futures::future::err(e)),
-        }
+    pub fn execute(self) -> C::Output<Vec<T>> {
+        self.client.run(self.build())
+    }
+
+    /// Executes the request and returns the items together with the pagination headers
+    pub fn execute_page(self) -> C::Output<Page<T>> {
+        self.client.run_page(self.build())
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T: DeserializeOwned + Send + 'static> CommentsRequest<crate::sync::TraktApi, T> {
+    /// Returns an iterator over the items of all pages, starting at the requested page.
+    /// Pages are fetched lazily.
+    pub fn into_iter_all(self) -> crate::sync::pagination::PaginationIter<T> {
+        crate::sync::pagination::PaginationRequest::new(&self.client, self.build()).into_iter_all()
     }
 }
 
-impl<'a, T> Pagination for CommentsRequest<'a, T> {
+#[cfg(feature = "async")]
+impl<T: DeserializeOwned + Send + 'static> CommentsRequest<crate::asyn::TraktApi, T> {
+    /// Returns a stream of the items of all pages, starting at the requested page.
+    /// Pages are fetched lazily.
+    pub fn into_stream_all(self) -> crate::asyn::pagination::PaginationStream<T> {
+        crate::asyn::pagination::PaginationRequest::new(&self.client, self.build())
+            .into_stream_all()
+    }
+}
+
+impl<C, T> Pagination for CommentsRequest<C, T> {
     fn page(mut self, page: u32) -> Self {
         self.query.insert("page".to_owned(), format!("{}", page));
         self
@@ -109,21 +130,21 @@ impl<'a, T> Pagination for CommentsRequest<'a, T> {
     }
 }
 
-impl<'a, T: WithFull> WithFull for CommentsRequest<'a, T> {
-    type Full = CommentsRequest<'a, T::Full>;
+impl<C, T: WithFull> WithFull for CommentsRequest<C, T> {
+    type Full = CommentsRequest<C, T::Full>;
 }
 
-impl<'a, T: WithNone> WithNone for CommentsRequest<'a, T> {
-    type None = CommentsRequest<'a, T::None>;
+impl<C, T: WithNone> WithNone for CommentsRequest<C, T> {
+    type None = CommentsRequest<C, T::None>;
 }
 
-impl<'a, T: WithFull> ExtendedInfoFull for CommentsRequest<'a, T> {
+impl<C, T: WithFull> ExtendedInfoFull for CommentsRequest<C, T> {
     fn full(mut self) -> Self::Full {
         self.query.insert("extended".to_owned(), "full".to_owned());
 
         Self::Full {
             client: self.client,
-            url: self.url,
+            route: self.route,
             comment_type: self.comment_type,
             item_type: self.item_type,
             query: self.query,
@@ -132,13 +153,13 @@ impl<'a, T: WithFull> ExtendedInfoFull for CommentsRequest<'a, T> {
     }
 }
 
-impl<'a, T: WithNone> ExtendedInfoNone for CommentsRequest<'a, T> {
+impl<C, T: WithNone> ExtendedInfoNone for CommentsRequest<C, T> {
     fn none(mut self) -> Self::None {
         self.query.remove("extended");
 
         Self::None {
             client: self.client,
-            url: self.url,
+            route: self.route,
             comment_type: self.comment_type,
             item_type: self.item_type,
             query: self.query,
@@ -146,10 +167,3 @@ impl<'a, T: WithNone> ExtendedInfoNone for CommentsRequest<'a, T> {
         }
     }
 }
-
-#[cfg(tests)]
-mod tests {
-
-    #[test]
-    fn comments_request() {}
-}
@@ -0,0 +1,94 @@
+pub mod comment_create_request;
+pub mod comment_post_request;
+pub mod comments_request;
+
+use crate::{
+    models::{Comment, CommentAndItem, CommentItem, Like},
+    request::ApiRequest,
+    requests::comments::{
+        comment_create_request::CommentCreateRequest, comment_post_request::CommentPostRequest,
+        comments_request::CommentsRequest,
+    },
+    route::Route,
+};
+
+endpoints! {
+    pub fn comment(&self, id: u32) -> Comment {
+        ApiRequest::get(Route::new("/comments").segment(id))
+    }
+
+    #[auth(required)]
+    pub fn comment_delete(&self, comment_id: u32) -> () {
+        ApiRequest::delete(Route::new("/comments").segment(comment_id))
+    }
+
+    pub fn replies(&self, comment_id: u32) -> Paginated<Comment> {
+        ApiRequest::get(
+            Route::new("/comments")
+                .segment(comment_id)
+                .segment("replies"),
+        )
+    }
+
+    pub fn comment_item(&self, comment_id: u32) -> CommentItem {
+        ApiRequest::get(Route::new("/comments").segment(comment_id).segment("item"))
+    }
+
+    pub fn comment_likes(&self, comment_id: u32) -> Paginated<Like> {
+        ApiRequest::get(Route::new("/comments").segment(comment_id).segment("likes"))
+    }
+
+    #[auth(required)]
+    pub fn comment_like(&self, comment_id: u32) -> () {
+        ApiRequest::post(
+            Route::new("/comments").segment(comment_id).segment("like"),
+            String::new(),
+        )
+    }
+
+    #[auth(required)]
+    pub fn comment_like_delete(&self, comment_id: u32) -> () {
+        ApiRequest::delete(Route::new("/comments").segment(comment_id).segment("like"))
+    }
+}
+
+builders! {
+    #[auth(required)]
+    pub fn comment_create(&self, comment: &str) -> CommentCreateRequest<Self> {
+        CommentCreateRequest::new(self, Route::new("/comments"), comment)
+    }
+
+    #[auth(required)]
+    pub fn comment_update(&self, comment_id: u32, comment: String) -> CommentPostRequest<Self> {
+        CommentPostRequest::new(
+            self,
+            Route::new("/comments").segment(comment_id),
+            true,
+            comment,
+        )
+    }
+
+    #[auth(required)]
+    pub fn replies_post(&self, comment_id: u32, comment: String) -> CommentPostRequest<Self> {
+        CommentPostRequest::new(
+            self,
+            Route::new("/comments")
+                .segment(comment_id)
+                .segment("replies"),
+            false,
+            comment,
+        )
+    }
+
+    pub fn comments_trending(&self) -> CommentsRequest<Self, CommentAndItem> {
+        CommentsRequest::new(self, Route::new("/comments/trending"))
+    }
+
+    pub fn comments_recent(&self) -> CommentsRequest<Self, CommentAndItem> {
+        CommentsRequest::new(self, Route::new("/comments/recent"))
+    }
+
+    pub fn comments_updates(&self) -> CommentsRequest<Self, CommentAndItem> {
+        CommentsRequest::new(self, Route::new("/comments/updates"))
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    models::{List, ListPrivacy},
+    request::{ApiRequest, Client},
+    route::Route,
+};
+use reqwest::Method;
+use serde_json::{Map, Value};
+
+/// A request for creating or updating a personal [list]
+///
+/// [list]: https://trakt.docs.apiary.io/#reference/users/lists
+#[derive(Debug, PartialEq)]
+pub struct ListCreateRequest<C> {
+    pub client: C,
+    pub method: Method,
+    pub route: Route,
+    pub body: Map<String, Value>,
+}
+
+impl<C: Client> ListCreateRequest<C> {
+    pub fn new(client: &C, method: Method, route: Route) -> Self {
+        Self {
+            client: client.clone(),
+            method,
+            route,
+            body: Map::new(),
+        }
+    }
+
+    /// Set the name of the list
+    pub fn name(mut self, name: &str) -> Self {
+        self.body
+            .insert("name".to_owned(), Value::String(name.to_owned()));
+        self
+    }
+
+    /// Set the description of the list
+    pub fn description(mut self, description: &str) -> Self {
+        self.body.insert(
+            "description".to_owned(),
+            Value::String(description.to_owned()),
+        );
+        self
+    }
+
+    /// Set who can see the list
+    pub fn privacy(mut self, privacy: ListPrivacy) -> Self {
+        self.body
+            .insert("privacy".to_owned(), Value::String(privacy.to_string()));
+        self
+    }
+
+    /// Show the rank of each item
+    pub fn display_numbers(mut self, display_numbers: bool) -> Self {
+        self.body
+            .insert("display_numbers".to_owned(), Value::Bool(display_numbers));
+        self
+    }
+
+    /// Allow comments on the list
+    pub fn allow_comments(mut self, allow_comments: bool) -> Self {
+        self.body
+            .insert("allow_comments".to_owned(), Value::Bool(allow_comments));
+        self
+    }
+
+    /// Set the default sorting of the list, e.g. `rank`, `added` or `title`
+    pub fn sort_by(mut self, sort_by: &str) -> Self {
+        self.body
+            .insert("sort_by".to_owned(), Value::String(sort_by.to_owned()));
+        self
+    }
+
+    /// Set the default sort direction of the list, `asc` or `desc`
+    pub fn sort_how(mut self, sort_how: &str) -> Self {
+        self.body
+            .insert("sort_how".to_owned(), Value::String(sort_how.to_owned()));
+        self
+    }
+
+    pub fn build(&self) -> ApiRequest<List> {
+        ApiRequest::new(self.method.clone(), self.route.clone())
+            .with_body(Value::Object(self.body.clone()).to_string())
+    }
+
+    pub fn execute(self) -> C::Output<List> {
+        self.client.run(self.build())
+    }
+}
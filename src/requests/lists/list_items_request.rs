@@ -0,0 +1,115 @@
+use crate::{
+    request::{ApiRequest, Client},
+    route::Route,
+    selectors::{SelectEpisode, SelectMovie, SelectPerson, SelectSeason, SelectShow},
+};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::marker::PhantomData;
+
+/// A struct for adding items to or removing items from a personal list
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use trakt::{selectors::*, TraktApi};
+///
+/// fn main() {
+///     let api = TraktApi::new(
+///         "...".to_owned(),
+///         Some("...".to_owned()),
+///     );
+///
+///     let token = api.oauth_get_token("CODE", "urn:ietf:wg:oauth:2.0:oob").unwrap();
+///     let session = api.session(token, "urn:ietf:wg:oauth:2.0:oob");
+///
+///     dbg!(session
+///         .user_list_items_add(|l| l.user(|u| u.slug("sean")).slug("star-wars-in-machete-order"))
+///         .movie(|m| m.slug("star-wars-episode-iv-a-new-hope-1977").notes("Watch first"))
+///         .person(|p| p.slug("mark-hamill"))
+///         .execute()
+///         .unwrap());
+/// }
+/// ```
+///
+/// Executing it fails with [Error::NoneError] if the slug of the user or both the trakt id and
+/// slug of the list are missing.
+///
+/// **Note**: [Session.user_list_items_add()] returns ListItemsRequest<Session, ListItemsAddResponse>
+///
+/// [Error::NoneError]: ../../../error/enum.Error.html#variant.NoneError
+/// [Session.user_list_items_add()]: ../../../struct.Session.html#method.user_list_items_add
+pub struct ListItemsRequest<C, R: DeserializeOwned> {
+    movies: Vec<Value>,
+    shows: Vec<Value>,
+    seasons: Vec<Value>,
+    episodes: Vec<Value>,
+    people: Vec<Value>,
+    route: Option<Route>,
+    client: C,
+    response_type: PhantomData<R>,
+}
+
+impl<C: Client, R: DeserializeOwned + Send + 'static> ListItemsRequest<C, R> {
+    pub fn new(route: Option<Route>, client: &C) -> Self {
+        Self {
+            movies: Vec::new(),
+            shows: Vec::new(),
+            seasons: Vec::new(),
+            episodes: Vec::new(),
+            people: Vec::new(),
+            route,
+            client: client.clone(),
+            response_type: PhantomData,
+        }
+    }
+
+    pub fn execute(self) -> C::Output<R> {
+        let mut obj = Map::new();
+        obj.insert("movies".to_owned(), Value::Array(self.movies));
+        obj.insert("shows".to_owned(), Value::Array(self.shows));
+        obj.insert("seasons".to_owned(), Value::Array(self.seasons));
+        obj.insert("episodes".to_owned(), Value::Array(self.episodes));
+        obj.insert("people".to_owned(), Value::Array(self.people));
+        let body = Value::Object(obj);
+
+        self.client
+            .run(ApiRequest::try_new(Method::POST, self.route).with_body(body.to_string()))
+    }
+}
+
+impl<C, R: DeserializeOwned> SelectMovie for ListItemsRequest<C, R> {
+    fn movie_value(mut self, movie: Value) -> Self {
+        self.movies.push(movie);
+        self
+    }
+}
+
+impl<C, R: DeserializeOwned> SelectShow for ListItemsRequest<C, R> {
+    fn show_value(mut self, show: Value) -> Self {
+        self.shows.push(show);
+        self
+    }
+}
+
+impl<C, R: DeserializeOwned> SelectSeason for ListItemsRequest<C, R> {
+    fn season_value(mut self, season: Value) -> Self {
+        self.seasons.push(season);
+        self
+    }
+}
+
+impl<C, R: DeserializeOwned> SelectEpisode for ListItemsRequest<C, R> {
+    fn episode_value(mut self, episode: Value) -> Self {
+        self.episodes.push(episode);
+        self
+    }
+}
+
+impl<C, R: DeserializeOwned> SelectPerson for ListItemsRequest<C, R> {
+    fn person_value(mut self, person: Value) -> Self {
+        self.people.push(person);
+        self
+    }
+}
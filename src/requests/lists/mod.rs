@@ -0,0 +1,1193 @@
+pub mod list_create_request;
+pub mod list_items_request;
+
+use crate::{
+    models::{
+        Comment, CommentSort, FullList, FullListItem, Like, List, ListInfo, ListItem, ListItemType,
+        ListItemsAddResponse, ListItemsRemoveResponse, ListItemsReorderResponse,
+    },
+    request::ApiRequest,
+    requests::lists::{
+        list_create_request::ListCreateRequest, list_items_request::ListItemsRequest,
+    },
+    route::Route,
+    selectors::ListSelector,
+};
+use reqwest::Method;
+use serde_json::{Map, Value};
+
+/// The route of a personal list of an user
+fn list_route(slug: &str, list_id: &str) -> Route {
+    Route::new("/users")
+        .segment(slug)
+        .segment("lists")
+        .segment(list_id)
+}
+
+/// The route of a personal list selected by the slug of its user and its trakt id or slug.
+/// None if one of them is missing.
+fn selected_list_route(list: impl FnOnce(ListSelector) -> ListSelector) -> Option<Route> {
+    let (slug, list_id) = list(ListSelector::default()).user_and_list_id().ok()?;
+    Some(list_route(&slug, &list_id))
+}
+
+endpoints! {
+    pub fn lists_trending(&self) -> Paginated<ListInfo> {
+        ApiRequest::get(Route::new("/lists/trending"))
+    }
+
+    pub fn lists_popular(&self) -> Paginated<ListInfo> {
+        ApiRequest::get(Route::new("/lists/popular"))
+    }
+
+    #[auth(optional)]
+    /// Get a list by its trakt id
+    pub fn list(&self, id: u64) -> List {
+        ApiRequest::get(Route::new("/lists").segment(id))
+    }
+
+    #[auth(optional)]
+    /// Get a list by its trakt id with full extended info. [More]
+    ///
+    /// [More]: https://trakt.docs.apiary.io/#reference/lists/list/get-list
+    pub fn list_full(&self, id: u64) -> FullList {
+        ApiRequest::get(Route::new("/lists").segment(id)).query("extended", "full")
+    }
+
+    #[auth(optional)]
+    /// Get the items of a list by its trakt id, optionally only those of one type
+    pub fn list_items(&self, id: u64, item_type: Option<ListItemType>) -> Paginated<ListItem> {
+        ApiRequest::get(
+            Route::new("/lists")
+                .segment(id)
+                .segment("items")
+                .optional_segment(item_type),
+        )
+    }
+
+    /// Get the users who liked a list. [More]
+    ///
+    /// [More]: https://trakt.docs.apiary.io/#reference/lists/list-likes/get-all-users-who-liked-a-list
+    pub fn list_likes(&self, id: u64) -> Paginated<Like> {
+        ApiRequest::get(Route::new("/lists").segment(id).segment("likes"))
+    }
+
+    /// Get the comments of a list, sorted by `sort`. [More]
+    ///
+    /// [More]: https://trakt.docs.apiary.io/#reference/lists/list-comments/get-all-list-comments
+    pub fn list_comments(&self, id: u64, sort: CommentSort) -> Paginated<Comment> {
+        ApiRequest::get(
+            Route::new("/lists")
+                .segment(id)
+                .segment("comments")
+                .segment(sort),
+        )
+    }
+
+    #[auth(required)]
+    /// Like a list. [More]
+    ///
+    /// [More]: https://trakt.docs.apiary.io/#reference/lists/list-like/like-a-list
+    pub fn list_like(&self, id: u64) -> () {
+        ApiRequest::post(
+            Route::new("/lists").segment(id).segment("like"),
+            String::new(),
+        )
+    }
+
+    #[auth(required)]
+    /// Remove the like of a list. [More]
+    ///
+    /// [More]: https://trakt.docs.apiary.io/#reference/lists/list-like/remove-like-on-a-list
+    pub fn list_unlike(&self, id: u64) -> () {
+        ApiRequest::delete(Route::new("/lists").segment(id).segment("like"))
+    }
+
+    #[auth(optional)]
+    /// Get the personal lists of a user. Private lists are only returned to their owner
+    pub fn user_lists(&self, slug: &str) -> Vec<List> {
+        ApiRequest::get(Route::new("/users").segment(slug).segment("lists"))
+    }
+
+    #[auth(optional)]
+    /// Get the personal lists of a user with full extended info. [More]
+    ///
+    /// [More]: https://trakt.docs.apiary.io/#reference/users/lists/get-a-user's-personal-lists
+    pub fn user_lists_full(&self, slug: &str) -> Vec<FullList> {
+        ApiRequest::get(Route::new("/users").segment(slug).segment("lists"))
+            .query("extended", "full")
+    }
+
+    #[auth(optional)]
+    /// Get a personal list of a user by its trakt id or slug
+    pub fn user_list(&self, slug: &str, list_id: &str) -> List {
+        ApiRequest::get(list_route(slug, list_id))
+    }
+
+    #[auth(optional)]
+    /// Get a personal list of a user by its trakt id or slug with full extended info. [More]
+    ///
+    /// [More]: https://trakt.docs.apiary.io/#reference/users/list/get-personal-list
+    pub fn user_list_full(&self, slug: &str, list_id: &str) -> FullList {
+        ApiRequest::get(list_route(slug, list_id)).query("extended", "full")
+    }
+
+    #[auth(required)]
+    /// Delete a personal list including all of its items
+    pub fn user_list_delete(&self, slug: &str, list_id: &str) -> () {
+        ApiRequest::delete(list_route(slug, list_id))
+    }
+
+    #[auth(optional)]
+    /// Get the items of a personal list, optionally only those of one type
+    ///
+    /// The list is selected by the slug of its user and its trakt id or slug. Executing the
+    /// request fails with [Error::NoneError] if one of them is missing.
+    ///
+    /// [Error::NoneError]: error/enum.Error.html#variant.NoneError
+    pub fn user_list_items(
+        &self,
+        list: impl FnOnce(ListSelector) -> ListSelector,
+        item_type: Option<ListItemType>,
+    ) -> Paginated<ListItem> {
+        ApiRequest::try_new(
+            Method::GET,
+            selected_list_route(list)
+                .map(|route| route.segment("items").optional_segment(item_type)),
+        )
+    }
+
+    #[auth(optional)]
+    /// Get the items of a personal list with full extended info. [More]
+    ///
+    /// The list is selected like in [user_list_items].
+    ///
+    /// [user_list_items]: #method.user_list_items
+    /// [More]: https://trakt.docs.apiary.io/#reference/users/list-items/get-items-on-a-personal-list
+    pub fn user_list_items_full(
+        &self,
+        list: impl FnOnce(ListSelector) -> ListSelector,
+        item_type: Option<ListItemType>,
+    ) -> Paginated<FullListItem> {
+        ApiRequest::try_new(
+            Method::GET,
+            selected_list_route(list)
+                .map(|route| route.segment("items").optional_segment(item_type)),
+        )
+        .query("extended", "full")
+    }
+
+    #[auth(required)]
+    /// Reorder the items of a personal list. `rank` contains the ids of the list items in
+    /// their new order
+    pub fn user_list_items_reorder(
+        &self,
+        list: impl FnOnce(ListSelector) -> ListSelector,
+        rank: Vec<u64>,
+    ) -> ListItemsReorderResponse {
+        let mut body = Map::new();
+        body.insert(
+            "rank".to_owned(),
+            Value::Array(rank.into_iter().map(Value::from).collect()),
+        );
+
+        ApiRequest::try_new(
+            Method::POST,
+            selected_list_route(list).map(|route| route.segment("items").segment("reorder")),
+        )
+        .with_body(Value::Object(body).to_string())
+    }
+
+    #[auth(required)]
+    /// Set the notes of an item of a personal list
+    pub fn user_list_item_notes(
+        &self,
+        list: impl FnOnce(ListSelector) -> ListSelector,
+        list_item_id: u64,
+        notes: &str,
+    ) -> () {
+        let mut body = Map::new();
+        body.insert("notes".to_owned(), Value::String(notes.to_owned()));
+
+        ApiRequest::try_new(
+            Method::PUT,
+            selected_list_route(list).map(|route| route.segment("items").segment(list_item_id)),
+        )
+        .with_body(Value::Object(body).to_string())
+    }
+}
+
+builders! {
+    #[auth(required)]
+    /// Create a personal list
+    pub fn user_list_create(&self, slug: &str, name: &str) -> ListCreateRequest<Self> {
+        ListCreateRequest::new(
+            self,
+            Method::POST,
+            Route::new("/users").segment(slug).segment("lists"),
+        )
+        .name(name)
+    }
+
+    #[auth(required)]
+    /// Update a personal list. Only the fields which are set are changed
+    pub fn user_list_update(&self, slug: &str, list_id: &str) -> ListCreateRequest<Self> {
+        ListCreateRequest::new(self, Method::PUT, list_route(slug, list_id))
+    }
+
+    #[auth(required)]
+    /// Add movies, shows, seasons, episodes and people to a personal list
+    pub fn user_list_items_add(
+        &self,
+        list: impl FnOnce(ListSelector) -> ListSelector,
+    ) -> ListItemsRequest<Self, ListItemsAddResponse> {
+        ListItemsRequest::new(
+            selected_list_route(list).map(|route| route.segment("items")),
+            self,
+        )
+    }
+
+    #[auth(required)]
+    /// Remove movies, shows, seasons, episodes and people from a personal list
+    pub fn user_list_items_remove(
+        &self,
+        list: impl FnOnce(ListSelector) -> ListSelector,
+    ) -> ListItemsRequest<Self, ListItemsRemoveResponse> {
+        ListItemsRequest::new(
+            selected_list_route(list).map(|route| route.segment("items").segment("remove")),
+            self,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod sync_tests {
+    use crate::{
+        error::Error,
+        models::{
+            CommentSort, Ids, List, ListInfo, ListItemType, ListItemsNumbers, ListPrivacy, User,
+        },
+        pagination::Pagination,
+        selectors::{SelectIds, SelectMovie, SelectPerson, Selector},
+        tests::{auth_mock, mock, session},
+        TraktApi,
+    };
+    use chrono::{offset::TimeZone, Utc};
+    use mockito::{server_url, Matcher};
+    use std::fs;
+
+    #[test]
+    fn lists_trending() -> Result<(), Error> {
+        let m = mock("GET", "/lists/trending?page=1&limit=5", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/lists.json")
+            .create();
+
+        let res = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
+            .lists_trending()
+            .page(1)
+            .limit(5)
+            .execute()
+            .map(|res| {
+                assert!(
+                    res.contains(
+                        &ListInfo {
+                            like_count: 89,
+                            comment_count: 0,
+                            list: List {
+                                name: "Movie Selections Based on Subreddits".to_string(),
+                                description: Some("This is a list based on movies found in the following subreddits: r/iwatchedanoldmovier/flicksr/moviesclub".to_string()),
+                                privacy: Some("public".to_string()),
+                                display_numbers: false,
+                                allow_comments: true,
+                                sort_by: "rank".to_string(),
+                                sort_how: "asc".to_string(),
+                                created_at: Utc.ymd(2019, 02, 18).and_hms(16, 37, 54),
+                                updated_at: None,
+                                item_count: 92,
+                                comment_count: 0,
+                                likes: 89,
+                                ids: Ids {
+                                    trakt: Some(6319578),
+                                    slug: Some("movie-selections-based-on-subreddits".to_string()),
+                                    tvdb: None,
+                                    imdb: None,
+                                    tmdb: None,
+                                    tvrage: None
+                                },
+                                user: User {
+                                    username: "Giladg".to_string(),
+                                    private: false,
+                                    name: Some("Gilad & Alex".to_string()),
+                                    vip: Some(false),
+                                    vip_ep: Some(false),
+                                    ids: Ids {
+                                        trakt: None,
+                                        slug: Some("giladg".to_owned()),
+                                        tvdb: None,
+                                        imdb: None,
+                                        tmdb: None,
+                                        tvrage: None
+                                    }
+                                }
+                            }
+                        }
+                    )
+                )
+            });
+
+        m.assert();
+        res
+    }
+
+    #[test]
+    fn lists_popular() -> Result<(), Error> {
+        let m = mock("GET", "/lists/popular?page=1&limit=5", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/lists.json")
+            .create();
+
+        let res = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
+            .lists_popular()
+            .page(1)
+            .limit(5)
+            .execute()
+            .map(|res| {
+                assert!(
+                    res.contains(
+                        &ListInfo {
+                            like_count: 89,
+                            comment_count: 0,
+                            list: List {
+                                name: "Movie Selections Based on Subreddits".to_string(),
+                                description: Some("This is a list based on movies found in the following subreddits: r/iwatchedanoldmovier/flicksr/moviesclub".to_string()),
+                                privacy: Some("public".to_string()),
+                                display_numbers: false,
+                                allow_comments: true,
+                                sort_by: "rank".to_string(),
+                                sort_how: "asc".to_string(),
+                                created_at: Utc.ymd(2019, 02, 18).and_hms(16, 37, 54),
+                                updated_at: None,
+                                item_count: 92,
+                                comment_count: 0,
+                                likes: 89,
+                                ids: Ids {
+                                    trakt: Some(6319578),
+                                    slug: Some("movie-selections-based-on-subreddits".to_string()),
+                                    tvdb: None,
+                                    imdb: None,
+                                    tmdb: None,
+                                    tvrage: None
+                                },
+                                user: User {
+                                    username: "Giladg".to_string(),
+                                    private: false,
+                                    name: Some("Gilad & Alex".to_string()),
+                                    vip: Some(false),
+                                    vip_ep: Some(false),
+                                    ids: Ids {
+                                        trakt: None,
+                                        slug: Some("giladg".to_owned()),
+                                        tvdb: None,
+                                        imdb: None,
+                                        tmdb: None,
+                                        tvrage: None
+                                    }
+                                }
+                            }
+                        }
+                    )
+                )
+            });
+
+        m.assert();
+        res
+    }
+
+    #[test]
+    fn lists_trending_page() -> Result<(), Error> {
+        let m = mock("GET", "/lists/trending?page=2&limit=5", "CLIENT_ID")
+            .with_status(200)
+            .with_header("X-Pagination-Page", "2")
+            .with_header("X-Pagination-Limit", "5")
+            .with_header("X-Pagination-Page-Count", "2")
+            .with_header("X-Pagination-Item-Count", "8")
+            .with_body_from_file("mock_data/lists.json")
+            .create();
+
+        let page = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
+            .lists_trending()
+            .page(2)
+            .limit(5)
+            .execute_page()?;
+
+        assert_eq!(page.page, 2);
+        assert_eq!(page.limit, 5);
+        assert_eq!(page.page_count, 2);
+        assert_eq!(page.item_count, 8);
+        assert!(!page.has_next_page());
+        assert!(!page.items.is_empty());
+
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn lists_trending_all() -> Result<(), Error> {
+        let pages: Vec<_> = (1..=3)
+            .map(|page| {
+                mock(
+                    "GET",
+                    &format!("/lists/trending?limit=5&page={}", page),
+                    "CLIENT_ID",
+                )
+                .with_status(200)
+                .with_header("X-Pagination-Page", &page.to_string())
+                .with_header("X-Pagination-Limit", "5")
+                .with_header("X-Pagination-Page-Count", "3")
+                .with_header("X-Pagination-Item-Count", "15")
+                .with_body_from_file("mock_data/lists.json")
+                .expect(if page < 3 { 2 } else { 1 })
+                .create()
+            })
+            .collect();
+
+        let url = server_url();
+        let api = TraktApi::with_url(&url, "CLIENT_ID".to_owned(), None);
+
+        let lists = api
+            .lists_trending()
+            .limit(5)
+            .into_iter_all()
+            .collect::<Result<Vec<_>, Error>>()?;
+        assert_eq!(lists.len(), 15);
+
+        let lists = api
+            .lists_trending()
+            .limit(5)
+            .into_iter_all()
+            .max_items(7)
+            .collect::<Result<Vec<_>, Error>>()?;
+        assert_eq!(lists.len(), 7);
+
+        pages.iter().for_each(|m| m.assert());
+        Ok(())
+    }
+
+    #[test]
+    fn user_lists() -> Result<(), Error> {
+        let m = mock("GET", "/users/sean/lists", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/user_lists.json")
+            .create();
+
+        let lists =
+            TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None).user_lists("sean")?;
+
+        assert_eq!(lists.len(), 1);
+        assert_eq!(lists[0].name, "Star Wars in machete order");
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_list_create() -> Result<(), Error> {
+        let m = auth_mock("POST", "/users/sean/lists", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(201)
+            .with_body_from_file("mock_data/user_list.json")
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/user_list_create_req.json").unwrap(),
+            ))
+            .create();
+
+        let list = session(&server_url())
+            .user_list_create("sean", "Star Wars in machete order")
+            .description("Next time you want to introduce someone to Star Wars for the first time, watch the films with them in this order: IV, V, II, III, VI.")
+            .privacy(ListPrivacy::Public)
+            .display_numbers(true)
+            .allow_comments(true)
+            .sort_by("rank")
+            .sort_how("asc")
+            .execute()?;
+
+        assert_eq!(list.ids.trakt, Some(55));
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_list_update() -> Result<(), Error> {
+        let m = auth_mock(
+            "PUT",
+            "/users/sean/lists/star-wars-in-machete-order",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/user_list.json")
+        .match_body(Matcher::JsonString(r#"{"privacy":"friends"}"#.to_owned()))
+        .create();
+
+        session(&server_url())
+            .user_list_update("sean", "star-wars-in-machete-order")
+            .privacy(ListPrivacy::Friends)
+            .execute()?;
+
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_list_delete() -> Result<(), Error> {
+        let m = auth_mock(
+            "DELETE",
+            "/users/sean/lists/star-wars-in-machete-order",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(204)
+        .create();
+
+        session(&server_url()).user_list_delete("sean", "star-wars-in-machete-order")?;
+
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_list_items() -> Result<(), Error> {
+        let m = mock(
+            "GET",
+            "/users/sean/lists/star-wars-in-machete-order/items",
+            "CLIENT_ID",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/user_list_items.json")
+        .create();
+
+        let items = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
+            .user_list_items(
+                |list| {
+                    list.user(|user| user.slug("sean"))
+                        .slug("star-wars-in-machete-order")
+                },
+                None,
+            )
+            .execute()?;
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id, Some(101));
+        assert_eq!(items[0].notes, Some("Watch this first".to_owned()));
+        assert_eq!(items[1].item_type, ListItemType::Person);
+        assert_eq!(items[1].person.as_ref().unwrap().name, "Mark Hamill");
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_list_items_type() -> Result<(), Error> {
+        let m = auth_mock(
+            "GET",
+            "/users/sean/lists/55/items/person",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(200)
+        .with_body("[]")
+        .create();
+
+        let items = session(&server_url())
+            .user_list_items(
+                |list| list.user(|user| user.slug("sean")).id(55),
+                Some(ListItemType::Person),
+            )?
+            .execute()?;
+
+        assert!(items.is_empty());
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_list_items_add() -> Result<(), Error> {
+        let m = auth_mock(
+            "POST",
+            "/users/sean/lists/55/items",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(201)
+        .with_body_from_file("mock_data/user_list_items_add.json")
+        .match_body(Matcher::JsonString(
+            fs::read_to_string("mock_data/user_list_items_add_req.json").unwrap(),
+        ))
+        .create();
+
+        let res = session(&server_url())
+            .user_list_items_add(|list| list.user(|user| user.slug("sean")).id(55))
+            .movie(|movie| {
+                movie
+                    .slug("star-wars-episode-iv-a-new-hope-1977")
+                    .notes("Watch this first")
+            })
+            .movie(|movie| movie.imdb("tt0000111"))
+            .person(|person| person.slug("mark-hamill"))
+            .execute()?;
+
+        assert_eq!(
+            res.added,
+            ListItemsNumbers {
+                movies: 1,
+                shows: 0,
+                seasons: 0,
+                episodes: 0,
+                people: 1
+            }
+        );
+        assert_eq!(res.not_found.movies.len(), 1);
+        assert_eq!(res.list.item_count, 2);
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_list_items_remove() -> Result<(), Error> {
+        let m = auth_mock(
+            "POST",
+            "/users/sean/lists/55/items/remove",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(200)
+        .with_body(
+            r#"{"deleted":{"movies":1,"shows":0,"seasons":0,"episodes":0,"people":0},"not_found":{"movies":[],"shows":[],"seasons":[],"episodes":[],"people":[]},"list":{"updated_at":"2022-04-27T21:40:41.000Z","item_count":1}}"#,
+        )
+        .match_body(Matcher::PartialJsonString(
+            r#"{"movies":[{"ids":{"trakt":12}}]}"#.to_owned(),
+        ))
+        .create();
+
+        let res = session(&server_url())
+            .user_list_items_remove(|list| list.user(|user| user.slug("sean")).id(55))
+            .movie(|movie| movie.id(12))
+            .execute()?;
+
+        assert_eq!(res.deleted.movies, 1);
+        assert_eq!(res.list.item_count, 1);
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_list_items_reorder() -> Result<(), Error> {
+        let m = auth_mock(
+            "POST",
+            "/users/sean/lists/55/items/reorder",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/user_list_items_reorder.json")
+        .match_body(Matcher::JsonString(r#"{"rank":[102,101,12]}"#.to_owned()))
+        .create();
+
+        let res = session(&server_url()).user_list_items_reorder(
+            |list| list.user(|user| user.slug("sean")).id(55),
+            vec![102, 101, 12],
+        )?;
+
+        assert_eq!(res.updated, 2);
+        assert_eq!(res.skipped_ids, vec![12]);
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_list_item_notes() -> Result<(), Error> {
+        let m = auth_mock(
+            "PUT",
+            "/users/sean/lists/55/items/101",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(204)
+        .match_body(Matcher::JsonString(
+            r#"{"notes":"Watch this first"}"#.to_owned(),
+        ))
+        .create();
+
+        session(&server_url()).user_list_item_notes(
+            |list| list.user(|user| user.slug("sean")).id(55),
+            101,
+            "Watch this first",
+        )?;
+
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_list_items_without_user() {
+        let api = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None);
+
+        assert!(matches!(
+            api.user_list_items(|list| list.id(55), None).execute(),
+            Err(Error::NoneError)
+        ));
+        assert!(matches!(
+            session(&server_url())
+                .user_list_items_add(|list| list.user(|user| user.slug("sean")))
+                .movie(|movie| movie.id(12))
+                .execute(),
+            Err(Error::NoneError)
+        ));
+    }
+
+    #[test]
+    fn list() -> Result<(), Error> {
+        let m = mock("GET", "/lists/55", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/user_list.json")
+            .create();
+
+        let list = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None).list(55)?;
+
+        assert_eq!(list.ids.slug, Some("star-wars-in-machete-order".to_owned()));
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn list_likes() -> Result<(), Error> {
+        let m = mock("GET", "/lists/55/likes?page=1&limit=10", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/list_likes.json")
+            .create();
+
+        let likes = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
+            .list_likes(55)
+            .page(1)
+            .limit(10)
+            .execute()?;
+
+        assert_eq!(likes.len(), 1);
+        assert_eq!(likes[0].user.username, "sean");
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn list_comments() -> Result<(), Error> {
+        let m = mock("GET", "/lists/55/comments/likes", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/media_comments.json")
+            .create();
+
+        let comments = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
+            .list_comments(55, CommentSort::Likes)
+            .execute()?;
+
+        assert_eq!(comments[0].id, 194915);
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn list_like() -> Result<(), Error> {
+        let like = auth_mock("POST", "/lists/55/like", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(204)
+            .create();
+        let unlike = auth_mock("DELETE", "/lists/55/like", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(204)
+            .create();
+
+        let session = session(&server_url());
+        session.list_like(55)?;
+        session.list_unlike(55)?;
+
+        like.assert();
+        unlike.assert();
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use crate::{
+        asyn::TraktApi,
+        error::Error,
+        models::{Ids, List, ListInfo, ListItemType, ListPrivacy, User},
+        pagination::Pagination,
+        selectors::{SelectIds, SelectMovie, SelectPerson, Selector},
+        tests::{async_session, auth_mock, mock},
+    };
+    use chrono::{offset::TimeZone, Utc};
+    use futures::TryStreamExt;
+    use mockito::{server_url, Matcher};
+    use std::fs;
+
+    #[tokio::test]
+    async fn lists_trending() -> Result<(), Error> {
+        let m = mock("GET", "/lists/trending?page=1&limit=5", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/lists.json")
+            .create();
+
+        let res = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
+            .lists_trending()
+            .page(1)
+            .limit(5)
+            .execute()
+            .await?;
+
+        assert!(
+            res.contains(
+                &ListInfo {
+                    like_count: 89,
+                    comment_count: 0,
+                    list: List {
+                        name: "Movie Selections Based on Subreddits".to_string(),
+                        description: Some("This is a list based on movies found in the following subreddits: r/iwatchedanoldmovier/flicksr/moviesclub".to_string()),
+                        privacy: Some("public".to_string()),
+                        display_numbers: false,
+                        allow_comments: true,
+                        sort_by: "rank".to_string(),
+                        sort_how: "asc".to_string(),
+                        created_at: Utc.ymd(2019, 02, 18).and_hms(16, 37, 54),
+                        updated_at: None,
+                        item_count: 92,
+                        comment_count: 0,
+                        likes: 89,
+                        ids: Ids {
+                            trakt: Some(6319578),
+                            slug: Some("movie-selections-based-on-subreddits".to_string()),
+                            tvdb: None,
+                            imdb: None,
+                            tmdb: None,
+                            tvrage: None
+                        },
+                        user: User {
+                            username: "Giladg".to_string(),
+                            private: false,
+                            name: Some("Gilad & Alex".to_string()),
+                            vip: Some(false),
+                            vip_ep: Some(false),
+                            ids: Ids {
+                                trakt: None,
+                                slug: Some("giladg".to_owned()),
+                                tvdb: None,
+                                imdb: None,
+                                tmdb: None,
+                                tvrage: None
+                            }
+                        }
+                    }
+                }
+            )
+        );
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lists_popular() -> Result<(), Error> {
+        let m = mock("GET", "/lists/popular?page=1&limit=5", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/lists.json")
+            .create();
+
+        let res = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
+            .lists_popular()
+            .page(1)
+            .limit(5)
+            .execute()
+            .await?;
+
+        assert!(
+            res.contains(
+                &ListInfo {
+                    like_count: 89,
+                    comment_count: 0,
+                    list: List {
+                        name: "Movie Selections Based on Subreddits".to_string(),
+                        description: Some("This is a list based on movies found in the following subreddits: r/iwatchedanoldmovier/flicksr/moviesclub".to_string()),
+                        privacy: Some("public".to_string()),
+                        display_numbers: false,
+                        allow_comments: true,
+                        sort_by: "rank".to_string(),
+                        sort_how: "asc".to_string(),
+                        created_at: Utc.ymd(2019, 02, 18).and_hms(16, 37, 54),
+                        updated_at: None,
+                        item_count: 92,
+                        comment_count: 0,
+                        likes: 89,
+                        ids: Ids {
+                            trakt: Some(6319578),
+                            slug: Some("movie-selections-based-on-subreddits".to_string()),
+                            tvdb: None,
+                            imdb: None,
+                            tmdb: None,
+                            tvrage: None
+                        },
+                        user: User {
+                            username: "Giladg".to_string(),
+                            private: false,
+                            name: Some("Gilad & Alex".to_string()),
+                            vip: Some(false),
+                            vip_ep: Some(false),
+                            ids: Ids {
+                                trakt: None,
+                                slug: Some("giladg".to_owned()),
+                                tvdb: None,
+                                imdb: None,
+                                tmdb: None,
+                                tvrage: None
+                            }
+                        }
+                    }
+                }
+            )
+        );
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lists_trending_page() -> Result<(), Error> {
+        let m = mock("GET", "/lists/trending?page=1&limit=5", "CLIENT_ID")
+            .with_status(200)
+            .with_header("X-Pagination-Page", "1")
+            .with_header("X-Pagination-Limit", "5")
+            .with_header("X-Pagination-Page-Count", "2")
+            .with_header("X-Pagination-Item-Count", "8")
+            .with_body_from_file("mock_data/lists.json")
+            .create();
+
+        let page = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
+            .lists_trending()
+            .page(1)
+            .limit(5)
+            .execute_page()
+            .await?;
+
+        assert_eq!(page.page, 1);
+        assert_eq!(page.page_count, 2);
+        assert_eq!(page.item_count, 8);
+        assert!(page.has_next_page());
+        m.assert();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lists_trending_all() -> Result<(), Error> {
+        let pages: Vec<_> = (1..=3)
+            .map(|page| {
+                mock(
+                    "GET",
+                    &format!("/lists/trending?limit=5&page={}", page),
+                    "CLIENT_ID",
+                )
+                .with_status(200)
+                .with_header("X-Pagination-Page", &page.to_string())
+                .with_header("X-Pagination-Limit", "5")
+                .with_header("X-Pagination-Page-Count", "3")
+                .with_header("X-Pagination-Item-Count", "15")
+                .with_body_from_file("mock_data/lists.json")
+                .expect(if page < 3 { 2 } else { 1 })
+                .create()
+            })
+            .collect();
+
+        let url = server_url();
+        let api = TraktApi::with_url(&url, "CLIENT_ID".to_owned(), None);
+
+        let lists: Vec<_> = api
+            .lists_trending()
+            .limit(5)
+            .into_stream_all()
+            .try_collect()
+            .await?;
+        assert_eq!(lists.len(), 15);
+
+        let lists: Vec<_> = api
+            .lists_trending()
+            .limit(5)
+            .into_stream_all()
+            .max_pages(2)
+            .try_collect()
+            .await?;
+        assert_eq!(lists.len(), 10);
+
+        pages.iter().for_each(|m| m.assert());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_list() -> Result<(), Error> {
+        let m = auth_mock(
+            "GET",
+            "/users/sean/lists/star-wars-in-machete-order",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/user_list.json")
+        .create();
+
+        let list = async_session(&server_url())
+            .user_list("sean", "star-wars-in-machete-order")
+            .await?;
+
+        assert_eq!(list.privacy, Some("public".to_owned()));
+        m.assert();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_list_create() -> Result<(), Error> {
+        let m = auth_mock("POST", "/users/sean/lists", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(201)
+            .with_body_from_file("mock_data/user_list.json")
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/user_list_create_req.json").unwrap(),
+            ))
+            .create();
+
+        let list = async_session(&server_url())
+            .user_list_create("sean", "Star Wars in machete order")
+            .description("Next time you want to introduce someone to Star Wars for the first time, watch the films with them in this order: IV, V, II, III, VI.")
+            .privacy(ListPrivacy::Public)
+            .display_numbers(true)
+            .allow_comments(true)
+            .sort_by("rank")
+            .sort_how("asc")
+            .execute()
+            .await?;
+
+        assert_eq!(list.ids.trakt, Some(55));
+        m.assert();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_list_items() -> Result<(), Error> {
+        let m = mock(
+            "GET",
+            "/users/sean/lists/star-wars-in-machete-order/items/movie",
+            "CLIENT_ID",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/user_list_items.json")
+        .create();
+
+        let items = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
+            .user_list_items(
+                |list| {
+                    list.user(|user| user.slug("sean"))
+                        .slug("star-wars-in-machete-order")
+                },
+                Some(ListItemType::Movie),
+            )
+            .execute()
+            .await?;
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id, Some(101));
+        m.assert();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_list_items_add() -> Result<(), Error> {
+        let m = auth_mock(
+            "POST",
+            "/users/sean/lists/55/items",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(201)
+        .with_body_from_file("mock_data/user_list_items_add.json")
+        .match_body(Matcher::JsonString(
+            fs::read_to_string("mock_data/user_list_items_add_req.json").unwrap(),
+        ))
+        .create();
+
+        let res = async_session(&server_url())
+            .user_list_items_add(|list| list.user(|user| user.slug("sean")).id(55))
+            .movie(|movie| {
+                movie
+                    .slug("star-wars-episode-iv-a-new-hope-1977")
+                    .notes("Watch this first")
+            })
+            .movie(|movie| movie.imdb("tt0000111"))
+            .person(|person| person.slug("mark-hamill"))
+            .execute()
+            .await?;
+
+        assert_eq!(res.added.people, 1);
+        m.assert();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_list_items_reorder() -> Result<(), Error> {
+        let m = auth_mock(
+            "POST",
+            "/users/sean/lists/55/items/reorder",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/user_list_items_reorder.json")
+        .match_body(Matcher::JsonString(r#"{"rank":[102,101,12]}"#.to_owned()))
+        .create();
+
+        let res = async_session(&server_url())
+            .user_list_items_reorder(
+                |list| list.user(|user| user.slug("sean")).id(55),
+                vec![102, 101, 12],
+            )
+            .await?;
+
+        assert_eq!(res.skipped_ids, vec![12]);
+        m.assert();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_items() -> Result<(), Error> {
+        let m = mock("GET", "/lists/55/items/person", "CLIENT_ID")
+            .with_status(200)
+            .with_body("[]")
+            .create();
+
+        let items = TraktApi::with_url(&server_url(), "CLIENT_ID".to_owned(), None)
+            .list_items(55, Some(ListItemType::Person))
+            .execute()
+            .await?;
+
+        assert!(items.is_empty());
+        m.assert();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_like() -> Result<(), Error> {
+        let like = auth_mock("POST", "/lists/55/like", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(204)
+            .create();
+        let unlike = auth_mock("DELETE", "/lists/55/like", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(204)
+            .create();
+
+        let session = async_session(&server_url());
+        session.list_like(55).await?;
+        session.list_unlike(55).await?;
+
+        like.assert();
+        unlike.assert();
+        Ok(())
+    }
+}
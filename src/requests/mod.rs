@@ -0,0 +1,113 @@
+pub mod authentication;
+pub mod calendar;
+pub mod checkin;
+pub mod comments;
+pub mod episodes;
+pub mod lists;
+pub mod movies;
+pub mod people;
+pub mod recommendations;
+pub mod scrobble;
+pub mod search;
+pub mod seasons;
+pub mod shows;
+pub mod sync;
+pub mod users;
+
+use crate::{
+    models::{Certifications, CertificationsType, Country, Genre, Language, MediaType, Network},
+    request::ApiRequest,
+    route::Route,
+};
+
+endpoints! {
+    /// Get a Vec of all certifications, including names, slugs, and descriptions. [Trakt API][more]
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::Response] if the response contains an unsuccessful status code
+    ///
+    /// Returns [Error::Connection] if the connection failed
+    ///
+    /// Returns [Error::Deserialize] if the response could not be deserialized
+    ///
+    /// [Error::Response]: error/enum.Error.html#variant.Response
+    /// [Error::Connection]: error/enum.Error.html#variant.Connection
+    /// [Error::Deserialize]: error/enum.Error.html#variant.Deserialize
+    /// [more]: https://trakt.docs.apiary.io/#reference/certifications/list/get-certifications
+    pub fn certifications(&self, ct: CertificationsType) -> Certifications {
+        ApiRequest::get(Route::new("/certifications").segment(ct.to_string()))
+    }
+
+    /// Get a Vec of all countries, including names and codes. [Trakt API][more]
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::Response] if the response contains an unsuccessful status code
+    ///
+    /// Returns [Error::Connection] if the connection failed
+    ///
+    /// Returns [Error::Deserialize] if the response could not be deserialized
+    ///
+    /// [Error::Response]: error/enum.Error.html#variant.Response
+    /// [Error::Connection]: error/enum.Error.html#variant.Connection
+    /// [Error::Deserialize]: error/enum.Error.html#variant.Deserialize
+    /// [more]: https://trakt.docs.apiary.io/#reference/countries/list/get-countries
+    pub fn countries(&self, media_type: MediaType) -> Vec<Country> {
+        ApiRequest::get(Route::new("/countries").segment(media_type.to_string()))
+    }
+
+    /// Get a Vec of all genres, including names and slugs. [Trakt API][more]
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::Response] if the response contains an unsuccessful status code
+    ///
+    /// Returns [Error::Connection] if the connection failed
+    ///
+    /// Returns [Error::Deserialize] if the response could not be deserialized
+    ///
+    /// [Error::Response]: error/enum.Error.html#variant.Response
+    /// [Error::Connection]: error/enum.Error.html#variant.Connection
+    /// [Error::Deserialize]: error/enum.Error.html#variant.Deserialize
+    /// [more]: https://trakt.docs.apiary.io/#reference/genres/get-genres
+    pub fn genres(&self, media_type: MediaType) -> Vec<Genre> {
+        ApiRequest::get(Route::new("/genres").segment(media_type.to_string()))
+    }
+
+    /// Get a Vec of all languages, including names and codes. [Trakt API][more]
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::Response] if the response contains an unsuccessful status code
+    ///
+    /// Returns [Error::Connection] if the connection failed
+    ///
+    /// Returns [Error::Deserialize] if the response could not be deserialized
+    ///
+    /// [Error::Response]: error/enum.Error.html#variant.Response
+    /// [Error::Connection]: error/enum.Error.html#variant.Connection
+    /// [Error::Deserialize]: error/enum.Error.html#variant.Deserialize
+    /// [more]: https://trakt.docs.apiary.io/#reference/genres/list/get-languages
+    pub fn languages(&self, media_type: MediaType) -> Vec<Language> {
+        ApiRequest::get(Route::new("/languages").segment(media_type.to_string()))
+    }
+
+    /// Get a Vec of all TV networks, including the name. [Trakt API][more]
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::Response] if the response contains an unsuccessful status code
+    ///
+    /// Returns [Error::Connection] if the connection failed
+    ///
+    /// Returns [Error::Deserialize] if the response could not be deserialized
+    ///
+    /// [Error::Response]: error/enum.Error.html#variant.Response
+    /// [Error::Connection]: error/enum.Error.html#variant.Connection
+    /// [Error::Deserialize]: error/enum.Error.html#variant.Deserialize
+    /// [more]: https://trakt.docs.apiary.io/#reference/networks/list/get-networks
+    pub fn networks(&self) -> Vec<Network> {
+        ApiRequest::get(Route::new("/networks"))
+    }
+}
@@ -0,0 +1,101 @@
+pub mod movies_request;
+
+pub use crate::requests::movies::movies_request::MoviesRequest;
+
+use crate::{
+    models::{
+        Alias, AnticipatedMovie, Comment, List, ListFactory, MediaStats, Movie, MovieInfo, People,
+        Ratings, TimePeriod, Translation, UpdatedMovie, User, WatchedMovie,
+    },
+    request::ApiRequest,
+    route::Route,
+};
+use std::fmt::Display;
+
+endpoints! {
+    pub fn movies_updates(&self) -> Paginated<UpdatedMovie> {
+        ApiRequest::get(Route::new("/movies/updates"))
+    }
+
+    pub fn movie(&self, id: impl Display) -> Movie {
+        ApiRequest::get(Route::new("/movies").segment(id))
+    }
+
+    pub fn movie_aliases(&self, id: impl Display) -> Vec<Alias> {
+        ApiRequest::get(Route::new("/movies").segment(id).segment("aliases"))
+    }
+
+    pub fn movie_translations(&self, id: impl Display, language: impl Display) -> Vec<Translation> {
+        ApiRequest::get(
+            Route::new("/movies")
+                .segment(id)
+                .segment("translations")
+                .segment(language),
+        )
+    }
+
+    pub fn movie_comments(&self, id: impl Display) -> Paginated<Comment> {
+        ApiRequest::get(Route::new("/movies").segment(id).segment("comments"))
+    }
+
+    pub fn movie_lists(
+        &self,
+        id: impl Display,
+        f: impl FnOnce(ListFactory) -> ListFactory,
+    ) -> Paginated<List> {
+        let list_factory = f(ListFactory::default());
+        ApiRequest::get(
+            Route::new("/movies")
+                .segment(id)
+                .segment("lists")
+                .segment(list_factory.list_filter)
+                .segment(list_factory.sorting),
+        )
+    }
+
+    pub fn movie_people(&self, id: impl Display) -> People {
+        ApiRequest::get(Route::new("/movies").segment(id).segment("people"))
+    }
+
+    pub fn movie_ratings(&self, id: impl Display) -> Ratings {
+        ApiRequest::get(Route::new("/movies").segment(id).segment("ratings"))
+    }
+
+    pub fn movie_related(&self, id: impl Display) -> Paginated<Movie> {
+        ApiRequest::get(Route::new("/movies").segment(id).segment("related"))
+    }
+
+    pub fn movie_stats(&self, id: impl Display) -> MediaStats {
+        ApiRequest::get(Route::new("/movies").segment(id).segment("stats"))
+    }
+
+    pub fn movie_watching(&self, id: impl Display) -> Vec<User> {
+        ApiRequest::get(Route::new("/movies").segment(id).segment("watching"))
+    }
+}
+
+builders! {
+    pub fn movies_trending(&self) -> MoviesRequest<Self, MovieInfo> {
+        MoviesRequest::new(self, Route::new("/movies/trending"))
+    }
+
+    pub fn movies_popular(&self) -> MoviesRequest<Self, Movie> {
+        MoviesRequest::new(self, Route::new("/movies/popular"))
+    }
+
+    pub fn movies_played(&self, period: TimePeriod) -> MoviesRequest<Self, WatchedMovie> {
+        MoviesRequest::new(self, Route::new("/movies/played").segment(period))
+    }
+
+    pub fn movies_watched(&self, period: TimePeriod) -> MoviesRequest<Self, WatchedMovie> {
+        MoviesRequest::new(self, Route::new("/movies/watched").segment(period))
+    }
+
+    pub fn movies_collected(&self, period: TimePeriod) -> MoviesRequest<Self, WatchedMovie> {
+        MoviesRequest::new(self, Route::new("/movies/collected").segment(period))
+    }
+
+    pub fn movies_anticipated(&self) -> MoviesRequest<Self, AnticipatedMovie> {
+        MoviesRequest::new(self, Route::new("/movies/anticipated"))
+    }
+}
@@ -1,53 +1,71 @@
 use crate::{
-    asyn::{Result, TraktApi},
     extended_info::{ExtendedInfoFull, ExtendedInfoNone, WithFull, WithNone},
     filters::Filters,
     models::ShowStatus,
-    pagination::Pagination,
-    Error,
+    pagination::{Page, Pagination},
+    request::{ApiRequest, Client},
+    route::Route,
 };
-use reqwest::{r#async::Request, Method};
 use serde::de::DeserializeOwned;
 use std::{collections::HashMap, marker::PhantomData};
 
-pub struct MoviesRequest<'a, T> {
-    client: &'a TraktApi<'a>,
-    url: String,
+pub struct MoviesRequest<C, T> {
+    client: C,
+    route: Route,
     query: HashMap<String, String>,
     response_type: PhantomData<T>,
 }
 
-impl<'a, T: DeserializeOwned + Send + 'static> MoviesRequest<'a, T> {
-    pub fn new(client: &'a TraktApi, url: String) -> Self {
+impl<C: Client, T: DeserializeOwned + Send + 'static> MoviesRequest<C, T> {
+    pub fn new(client: &C, route: Route) -> Self {
         Self {
-            client,
-            url,
+            client: client.clone(),
+            route,
             query: HashMap::new(),
             response_type: PhantomData,
         }
     }
 
-    pub fn build(&self) -> crate::Result<Request> {
-        let mut req = self
-            .client
-            .builder(Method::GET, format!("/movies/{}", self.url));
+    pub fn build(&self) -> ApiRequest<Vec<T>> {
+        let mut req = ApiRequest::get(self.route.clone());
 
-        if !self.query.is_empty() {
-            req = req.query(&self.query);
+        for (key, value) in &self.query {
+            req = req.query(key, value);
         }
 
-        req.build().map_err(Error::from)
+        req
     }
 
-    pub fn execute(self) -> Result<Vec<T>> {
-        match self.build() {
-            Ok(req) => self.client.execute(req),
-            Err(e) => Box::new(futures::future::err(e)),
-        }
+    pub fn execute(self) -> C::Output<Vec<T>> {
+        self.client.run(self.build())
+    }
+
+    /// Executes the request and returns the items together with the pagination headers
+    pub fn execute_page(self) -> C::Output<Page<T>> {
+        self.client.run_page(self.build())
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T: DeserializeOwned + Send + 'static> MoviesRequest<crate::sync::TraktApi, T> {
+    /// Returns an iterator over the items of all pages, starting at the requested page.
+    /// Pages are fetched lazily.
+    pub fn into_iter_all(self) -> crate::sync::pagination::PaginationIter<T> {
+        crate::sync::pagination::PaginationRequest::new(&self.client, self.build()).into_iter_all()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: DeserializeOwned + Send + 'static> MoviesRequest<crate::asyn::TraktApi, T> {
+    /// Returns a stream of the items of all pages, starting at the requested page.
+    /// Pages are fetched lazily.
+    pub fn into_stream_all(self) -> crate::asyn::pagination::PaginationStream<T> {
+        crate::asyn::pagination::PaginationRequest::new(&self.client, self.build())
+            .into_stream_all()
     }
 }
 
-impl<'a, T: DeserializeOwned + Send + 'static> Pagination for MoviesRequest<'a, T> {
+impl<C, T: DeserializeOwned> Pagination for MoviesRequest<C, T> {
     fn page(mut self, page: u32) -> Self {
         self.query.insert("page".to_owned(), format!("{}", page));
         self
@@ -59,45 +77,41 @@ impl<'a, T: DeserializeOwned + Send + 'static> Pagination for MoviesRequest<'a,
     }
 }
 
-impl<'a, T: WithFull> WithFull for MoviesRequest<'a, T> {
-    type Full = MoviesRequest<'a, T::Full>;
+impl<C, T: WithFull> WithFull for MoviesRequest<C, T> {
+    type Full = MoviesRequest<C, T::Full>;
 }
 
-impl<'a, T: WithNone> WithNone for MoviesRequest<'a, T> {
-    type None = MoviesRequest<'a, T::None>;
+impl<C, T: WithNone> WithNone for MoviesRequest<C, T> {
+    type None = MoviesRequest<C, T::None>;
 }
 
-impl<'a, T: WithFull + DeserializeOwned + Send + 'static> ExtendedInfoFull
-    for MoviesRequest<'a, T>
-{
+impl<C, T: WithFull + DeserializeOwned> ExtendedInfoFull for MoviesRequest<C, T> {
     fn full(mut self) -> Self::Full {
         self.query.insert("extended".to_owned(), "full".to_owned());
 
         Self::Full {
             client: self.client,
-            url: self.url,
+            route: self.route,
             query: self.query,
             response_type: PhantomData,
         }
     }
 }
 
-impl<'a, T: WithNone + DeserializeOwned + Send + 'static> ExtendedInfoNone
-    for MoviesRequest<'a, T>
-{
+impl<C, T: WithNone + DeserializeOwned> ExtendedInfoNone for MoviesRequest<C, T> {
     fn none(mut self) -> Self::None {
         self.query.remove("extended");
 
         Self::None {
             client: self.client,
-            url: self.url,
+            route: self.route,
             query: self.query,
             response_type: PhantomData,
         }
     }
 }
 
-impl<'a, T: DeserializeOwned + Send + 'static> Filters for MoviesRequest<'a, T> {
+impl<C, T: DeserializeOwned> Filters for MoviesRequest<C, T> {
     fn query(mut self, query: &str) -> Self {
         self.query.insert("query".to_owned(), query.to_owned());
         self
@@ -0,0 +1,234 @@
+use crate::{
+    models::{Movie, Show},
+    request::ApiRequest,
+    route::Route,
+};
+use std::fmt::Display;
+
+endpoints! {
+    #[auth(required)]
+    pub fn recommendations_movie(&self) -> Vec<Movie> {
+        ApiRequest::get(Route::new("/recommendations/movies"))
+    }
+
+    #[auth(required)]
+    pub fn recommendations_movie_hide(&self, id: impl Display) -> () {
+        ApiRequest::delete(Route::new("/recommendations/movies").segment(id))
+    }
+
+    #[auth(required)]
+    pub fn recommendations_show(&self) -> Vec<Show> {
+        ApiRequest::get(Route::new("/recommendations/shows"))
+    }
+
+    #[auth(required)]
+    pub fn recommendations_show_hide(&self, id: impl Display) -> () {
+        ApiRequest::delete(Route::new("/recommendations/shows").segment(id))
+    }
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod sync_tests {
+    use crate::{
+        error::Error,
+        models::{Ids, Movie, Show},
+        tests::{auth_mock, session},
+    };
+    use mockito::server_url;
+
+    #[test]
+    fn recommendations_movie() -> Result<(), Error> {
+        let m = auth_mock(
+            "GET",
+            "/recommendations/movies",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/movies.json")
+        .create();
+
+        let res = session(&server_url()).recommendations_movie().map(|res| {
+            assert!(res.contains(&Movie {
+                title: "The Revenant".to_string(),
+                year: Some(2015),
+                ids: Ids {
+                    trakt: Some(179334),
+                    slug: Some("the-revenant-2015".to_string()),
+                    tvdb: None,
+                    imdb: Some("tt1663202".to_string()),
+                    tmdb: Some(281957),
+                    tvrage: None
+                }
+            }))
+        });
+
+        m.assert();
+        res
+    }
+
+    #[test]
+    fn recommendations_movie_hide() -> Result<(), Error> {
+        let m = auth_mock(
+            "DELETE",
+            "/recommendations/movies/the-revenant-2015",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(204)
+        .create();
+
+        let res = session(&server_url()).recommendations_movie_hide("the-revenant-2015");
+
+        m.assert();
+        res
+    }
+
+    #[test]
+    fn recommendations_show() -> Result<(), Error> {
+        let m = auth_mock("GET", "/recommendations/shows", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(200)
+            .with_body_from_file("mock_data/shows.json")
+            .create();
+
+        let res = session(&server_url()).recommendations_show().map(|res| {
+            assert!(res.contains(&Show {
+                title: "My Hero Academia".to_string(),
+                year: Some(2016),
+                ids: Ids {
+                    trakt: Some(104311),
+                    slug: Some("my-hero-academia".to_string()),
+                    tvdb: Some(305074),
+                    imdb: Some("tt5626028".to_string()),
+                    tmdb: Some(65930),
+                    tvrage: None
+                }
+            }))
+        });
+
+        m.assert();
+        res
+    }
+
+    #[test]
+    fn recommendations_show_hide() -> Result<(), Error> {
+        let m = auth_mock(
+            "DELETE",
+            "/recommendations/shows/my-hero-academia",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(204)
+        .create();
+
+        let res = session(&server_url()).recommendations_show_hide("my-hero-academia");
+
+        m.assert();
+        res
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use crate::{
+        error::Error,
+        models::{Ids, Movie, Show},
+        tests::{async_session, auth_mock},
+    };
+    use mockito::server_url;
+
+    #[tokio::test]
+    async fn recommendations_movie() -> Result<(), Error> {
+        let m = auth_mock(
+            "GET",
+            "/recommendations/movies",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/movies.json")
+        .create();
+
+        let res = async_session(&server_url()).recommendations_movie().await?;
+
+        assert!(res.contains(&Movie {
+            title: "The Revenant".to_string(),
+            year: Some(2015),
+            ids: Ids {
+                trakt: Some(179334),
+                slug: Some("the-revenant-2015".to_string()),
+                tvdb: None,
+                imdb: Some("tt1663202".to_string()),
+                tmdb: Some(281957),
+                tvrage: None
+            }
+        }));
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn recommendations_movie_hide() -> Result<(), Error> {
+        let m = auth_mock(
+            "DELETE",
+            "/recommendations/movies/the-revenant-2015",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(204)
+        .create();
+
+        async_session(&server_url())
+            .recommendations_movie_hide("the-revenant-2015")
+            .await?;
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn recommendations_show() -> Result<(), Error> {
+        let m = auth_mock("GET", "/recommendations/shows", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(200)
+            .with_body_from_file("mock_data/shows.json")
+            .create();
+
+        let res = async_session(&server_url()).recommendations_show().await?;
+
+        assert!(res.contains(&Show {
+            title: "My Hero Academia".to_string(),
+            year: Some(2016),
+            ids: Ids {
+                trakt: Some(104311),
+                slug: Some("my-hero-academia".to_string()),
+                tvdb: Some(305074),
+                imdb: Some("tt5626028".to_string()),
+                tmdb: Some(65930),
+                tvrage: None
+            }
+        }));
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn recommendations_show_hide() -> Result<(), Error> {
+        let m = auth_mock(
+            "DELETE",
+            "/recommendations/shows/my-hero-academia",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(204)
+        .create();
+
+        async_session(&server_url())
+            .recommendations_show_hide("my-hero-academia")
+            .await?;
+        m.assert();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,378 @@
+use crate::{
+    models::{Episode, Movie, Show},
+    request::{ApiRequest, Client},
+    requests::checkin::CheckinSharing,
+    route::Route,
+    selectors::{SelectEpisode, SelectMovie, SelectShow},
+};
+use chrono::NaiveDate;
+use serde_json::{Map, Value};
+
+/// A struct for starting, pausing or stopping a scrobble. [More]
+///
+/// Stopping a scrobble of an item which was scrobbled in the last hour returns a 409
+/// [ApiError]. Its [watching_conflict] tells when the item was watched.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use trakt::{
+///     selectors::{SelectIds, SelectMovie},
+///     TraktApi,
+/// };
+///
+/// fn main() {
+///     let api = TraktApi::new(
+///         "...".to_owned(),
+///         Some("...".to_owned()),
+///     );
+///
+///     let token = api.oauth_get_token("CODE", "urn:ietf:wg:oauth:2.0:oob").unwrap();
+///     let session = api.session(token, "urn:ietf:wg:oauth:2.0:oob");
+///
+///     dbg!(session
+///         .scrobble_start(1.25)
+///         .movie(|m| m.slug("warcraft-2016"))
+///         .execute()
+///         .unwrap());
+/// }
+/// ```
+///
+/// [More]: https://trakt.docs.apiary.io/#reference/scrobble
+/// [ApiError]: ../../error/struct.ApiError.html
+/// [watching_conflict]: ../../error/struct.ApiError.html#method.watching_conflict
+#[derive(Debug, PartialEq)]
+pub struct Scrobble<C> {
+    pub client: C,
+    pub route: Route,
+    pub body: Map<String, Value>,
+}
+
+impl<C: Client> Scrobble<C> {
+    fn new(client: &C, route: Route, progress: f64) -> Self {
+        let mut body = Map::new();
+        body.insert(
+            "progress".to_owned(),
+            serde_json::to_value(progress).unwrap(),
+        );
+
+        Self {
+            client: client.clone(),
+            route,
+            body,
+        }
+    }
+
+    /// Set the app version in a scrobble
+    pub fn app_version(mut self, app_version: &str) -> Self {
+        self.body.insert(
+            "app_version".to_owned(),
+            Value::String(app_version.to_owned()),
+        );
+        self
+    }
+
+    /// Set the app build date in a scrobble
+    ///
+    /// Panics if app_date can't be serialized
+    pub fn app_date(mut self, app_date: NaiveDate) -> Self {
+        self.body.insert(
+            "app_date".to_owned(),
+            serde_json::to_value(app_date).unwrap(),
+        );
+        self
+    }
+
+    /// Executes the scrobble
+    ///
+    /// Panics if self.body can't be serialized
+    pub fn execute(self) -> C::Output<ScrobbleResponse> {
+        self.client.run(ApiRequest::post(
+            self.route,
+            serde_json::to_string(&self.body).unwrap(),
+        ))
+    }
+}
+
+/// Select the movie of a scrobble
+impl<C> SelectMovie for Scrobble<C> {
+    fn movie_value(mut self, movie: Value) -> Self {
+        self.body.insert("movie".to_owned(), movie);
+        self
+    }
+}
+
+/// Select the episode of a scrobble
+impl<C> SelectEpisode for Scrobble<C> {
+    fn episode_value(mut self, episode: Value) -> Self {
+        self.body.insert("episode".to_owned(), episode);
+        self
+    }
+}
+
+/// Select the show of a scrobble
+impl<C> SelectShow for Scrobble<C> {
+    fn show_value(mut self, show: Value) -> Self {
+        self.body.insert("show".to_owned(), show);
+        self
+    }
+}
+
+/// What trakt did with a scrobble
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ScrobbleAction {
+    /// The item is being watched
+    Start,
+    /// The item is paused, or it was stopped before 80% progress
+    Pause,
+    /// The item was added to the watched history
+    Scrobble,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ScrobbleResponse {
+    pub id: u64,
+    pub action: ScrobbleAction,
+    pub progress: f64,
+    pub sharing: CheckinSharing,
+    pub movie: Option<Movie>,
+    pub episode: Option<Episode>,
+    pub show: Option<Show>,
+}
+
+builders! {
+    #[auth(required)]
+    /// Start watching an item at the given progress in percent
+    pub fn scrobble_start(&self, progress: f64) -> Scrobble<Self> {
+        Scrobble::new(self, Route::new("/scrobble/start"), progress)
+    }
+
+    #[auth(required)]
+    /// Pause watching an item at the given progress in percent
+    pub fn scrobble_pause(&self, progress: f64) -> Scrobble<Self> {
+        Scrobble::new(self, Route::new("/scrobble/pause"), progress)
+    }
+
+    #[auth(required)]
+    /// Stop watching an item at the given progress in percent
+    ///
+    /// The item is added to the watched history if the progress is at least 80%
+    pub fn scrobble_stop(&self, progress: f64) -> Scrobble<Self> {
+        Scrobble::new(self, Route::new("/scrobble/stop"), progress)
+    }
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod sync_tests {
+    use crate::{
+        error::Error,
+        models::{Ids, Movie},
+        requests::{
+            checkin::CheckinSharing,
+            scrobble::{Scrobble, ScrobbleAction, ScrobbleResponse},
+        },
+        route::Route,
+        selectors::{SelectIds, SelectMovie},
+        tests::{auth_mock, session},
+        TraktApi,
+    };
+    use chrono::{DateTime, Utc};
+    use mockito::{server_url, Matcher};
+    use serde_json::{Map, Value};
+    use std::fs;
+
+    #[test]
+    fn scrobble_struct() {
+        let client = TraktApi::staging("_".to_owned(), Some("_".to_owned()));
+        let s = Scrobble::new(&client, Route::new("/scrobble/pause"), 50.0)
+            .movie(|movie| movie.slug("warcraft-2016"))
+            .app_version("0.1.0");
+
+        let mut body = Map::new();
+
+        body.insert("progress".to_owned(), json!(50.0));
+        body.insert(
+            "movie".to_owned(),
+            json!({
+                "ids": {
+                    "slug": "warcraft-2016"
+                }
+            }),
+        );
+        body.insert("app_version".to_owned(), Value::String("0.1.0".to_owned()));
+
+        assert_eq!(
+            s,
+            Scrobble {
+                client,
+                route: Route::new("/scrobble/pause"),
+                body
+            }
+        );
+    }
+
+    #[test]
+    fn scrobble_start() {
+        let m = auth_mock("POST", "/scrobble/start", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(201)
+            .with_body_from_file("mock_data/scrobble.json")
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/scrobble_req.json").unwrap(),
+            ))
+            .create();
+
+        let res = session(&server_url())
+            .scrobble_start(1.25)
+            .movie(|movie| movie.slug("guardians-of-the-galaxy-2014"))
+            .app_version("0.1.0")
+            .execute()
+            .unwrap();
+
+        assert_eq!(
+            res,
+            ScrobbleResponse {
+                id: 0,
+                action: ScrobbleAction::Start,
+                progress: 1.25,
+                sharing: CheckinSharing {
+                    twitter: true,
+                    tumblr: false,
+                    facebook: false
+                },
+                movie: Some(Movie {
+                    title: "Guardians of the Galaxy".to_string(),
+                    year: Some(2014),
+                    ids: Ids {
+                        trakt: Some(28),
+                        slug: Some("guardians-of-the-galaxy-2014".to_owned()),
+                        tvdb: None,
+                        imdb: Some("tt2015381".to_owned()),
+                        tmdb: Some(118340),
+                        tvrage: None
+                    }
+                }),
+                episode: None,
+                show: None
+            }
+        );
+        m.assert();
+    }
+
+    #[test]
+    fn scrobble_stop_conflict() {
+        let m = auth_mock("POST", "/scrobble/stop", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(409)
+            .with_body(
+                r#"{"watched_at":"2014-10-15T22:21:29.000Z","expires_at":"2014-10-15T23:21:29.000Z"}"#,
+            )
+            .create();
+
+        let e = session(&server_url())
+            .scrobble_stop(99.9)
+            .movie(|movie| movie.slug("guardians-of-the-galaxy-2014"))
+            .execute()
+            .unwrap_err();
+
+        match e {
+            Error::Response(e) => assert_eq!(
+                e.watching_conflict().unwrap().watched_at,
+                "2014-10-15T22:21:29.000Z".parse::<DateTime<Utc>>().unwrap()
+            ),
+            e => panic!("{:?}", e),
+        }
+        m.assert();
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use crate::{
+        error::Error,
+        models::{Ids, Movie},
+        requests::{
+            checkin::CheckinSharing,
+            scrobble::{ScrobbleAction, ScrobbleResponse},
+        },
+        selectors::{SelectIds, SelectMovie},
+        tests::{async_session, auth_mock},
+    };
+    use chrono::{DateTime, Utc};
+    use mockito::{server_url, Matcher};
+    use std::fs;
+
+    #[tokio::test]
+    async fn scrobble_start() -> Result<(), Error> {
+        let m = auth_mock("POST", "/scrobble/start", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(201)
+            .with_body_from_file("mock_data/scrobble.json")
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/scrobble_req.json").unwrap(),
+            ))
+            .create();
+
+        let res = async_session(&server_url())
+            .scrobble_start(1.25)
+            .movie(|movie| movie.slug("guardians-of-the-galaxy-2014"))
+            .app_version("0.1.0")
+            .execute()
+            .await?;
+
+        assert_eq!(
+            res,
+            ScrobbleResponse {
+                id: 0,
+                action: ScrobbleAction::Start,
+                progress: 1.25,
+                sharing: CheckinSharing {
+                    twitter: true,
+                    tumblr: false,
+                    facebook: false
+                },
+                movie: Some(Movie {
+                    title: "Guardians of the Galaxy".to_string(),
+                    year: Some(2014),
+                    ids: Ids {
+                        trakt: Some(28),
+                        slug: Some("guardians-of-the-galaxy-2014".to_owned()),
+                        tvdb: None,
+                        imdb: Some("tt2015381".to_owned()),
+                        tmdb: Some(118340),
+                        tvrage: None
+                    }
+                }),
+                episode: None,
+                show: None
+            }
+        );
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn scrobble_stop_conflict() {
+        let m = auth_mock("POST", "/scrobble/stop", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(409)
+            .with_body(
+                r#"{"watched_at":"2014-10-15T22:21:29.000Z","expires_at":"2014-10-15T23:21:29.000Z"}"#,
+            )
+            .create();
+
+        let e = async_session(&server_url())
+            .scrobble_stop(99.9)
+            .movie(|movie| movie.slug("guardians-of-the-galaxy-2014"))
+            .execute()
+            .await
+            .unwrap_err();
+
+        match e {
+            Error::Response(e) => assert_eq!(
+                e.watching_conflict().unwrap().watched_at,
+                "2014-10-15T22:21:29.000Z".parse::<DateTime<Utc>>().unwrap()
+            ),
+            e => panic!("{:?}", e),
+        }
+        m.assert();
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{
+    models::{IdType, SearchItemType, SearchResult, SearchType},
+    request::ApiRequest,
+    route::Route,
+};
+use std::fmt::Display;
+
+endpoints! {
+    pub fn search(&self, item_type: SearchType, query: &str) -> Paginated<SearchResult> {
+        ApiRequest::get(Route::new("/search").segment(item_type)).query("query", query)
+    }
+
+    pub fn id_lookup(
+        &self,
+        id_type: IdType,
+        id: impl Display,
+        item_type: Option<SearchItemType>,
+    ) -> Vec<SearchResult> {
+        ApiRequest::get(Route::new("/search").segment(id_type).segment(id))
+            .optional_query("type", item_type)
+    }
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod sync_tests {
+    use crate::{
+        error::Error,
+        models::{IdType, Ids, Movie, SearchItemType, SearchResult, SearchType},
+        pagination::Pagination,
+        tests::mock,
+        TraktApi,
+    };
+
+    #[test]
+    fn search() -> Result<(), Error> {
+        let m = mock(
+            "GET",
+            "/search/movie,show?query=tron&page=1&limit=5",
+            "CLIENT_ID",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/search.json")
+        .create();
+
+        let res = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .search(SearchType::new(true, true, false, false, false), "tron")
+            .page(1)
+            .limit(5)
+            .execute()
+            .map(|res| {
+                assert!(res.contains(&SearchResult {
+                    item_type: SearchItemType::Movie,
+                    score: 1000.0,
+                    movie: Some(Movie {
+                        title: "Tron".to_string(),
+                        year: Some(1982),
+                        ids: Ids {
+                            trakt: Some(66),
+                            slug: Some("tron-1982".to_string()),
+                            tvdb: None,
+                            imdb: Some("tt0084827".to_string()),
+                            tmdb: Some(97),
+                            tvrage: None
+                        }
+                    }),
+                    show: None,
+                    episode: None,
+                    person: None,
+                    list: None
+                }))
+            });
+
+        m.assert();
+        res
+    }
+
+    #[test]
+    fn id_lookup() -> Result<(), Error> {
+        let m = mock("GET", "/search/imdb/tt0084827?type=movie", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/id_lookup.json")
+            .create();
+
+        let res = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .id_lookup(IdType::IMDb, "tt0084827", Some(SearchItemType::Movie))
+            .map(|res| {
+                assert_eq!(
+                    res,
+                    vec![SearchResult {
+                        item_type: SearchItemType::Movie,
+                        score: 1000.0,
+                        movie: Some(Movie {
+                            title: "Tron".to_string(),
+                            year: Some(1982),
+                            ids: Ids {
+                                trakt: Some(66),
+                                slug: Some("tron-1982".to_owned()),
+                                tvdb: None,
+                                imdb: Some("tt0084827".to_owned()),
+                                tmdb: Some(97),
+                                tvrage: None
+                            }
+                        }),
+                        show: None,
+                        episode: None,
+                        person: None,
+                        list: None
+                    }]
+                )
+            });
+
+        m.assert();
+        res
+    }
+
+    #[test]
+    fn id_lookup_without_type() -> Result<(), Error> {
+        let m = mock("GET", "/search/imdb/tt0084827", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/id_lookup.json")
+            .create();
+
+        let res = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .id_lookup(IdType::IMDb, "tt0084827", None)?;
+
+        assert_eq!(res.len(), 1);
+        m.assert();
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use crate::{
+        asyn::TraktApi,
+        error::Error,
+        models::{IdType, Ids, Movie, SearchItemType, SearchResult, SearchType},
+        pagination::Pagination,
+        tests::mock,
+    };
+
+    #[tokio::test]
+    async fn search() -> Result<(), Error> {
+        let m = mock(
+            "GET",
+            "/search/movie,show?query=tron&page=1&limit=5",
+            "CLIENT_ID",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/search.json")
+        .create();
+
+        let res = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .search(SearchType::new(true, true, false, false, false), "tron")
+            .page(1)
+            .limit(5)
+            .execute()
+            .await?;
+
+        assert!(res.contains(&SearchResult {
+            item_type: SearchItemType::Movie,
+            score: 1000.0,
+            movie: Some(Movie {
+                title: "Tron".to_string(),
+                year: Some(1982),
+                ids: Ids {
+                    trakt: Some(66),
+                    slug: Some("tron-1982".to_string()),
+                    tvdb: None,
+                    imdb: Some("tt0084827".to_string()),
+                    tmdb: Some(97),
+                    tvrage: None
+                }
+            }),
+            show: None,
+            episode: None,
+            person: None,
+            list: None
+        }));
+        m.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn id_lookup() -> Result<(), Error> {
+        let m = mock("GET", "/search/imdb/tt0084827?type=movie", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/id_lookup.json")
+            .create();
+
+        let res = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .id_lookup(IdType::IMDb, "tt0084827", Some(SearchItemType::Movie))
+            .await?;
+
+        assert_eq!(
+            res,
+            vec![SearchResult {
+                item_type: SearchItemType::Movie,
+                score: 1000.0,
+                movie: Some(Movie {
+                    title: "Tron".to_string(),
+                    year: Some(1982),
+                    ids: Ids {
+                        trakt: Some(66),
+                        slug: Some("tron-1982".to_owned()),
+                        tvdb: None,
+                        imdb: Some("tt0084827".to_owned()),
+                        tmdb: Some(97),
+                        tvrage: None
+                    }
+                }),
+                show: None,
+                episode: None,
+                person: None,
+                list: None
+            }]
+        );
+        m.assert();
+
+        Ok(())
+    }
+}
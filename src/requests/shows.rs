@@ -0,0 +1,124 @@
+use crate::{
+    models::{
+        Alias, AnticipatedShow, CollectionProgress, Comment, Episode, List, ListFactory,
+        MediaStats, People, Ratings, Show, ShowInfo, TimePeriod, Translation, UpdatedShow, User,
+        WatchedProgress, WatchedShow,
+    },
+    request::ApiRequest,
+    route::Route,
+};
+use std::fmt::Display;
+
+endpoints! {
+    pub fn shows_trending(&self) -> Paginated<ShowInfo> {
+        ApiRequest::get(Route::new("/shows/trending"))
+    }
+
+    pub fn shows_popular(&self) -> Paginated<Show> {
+        ApiRequest::get(Route::new("/shows/popular"))
+    }
+
+    pub fn shows_played(&self, period: TimePeriod) -> Paginated<WatchedShow> {
+        ApiRequest::get(Route::new("/shows/played").segment(period))
+    }
+
+    pub fn shows_watched(&self, period: TimePeriod) -> Paginated<WatchedShow> {
+        ApiRequest::get(Route::new("/shows/watched").segment(period))
+    }
+
+    pub fn shows_collected(&self, period: TimePeriod) -> Paginated<WatchedShow> {
+        ApiRequest::get(Route::new("/shows/collected").segment(period))
+    }
+
+    pub fn shows_anticipated(&self) -> Paginated<AnticipatedShow> {
+        ApiRequest::get(Route::new("/shows/anticipated"))
+    }
+
+    pub fn shows_updates(&self) -> Paginated<UpdatedShow> {
+        ApiRequest::get(Route::new("/shows/updates"))
+    }
+
+    pub fn show(&self, id: impl Display) -> Show {
+        ApiRequest::get(Route::new("/shows").segment(id))
+    }
+
+    pub fn show_aliases(&self, id: impl Display) -> Vec<Alias> {
+        ApiRequest::get(Route::new("/shows").segment(id).segment("aliases"))
+    }
+
+    pub fn show_translations(&self, id: impl Display, language: impl Display) -> Vec<Translation> {
+        ApiRequest::get(
+            Route::new("/shows")
+                .segment(id)
+                .segment("translations")
+                .segment(language),
+        )
+    }
+
+    pub fn show_comments(&self, id: impl Display) -> Paginated<Comment> {
+        ApiRequest::get(Route::new("/shows").segment(id).segment("comments"))
+    }
+
+    pub fn show_lists(
+        &self,
+        id: impl Display,
+        f: impl FnOnce(ListFactory) -> ListFactory,
+    ) -> Paginated<List> {
+        let list_factory = f(ListFactory::default());
+        ApiRequest::get(
+            Route::new("/shows")
+                .segment(id)
+                .segment("lists")
+                .segment(list_factory.list_filter)
+                .segment(list_factory.sorting),
+        )
+    }
+
+    #[auth(required)]
+    pub fn show_progress_collection(&self, id: impl Display) -> CollectionProgress {
+        ApiRequest::get(
+            Route::new("/shows")
+                .segment(id)
+                .segment("progress")
+                .segment("collection"),
+        )
+    }
+
+    #[auth(required)]
+    pub fn show_progress_watched(&self, id: impl Display) -> WatchedProgress {
+        ApiRequest::get(
+            Route::new("/shows")
+                .segment(id)
+                .segment("progress")
+                .segment("watched"),
+        )
+    }
+
+    pub fn show_people(&self, id: impl Display) -> People {
+        ApiRequest::get(Route::new("/shows").segment(id).segment("people"))
+    }
+
+    pub fn show_ratings(&self, id: impl Display) -> Ratings {
+        ApiRequest::get(Route::new("/shows").segment(id).segment("ratings"))
+    }
+
+    pub fn show_related(&self, id: impl Display) -> Paginated<Show> {
+        ApiRequest::get(Route::new("/shows").segment(id).segment("related"))
+    }
+
+    pub fn show_stats(&self, id: impl Display) -> MediaStats {
+        ApiRequest::get(Route::new("/shows").segment(id).segment("stats"))
+    }
+
+    pub fn show_watching(&self, id: impl Display) -> Vec<User> {
+        ApiRequest::get(Route::new("/shows").segment(id).segment("watching"))
+    }
+
+    pub fn show_next_episode(&self, id: impl Display) -> Option<Episode> {
+        ApiRequest::get(Route::new("/shows").segment(id).segment("next_episode"))
+    }
+
+    pub fn show_last_episode(&self, id: impl Display) -> Option<Episode> {
+        ApiRequest::get(Route::new("/shows").segment(id).segment("last_episode"))
+    }
+}
@@ -0,0 +1,156 @@
+pub mod sync_request;
+
+use crate::{
+    models::{
+        AllItemType, CollectionMovie, CollectionShow, FullCollectionMovie, FullCollectionShow,
+        FullHistoryItem, FullListItem, FullWatchedEntry, HistoryItem, ItemType, LastActivities,
+        ListItem, MediaType, Playback, Rating, SyncAddResponse, SyncRemoveResponse, WatchableType,
+        WatchedEntry,
+    },
+    request::ApiRequest,
+    requests::sync::sync_request::SyncRequest,
+    route::Route,
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+
+endpoints! {
+    #[auth(required)]
+    pub fn sync_last_activities(&self) -> LastActivities {
+        ApiRequest::get(Route::new("/sync/last_activities"))
+    }
+
+    #[auth(required)]
+    pub fn sync_playback(&self, item_type: WatchableType) -> Vec<Playback> {
+        ApiRequest::get(Route::new("/sync/playback").segment(item_type))
+    }
+
+    #[auth(required)]
+    pub fn sync_playback_delete(&self, playback_id: u64) -> () {
+        ApiRequest::delete(Route::new("/sync/playback").segment(playback_id))
+    }
+
+    #[auth(required)]
+    pub fn sync_collection_movie(&self) -> Vec<CollectionMovie> {
+        ApiRequest::get(Route::new("/sync/collection/movies"))
+    }
+
+    #[auth(required)]
+    pub fn sync_collection_movie_full(&self) -> Vec<FullCollectionMovie> {
+        ApiRequest::get(Route::new("/sync/collection/movies")).query("extended", "full")
+    }
+
+    #[auth(required)]
+    pub fn sync_collection_show(&self) -> Vec<CollectionShow> {
+        ApiRequest::get(Route::new("/sync/collection/shows"))
+    }
+
+    #[auth(required)]
+    pub fn sync_collection_show_full(&self) -> Vec<FullCollectionShow> {
+        ApiRequest::get(Route::new("/sync/collection/shows")).query("extended", "full")
+    }
+
+    #[auth(required)]
+    pub fn sync_watched(&self, item_type: MediaType) -> Vec<WatchedEntry> {
+        ApiRequest::get(Route::new("/sync/watched").segment(item_type))
+    }
+
+    #[auth(required)]
+    pub fn sync_watched_full(&self, item_type: MediaType) -> Vec<FullWatchedEntry> {
+        ApiRequest::get(Route::new("/sync/watched").segment(item_type)).query("extended", "full")
+    }
+
+    #[auth(required)]
+    pub fn sync_history(
+        &self,
+        item_type: ItemType,
+        start_at: DateTime<Utc>,
+        end_at: DateTime<Utc>,
+    ) -> Paginated<HistoryItem> {
+        ApiRequest::get(Route::new("/sync/history").segment(item_type))
+            .query(
+                "start_at",
+                start_at.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            )
+            .query(
+                "end_at",
+                end_at.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            )
+    }
+
+    #[auth(required)]
+    pub fn sync_history_full(
+        &self,
+        item_type: ItemType,
+        start_at: DateTime<Utc>,
+        end_at: DateTime<Utc>,
+    ) -> Paginated<FullHistoryItem> {
+        ApiRequest::get(Route::new("/sync/history").segment(item_type))
+            .query("extended", "full")
+            .query(
+                "start_at",
+                start_at.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            )
+            .query(
+                "end_at",
+                end_at.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            )
+    }
+
+    #[auth(required)]
+    pub fn sync_ratings(&self, item_type: AllItemType) -> Vec<Rating> {
+        ApiRequest::get(Route::new("/sync/ratings").segment(item_type))
+    }
+
+    #[auth(required)]
+    pub fn sync_watchlist(&self, item_type: Option<ItemType>) -> Vec<ListItem> {
+        ApiRequest::get(Route::new("/sync/watchlist").optional_segment(item_type))
+    }
+
+    #[auth(required)]
+    pub fn sync_watchlist_full(&self, item_type: Option<ItemType>) -> Vec<FullListItem> {
+        ApiRequest::get(Route::new("/sync/watchlist").optional_segment(item_type))
+            .query("extended", "full")
+    }
+}
+
+builders! {
+    #[auth(required)]
+    pub fn sync_collection_add(&self) -> SyncRequest<Self, SyncAddResponse> {
+        SyncRequest::new(Route::new("/sync/collection"), self)
+    }
+
+    #[auth(required)]
+    pub fn sync_collection_remove(&self) -> SyncRequest<Self, SyncRemoveResponse> {
+        SyncRequest::new(Route::new("/sync/collection/remove"), self)
+    }
+
+    #[auth(required)]
+    pub fn sync_history_add(&self) -> SyncRequest<Self, SyncAddResponse> {
+        SyncRequest::new(Route::new("/sync/history"), self)
+    }
+
+    #[auth(required)]
+    pub fn sync_history_remove(&self) -> SyncRequest<Self, SyncRemoveResponse> {
+        SyncRequest::new(Route::new("/sync/history/remove"), self)
+    }
+
+    #[auth(required)]
+    pub fn sync_ratings_add(&self) -> SyncRequest<Self, SyncAddResponse> {
+        SyncRequest::new(Route::new("/sync/ratings"), self)
+    }
+
+    #[auth(required)]
+    pub fn sync_ratings_remove(&self) -> SyncRequest<Self, SyncRemoveResponse> {
+        SyncRequest::new(Route::new("/sync/ratings/remove"), self)
+    }
+
+    #[auth(required)]
+    pub fn sync_watchlist_add(&self) -> SyncRequest<Self, SyncAddResponse> {
+        SyncRequest::new(Route::new("/sync/watchlist"), self)
+    }
+
+    #[auth(required)]
+    pub fn sync_watchlist_remove(&self) -> SyncRequest<Self, SyncRemoveResponse> {
+        SyncRequest::new(Route::new("/sync/watchlist/remove"), self)
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
+    request::{ApiRequest, Client},
+    route::Route,
     selectors::{SelectEpisode, SelectMovie, SelectSeason, SelectShow},
-    sync::Result,
-    TraktApi,
 };
 use serde::de::DeserializeOwned;
 use serde_json::{Map, Value};
@@ -20,49 +20,50 @@ use std::marker::PhantomData;
 /// fn main() {
 ///     let api = TraktApi::new(
 ///         "...".to_owned(),
-///         None,
+///         Some("...".to_owned()),
 ///     );
 ///
-///     let access_token = "";
+///     let token = api.oauth_get_token("CODE", "urn:ietf:wg:oauth:2.0:oob").unwrap();
+///     let session = api.session(token, "urn:ietf:wg:oauth:2.0:oob");
 ///
-///     dbg!(api
+///     dbg!(session
 ///         .sync_ratings_add()
 ///         .movie(|m| m
 ///             .slug("warcraft-2016")
 ///             .rating(3)
 ///             .rated_at(Utc.ymd(2010, 10, 10).and_hms(10, 10, 10)))
-///         .execute(access_token)
+///         .execute()
 ///         .unwrap());
 /// }
 /// ```
 ///
-/// **Note**: [TraktApi.sync_ratings_add()] returns SyncRequest<SyncAddResponse>
+/// **Note**: [Session.sync_ratings_add()] returns SyncRequest<Session, SyncAddResponse>
 ///
-/// [TraktApi.sync_ratings_add()]: ../../struct.TraktApi.html#method.sync_ratings_add
-pub struct SyncRequest<'a, R: DeserializeOwned> {
+/// [Session.sync_ratings_add()]: ../../../struct.Session.html#method.sync_ratings_add
+pub struct SyncRequest<C, R: DeserializeOwned> {
     movies: Vec<Value>,
     shows: Vec<Value>,
     seasons: Vec<Value>,
     episodes: Vec<Value>,
-    url: String,
-    client: &'a TraktApi<'a>,
+    route: Route,
+    client: C,
     response_type: PhantomData<R>,
 }
 
-impl<'a, R: DeserializeOwned> SyncRequest<'a, R> {
-    pub fn new(url: String, client: &'a TraktApi) -> Self {
+impl<C: Client, R: DeserializeOwned + Send + 'static> SyncRequest<C, R> {
+    pub fn new(route: Route, client: &C) -> Self {
         Self {
             movies: Vec::new(),
             shows: Vec::new(),
             seasons: Vec::new(),
             episodes: Vec::new(),
-            url,
-            client,
+            route,
+            client: client.clone(),
             response_type: PhantomData,
         }
     }
 
-    pub fn execute(self, access_token: &str) -> Result<R> {
+    pub fn execute(self) -> C::Output<R> {
         let mut obj = Map::new();
         obj.insert("movies".to_owned(), Value::Array(self.movies));
         obj.insert("shows".to_owned(), Value::Array(self.shows));
@@ -71,32 +72,32 @@ impl<'a, R: DeserializeOwned> SyncRequest<'a, R> {
         let body = Value::Object(obj);
 
         self.client
-            .auth_post(self.url, serde_json::to_string(&body)?, access_token)
+            .run(ApiRequest::post(self.route, body.to_string()))
     }
 }
 
-impl<'a, R: DeserializeOwned> SelectMovie for SyncRequest<'a, R> {
+impl<C, R: DeserializeOwned> SelectMovie for SyncRequest<C, R> {
     fn movie_value(mut self, movie: Value) -> Self {
         self.movies.push(movie);
         self
     }
 }
 
-impl<'a, R: DeserializeOwned> SelectShow for SyncRequest<'a, R> {
+impl<C, R: DeserializeOwned> SelectShow for SyncRequest<C, R> {
     fn show_value(mut self, show: Value) -> Self {
         self.shows.push(show);
         self
     }
 }
 
-impl<'a, R: DeserializeOwned> SelectSeason for SyncRequest<'a, R> {
+impl<C, R: DeserializeOwned> SelectSeason for SyncRequest<C, R> {
     fn season_value(mut self, season: Value) -> Self {
         self.seasons.push(season);
         self
     }
 }
 
-impl<'a, R: DeserializeOwned> SelectEpisode for SyncRequest<'a, R> {
+impl<C, R: DeserializeOwned> SelectEpisode for SyncRequest<C, R> {
     fn episode_value(mut self, episode: Value) -> Self {
         self.episodes.push(episode);
         self
@@ -0,0 +1,401 @@
+use crate::{
+    models::{
+        comment::GetComments,
+        like::{LikeableType, UserLike},
+        user::{
+            Follow, FollowRequest, FollowRequestApprove, FollowedUser, Friend, FullFollowedUser,
+            FullFriend, FullUser, Settings,
+        },
+        CollectionMovie, CollectionShow, CommentAndItem, User,
+    },
+    request::ApiRequest,
+    route::Route,
+};
+
+endpoints! {
+    #[auth(required)]
+    pub fn user_settings(&self) -> Settings {
+        ApiRequest::get(Route::new("/users/settings"))
+    }
+
+    #[auth(required)]
+    pub fn user_requests(&self) -> Vec<FollowRequest> {
+        ApiRequest::get(Route::new("/users/requests"))
+    }
+
+    #[auth(required)]
+    pub fn user_request_approve(&self, id: u32) -> FollowRequestApprove {
+        ApiRequest::post(Route::new("/users/requests").segment(id), "".to_owned())
+    }
+
+    #[auth(required)]
+    pub fn user_request_deny(&self, id: u32) -> () {
+        ApiRequest::delete(Route::new("/users/requests").segment(id))
+    }
+
+    #[auth(required)]
+    pub fn user_likes(&self, item_type: Option<LikeableType>) -> Paginated<UserLike> {
+        ApiRequest::get(Route::new("/users/likes").optional_segment(item_type))
+    }
+
+    #[auth(optional)]
+    pub fn user_profile(&self, slug: &str) -> User {
+        ApiRequest::get(Route::new("/users").segment(slug))
+    }
+
+    #[auth(optional)]
+    pub fn user_profile_full(&self, slug: &str) -> FullUser {
+        ApiRequest::get(Route::new("/users").segment(slug)).query("extended", "full")
+    }
+
+    #[auth(optional)]
+    pub fn user_collection_movies(&self, slug: &str) -> Vec<CollectionMovie> {
+        ApiRequest::get(
+            Route::new("/users")
+                .segment(slug)
+                .segment("collection")
+                .segment("movies"),
+        )
+    }
+
+    #[auth(optional)]
+    pub fn user_collection_shows(&self, slug: &str) -> Vec<CollectionShow> {
+        ApiRequest::get(
+            Route::new("/users")
+                .segment(slug)
+                .segment("collection")
+                .segment("shows"),
+        )
+    }
+
+    #[auth(optional)]
+    pub fn user_comments(
+        &self,
+        slug: &str,
+        f: impl FnOnce(GetComments) -> GetComments,
+    ) -> Paginated<CommentAndItem> {
+        let gc = f(GetComments::default());
+
+        ApiRequest::get(
+            Route::new("/users")
+                .segment(slug)
+                .segment("comments")
+                .segment(gc.comment_type)
+                .segment(gc.item_type),
+        )
+        .query("include_replies", gc.include_replies)
+    }
+
+    #[auth(required)]
+    /// Follow an user. Following a private user only sends a follow request
+    pub fn user_follow(&self, slug: &str) -> Follow {
+        ApiRequest::post(
+            Route::new("/users").segment(slug).segment("follow"),
+            String::new(),
+        )
+    }
+
+    #[auth(required)]
+    /// Unfollow an user
+    pub fn user_unfollow(&self, slug: &str) -> () {
+        ApiRequest::delete(Route::new("/users").segment(slug).segment("follow"))
+    }
+
+    #[auth(optional)]
+    /// Get the users who follow an user
+    pub fn user_followers(&self, slug: &str) -> Vec<FollowedUser> {
+        ApiRequest::get(Route::new("/users").segment(slug).segment("followers"))
+    }
+
+    #[auth(optional)]
+    /// Get the users who follow an user with full extended info
+    pub fn user_followers_full(&self, slug: &str) -> Vec<FullFollowedUser> {
+        ApiRequest::get(Route::new("/users").segment(slug).segment("followers"))
+            .query("extended", "full")
+    }
+
+    #[auth(optional)]
+    /// Get the users an user is following
+    pub fn user_following(&self, slug: &str) -> Vec<FollowedUser> {
+        ApiRequest::get(Route::new("/users").segment(slug).segment("following"))
+    }
+
+    #[auth(optional)]
+    /// Get the users an user is following with full extended info
+    pub fn user_following_full(&self, slug: &str) -> Vec<FullFollowedUser> {
+        ApiRequest::get(Route::new("/users").segment(slug).segment("following"))
+            .query("extended", "full")
+    }
+
+    #[auth(optional)]
+    /// Get the users who follow an user and are followed back
+    pub fn user_friends(&self, slug: &str) -> Vec<Friend> {
+        ApiRequest::get(Route::new("/users").segment(slug).segment("friends"))
+    }
+
+    #[auth(optional)]
+    /// Get the users who follow an user and are followed back with full extended info
+    pub fn user_friends_full(&self, slug: &str) -> Vec<FullFriend> {
+        ApiRequest::get(Route::new("/users").segment(slug).segment("friends"))
+            .query("extended", "full")
+    }
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod sync_tests {
+    use crate::{
+        error::Error,
+        models::Follow,
+        tests::{auth_mock, mock, session},
+        TraktApi,
+    };
+    use chrono::{DateTime, Utc};
+    use mockito::Matcher;
+
+    #[test]
+    fn user_profile_with_space() -> Result<(), Error> {
+        let m = mock("GET", "/users/sean%20rudford", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/user.json")
+            .create();
+
+        let user = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .user_profile("sean rudford")?;
+
+        assert_eq!(user.username, "sean");
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_profile_with_unicode() -> Result<(), Error> {
+        let m = mock(
+            "GET",
+            "/users/J%C3%BCrgen%2FM%C3%BCller/collection/movies",
+            "CLIENT_ID",
+        )
+        .with_status(200)
+        .with_body("[]")
+        .create();
+
+        let collection = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .user_collection_movies("Jürgen/Müller")?;
+
+        assert!(collection.is_empty());
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_profile_optional_auth() -> Result<(), Error> {
+        let anonymous = mock("GET", "/users/sean", "CLIENT_ID")
+            .match_header("Authorization", Matcher::Missing)
+            .with_status(200)
+            .with_body_from_file("mock_data/user.json")
+            .create();
+        let authenticated = auth_mock("GET", "/users/sean", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(200)
+            .with_body_from_file("mock_data/user.json")
+            .create();
+
+        TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .user_profile("sean")?;
+        session(&mockito::server_url()).user_profile("sean")?;
+
+        anonymous.assert();
+        authenticated.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_follow() -> Result<(), Error> {
+        let m = auth_mock("POST", "/users/sean/follow", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(201)
+            .with_body_from_file("mock_data/user_follow.json")
+            .create();
+
+        match session(&mockito::server_url()).user_follow("sean")? {
+            Follow::Approved { approved_at, user } => {
+                assert_eq!(
+                    approved_at,
+                    "2014-11-15T09:41:34.704Z".parse::<DateTime<Utc>>().unwrap()
+                );
+                assert_eq!(user.username, "sean");
+            }
+            Follow::Pending { .. } => panic!("follow is pending"),
+        }
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_follow_pending() -> Result<(), Error> {
+        let m = auth_mock("POST", "/users/sean/follow", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(201)
+            .with_body(
+                r#"{"approved_at":null,"user":{"username":"sean","private":true,"name":"Sean Rudford","vip":true,"vip_ep":false,"ids":{"slug":"sean"}}}"#,
+            )
+            .create();
+
+        let follow = session(&mockito::server_url()).user_follow("sean")?;
+
+        assert!(matches!(follow, Follow::Pending { .. }));
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_unfollow() -> Result<(), Error> {
+        let m = auth_mock("DELETE", "/users/sean/follow", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(204)
+            .create();
+
+        session(&mockito::server_url()).user_unfollow("sean")?;
+
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_followers() -> Result<(), Error> {
+        let m = mock("GET", "/users/justin/followers", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/user_followers.json")
+            .create();
+
+        let followers = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .user_followers("justin")?;
+
+        assert_eq!(followers.len(), 1);
+        assert_eq!(followers[0].user.username, "sean");
+        assert_eq!(
+            followers[0].followed_at,
+            "2014-09-01T09:10:11.000Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        m.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn user_friends_full() -> Result<(), Error> {
+        let m = auth_mock(
+            "GET",
+            "/users/me/friends?extended=full",
+            "CLIENT_ID",
+            "ACCESS_TOKEN",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/user_friends_full.json")
+        .create();
+
+        let friends = session(&mockito::server_url()).user_friends_full("me")?;
+
+        assert_eq!(friends[0].user.location, Some("San Diego, CA".to_owned()));
+        assert_eq!(friends[0].user.vip_years, Some(5));
+        m.assert();
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use crate::{
+        asyn::TraktApi,
+        error::Error,
+        models::Follow,
+        tests::{async_session, auth_mock, mock},
+    };
+    use mockito::Matcher;
+
+    #[tokio::test]
+    async fn user_profile_with_space() -> Result<(), Error> {
+        let m = mock("GET", "/users/sean%20rudford", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/user.json")
+            .create();
+
+        let user = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .user_profile("sean rudford")
+            .await?;
+
+        assert_eq!(user.username, "sean");
+        m.assert();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_profile_with_unicode() -> Result<(), Error> {
+        let m = mock(
+            "GET",
+            "/users/%E3%83%A6%E3%83%BC%E3%82%B6%E3%83%BC",
+            "CLIENT_ID",
+        )
+        .with_status(200)
+        .with_body_from_file("mock_data/user.json")
+        .create();
+
+        let user = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .user_profile("ユーザー")
+            .await?;
+
+        assert_eq!(user.username, "sean");
+        m.assert();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_profile_optional_auth() -> Result<(), Error> {
+        let anonymous = mock("GET", "/users/sean", "CLIENT_ID")
+            .match_header("Authorization", Matcher::Missing)
+            .with_status(200)
+            .with_body_from_file("mock_data/user.json")
+            .create();
+        let authenticated = auth_mock("GET", "/users/sean", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(200)
+            .with_body_from_file("mock_data/user.json")
+            .create();
+
+        TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .user_profile("sean")
+            .await?;
+        async_session(&mockito::server_url())
+            .user_profile("sean")
+            .await?;
+
+        anonymous.assert();
+        authenticated.assert();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_follow() -> Result<(), Error> {
+        let m = auth_mock("POST", "/users/sean/follow", "CLIENT_ID", "ACCESS_TOKEN")
+            .with_status(201)
+            .with_body_from_file("mock_data/user_follow.json")
+            .create();
+
+        let follow = async_session(&mockito::server_url())
+            .user_follow("sean")
+            .await?;
+
+        assert!(matches!(follow, Follow::Approved { .. }));
+        m.assert();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_following() -> Result<(), Error> {
+        let m = mock("GET", "/users/justin/following", "CLIENT_ID")
+            .with_status(200)
+            .with_body_from_file("mock_data/user_followers.json")
+            .create();
+
+        let following = TraktApi::with_url(&mockito::server_url(), "CLIENT_ID".to_owned(), None)
+            .user_following("justin")
+            .await?;
+
+        assert_eq!(following[0].user.username, "sean");
+        m.assert();
+        Ok(())
+    }
+}
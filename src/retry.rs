@@ -0,0 +1,371 @@
+//! Retrying of requests that failed because of transient errors
+use crate::{
+    error::{ApiErrorKind, Error},
+    oauth::random_bytes,
+    rate_limit::{RateLimitBucket, RateLimiter},
+    transport::HttpRequest,
+};
+use reqwest::{Method, Url};
+use std::{
+    convert::TryInto,
+    fmt, io,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Information about a request which is about to be retried
+#[derive(Debug)]
+pub struct RetryAttempt<'a> {
+    pub method: &'a Method,
+    pub url: &'a Url,
+    /// Number of the retry, starting at 1
+    pub attempt: u32,
+    /// Time until the request is sent again
+    pub delay: Duration,
+    /// The error of the last try
+    pub error: &'a Error,
+}
+
+type RetryHook = Arc<dyn Fn(&RetryAttempt) + Send + Sync>;
+
+/// Configures which failed requests are retried and how long to wait between the tries.
+///
+/// Connection errors, timeouts and the status codes 502, 503, 504 and 520 to 530 are retried
+/// with an exponential backoff. By default only GET requests and requests marked as
+/// [idempotent] are retried.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use trakt::retry::RetryPolicy;
+///
+/// let policy = RetryPolicy::default()
+///     .max_retries(5)
+///     .max_elapsed(Duration::from_secs(60))
+///     .retry_mutating(true)
+///     .on_retry(|attempt| println!("retry {} of {}", attempt.attempt, attempt.url));
+/// ```
+///
+/// [idempotent]: ../request/struct.ApiRequest.html#method.idempotent
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    max_elapsed: Option<Duration>,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    retry_mutating: bool,
+    on_retry: Option<RetryHook>,
+}
+
+impl RetryPolicy {
+    /// A policy which never retries
+    pub fn none() -> Self {
+        Self::default().max_retries(0)
+    }
+
+    /// How often a request is retried at most. Defaults to 3.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// The longest time spent on a request including all retries. Unlimited by default.
+    pub fn max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = Some(max_elapsed);
+        self
+    }
+
+    /// Time to wait before the first retry. It doubles with every retry. Defaults to 500ms.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// The longest time to wait between two tries. Defaults to 30s.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Whether all POST, PUT and DELETE requests are retried too. Defaults to false.
+    ///
+    /// Single requests can be marked as safe to retry with [ApiRequest::idempotent].
+    ///
+    /// [ApiRequest::idempotent]: ../request/struct.ApiRequest.html#method.idempotent
+    pub fn retry_mutating(mut self, retry_mutating: bool) -> Self {
+        self.retry_mutating = retry_mutating;
+        self
+    }
+
+    /// Sets a function which is called before every retry
+    pub fn on_retry<F: Fn(&RetryAttempt) + Send + Sync + 'static>(mut self, f: F) -> Self {
+        self.on_retry = Some(Arc::new(f));
+        self
+    }
+
+    /// Returns true if the error is worth another try
+    pub fn is_transient(error: &Error) -> bool {
+        match error {
+            Error::Connection(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+            Error::Io(e) => matches!(
+                e.kind(),
+                io::ErrorKind::TimedOut
+                    | io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+            ),
+            Error::Response(e) => matches!(e.status.as_u16(), 502 | 503 | 504 | 520..=530),
+            _ => false,
+        }
+    }
+
+    /// Time to wait before the `retries + 1`th retry. Returns None if it should not be retried.
+    fn delay(
+        &self,
+        method: &Method,
+        idempotent: bool,
+        retries: u32,
+        elapsed: Duration,
+        error: &Error,
+    ) -> Option<Duration> {
+        if (!self.retry_mutating && !idempotent && method != Method::GET)
+            || retries >= self.max_retries
+            || !Self::is_transient(error)
+        {
+            return None;
+        }
+
+        let backoff = self
+            .initial_backoff
+            .checked_mul(2u32.saturating_pow(retries))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff));
+
+        // Equal jitter: wait between half and the full backoff
+        let half = backoff / 2;
+        let delay = half + half.mul_f64(jitter());
+
+        match self.max_elapsed {
+            Some(max) if elapsed + delay > max => None,
+            _ => Some(delay),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            max_elapsed: None,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            retry_mutating: false,
+            on_retry: None,
+        }
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_retries", &self.max_retries)
+            .field("max_elapsed", &self.max_elapsed)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("retry_mutating", &self.retry_mutating)
+            .field("on_retry", &self.on_retry.is_some())
+            .finish()
+    }
+}
+
+/// A random number between 0 and 1
+fn jitter() -> f64 {
+    let bytes = random_bytes(8).try_into().unwrap();
+    (u64::from_le_bytes(bytes) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Keeps track of the tries of a single request
+#[derive(Debug)]
+pub(crate) struct RetryState {
+    method: Method,
+    url: Url,
+    idempotent: bool,
+    pub(crate) bucket: RateLimitBucket,
+    rate_limited: u32,
+    retries: u32,
+    start: Instant,
+}
+
+impl RetryState {
+    pub(crate) fn new(request: &HttpRequest) -> Self {
+        Self {
+            method: request.method.clone(),
+            url: request.url.clone(),
+            idempotent: request.idempotent,
+            bucket: RateLimitBucket::of(&request.method, &request.headers),
+            rate_limited: 0,
+            retries: 0,
+            start: Instant::now(),
+        }
+    }
+
+    /// Number of retries so far, including those after hitting the rate limit
+    pub(crate) fn retries(&self) -> u32 {
+        self.rate_limited + self.retries
+    }
+
+    /// Time to wait before retrying the request after it failed with `error`.
+    /// Rate limited requests are handled by the [RateLimiter], all others by the [RetryPolicy].
+    /// Returns None if the request should not be retried.
+    pub(crate) fn next_delay(
+        &mut self,
+        limiter: &RateLimiter,
+        policy: &RetryPolicy,
+        error: &Error,
+    ) -> Option<Duration> {
+        let delay = match error {
+            Error::Response(e) if e.kind == ApiErrorKind::RateLimited => {
+                let delay = limiter.retry_delay(self.bucket, self.rate_limited, e)?;
+                self.rate_limited += 1;
+                delay
+            }
+            _ => {
+                let delay = policy.delay(
+                    &self.method,
+                    self.idempotent,
+                    self.retries,
+                    self.start.elapsed(),
+                    error,
+                )?;
+                self.retries += 1;
+                delay
+            }
+        };
+
+        if let Some(on_retry) = &policy.on_retry {
+            on_retry(&RetryAttempt {
+                method: &self.method,
+                url: &self.url,
+                attempt: self.retries(),
+                delay,
+                error,
+            });
+        }
+
+        Some(delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{ApiError, Error},
+        retry::{jitter, RetryPolicy},
+    };
+    use reqwest::{header::HeaderMap, Method, StatusCode};
+    use std::{io, time::Duration};
+
+    fn status(code: u16) -> Error {
+        Error::from(ApiError::new(
+            StatusCode::from_u16(code).unwrap(),
+            HeaderMap::new(),
+            b"",
+        ))
+    }
+
+    #[test]
+    fn is_transient() {
+        assert!(RetryPolicy::is_transient(&status(502)));
+        assert!(RetryPolicy::is_transient(&status(503)));
+        assert!(RetryPolicy::is_transient(&status(504)));
+        assert!(RetryPolicy::is_transient(&status(522)));
+        assert!(!RetryPolicy::is_transient(&status(500)));
+        assert!(!RetryPolicy::is_transient(&status(404)));
+        assert!(!RetryPolicy::is_transient(&status(429)));
+        assert!(!RetryPolicy::is_transient(&Error::NoneError));
+        assert!(RetryPolicy::is_transient(&Error::from(io::Error::from(
+            io::ErrorKind::TimedOut
+        ))));
+        assert!(!RetryPolicy::is_transient(&Error::from(io::Error::from(
+            io::ErrorKind::NotFound
+        ))));
+    }
+
+    #[test]
+    fn delay() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_secs(2))
+            .max_backoff(Duration::from_secs(5));
+        let zero = Duration::from_secs(0);
+
+        let first = policy
+            .delay(&Method::GET, false, 0, zero, &status(503))
+            .unwrap();
+        assert!(first >= Duration::from_secs(1) && first <= Duration::from_secs(2));
+
+        let second = policy
+            .delay(&Method::GET, false, 1, zero, &status(503))
+            .unwrap();
+        assert!(second >= Duration::from_secs(2) && second <= Duration::from_secs(4));
+
+        let third = policy
+            .delay(&Method::GET, false, 2, zero, &status(503))
+            .unwrap();
+        assert!(third >= Duration::from_millis(2500) && third <= Duration::from_secs(5));
+
+        assert_eq!(
+            policy.delay(&Method::GET, false, 3, zero, &status(503)),
+            None
+        );
+        assert_eq!(
+            policy.delay(&Method::GET, false, 0, zero, &status(400)),
+            None
+        );
+        assert_eq!(
+            policy.delay(&Method::POST, false, 0, zero, &status(503)),
+            None
+        );
+        assert!(policy
+            .delay(&Method::POST, true, 0, zero, &status(503))
+            .is_some());
+    }
+
+    #[test]
+    fn delay_limits() {
+        let policy = RetryPolicy::default()
+            .retry_mutating(true)
+            .max_elapsed(Duration::from_secs(1));
+
+        assert!(policy
+            .delay(
+                &Method::POST,
+                false,
+                0,
+                Duration::from_millis(100),
+                &status(503)
+            )
+            .is_some());
+        assert_eq!(
+            policy.delay(
+                &Method::POST,
+                false,
+                0,
+                Duration::from_secs(1),
+                &status(503)
+            ),
+            None
+        );
+        assert_eq!(
+            RetryPolicy::none().delay(&Method::GET, false, 0, Duration::from_secs(0), &status(503)),
+            None
+        );
+    }
+
+    #[test]
+    fn jitter_range() {
+        let values: Vec<_> = (0..16).map(|_| jitter()).collect();
+
+        assert!(values.iter().all(|v| (0.0..1.0).contains(v)));
+        assert!(values.iter().any(|v| *v != values[0]));
+    }
+}
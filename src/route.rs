@@ -0,0 +1,144 @@
+//! Typed construction of the paths of api endpoints
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use std::fmt;
+
+/// Characters which are escaped in a path segment. Everything but ASCII letters, digits and
+/// the characters allowed in a segment by RFC 3986.
+const SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// The path of an api endpoint, relative to the base url of the client.
+///
+/// It starts with a static path and is extended segment by segment. Each segment is
+/// percent-encoded, so ids, slugs and usernames may contain any character, including `/`.
+///
+/// # Example
+///
+/// ```rust
+/// use trakt::route::Route;
+///
+/// let route = Route::new("/users").segment("Jürgen Müller").segment("lists");
+/// assert_eq!(route.as_str(), "/users/J%C3%BCrgen%20M%C3%BCller/lists");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Route {
+    path: String,
+}
+
+impl Route {
+    /// Creates a route from a static path like `/sync/history`. It is used as is, so it must
+    /// not contain any user input.
+    pub fn new(path: &'static str) -> Self {
+        let path = path.trim_end_matches('/');
+
+        if path.starts_with('/') {
+            Self {
+                path: path.to_owned(),
+            }
+        } else {
+            Self {
+                path: format!("/{}", path),
+            }
+        }
+    }
+
+    /// Appends a percent-encoded segment
+    pub fn segment(mut self, segment: impl fmt::Display) -> Self {
+        self.path.push('/');
+        self.path
+            .extend(utf8_percent_encode(&segment.to_string(), SEGMENT));
+        self
+    }
+
+    /// Appends a percent-encoded segment if it is given
+    pub fn optional_segment(self, segment: Option<impl fmt::Display>) -> Self {
+        match segment {
+            Some(segment) => self.segment(segment),
+            None => self,
+        }
+    }
+
+    /// The percent-encoded path
+    pub fn as_str(&self) -> &str {
+        &self.path
+    }
+}
+
+impl fmt::Display for Route {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Route;
+
+    #[test]
+    fn static_path() {
+        assert_eq!(Route::new("/sync/history").as_str(), "/sync/history");
+        assert_eq!(Route::new("networks").as_str(), "/networks");
+        assert_eq!(Route::new("/lists/").as_str(), "/lists");
+    }
+
+    #[test]
+    fn segments() {
+        let route = Route::new("/shows")
+            .segment("fairy-tail")
+            .segment("seasons")
+            .segment(1);
+        assert_eq!(route.as_str(), "/shows/fairy-tail/seasons/1");
+
+        assert_eq!(
+            Route::new("/search").segment("movie,show").as_str(),
+            "/search/movie,show"
+        );
+    }
+
+    #[test]
+    fn escaped_segments() {
+        assert_eq!(
+            Route::new("/users").segment("sean rudford").as_str(),
+            "/users/sean%20rudford"
+        );
+        assert_eq!(
+            Route::new("/users").segment("ユーザー").as_str(),
+            "/users/%E3%83%A6%E3%83%BC%E3%82%B6%E3%83%BC"
+        );
+        assert_eq!(
+            Route::new("/users").segment("a/b?c#d%e").as_str(),
+            "/users/a%2Fb%3Fc%23d%25e"
+        );
+    }
+
+    #[test]
+    fn optional_segment() {
+        assert_eq!(
+            Route::new("/sync/watchlist")
+                .optional_segment(Some("movies"))
+                .as_str(),
+            "/sync/watchlist/movies"
+        );
+        assert_eq!(
+            Route::new("/sync/watchlist")
+                .optional_segment(None::<&str>)
+                .as_str(),
+            "/sync/watchlist"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::error::Error;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use serde_json::{Map, Number, Value};
@@ -85,6 +86,11 @@ pub trait Selector: Sized {
     fn rating(self, rating: u8) -> Self {
         self.insert_num("rating".to_owned(), rating)
     }
+
+    /// Specify notes for an item in a list
+    fn notes(self, notes: &str) -> Self {
+        self.insert_str("notes".to_owned(), notes.to_owned())
+    }
 }
 
 /// Trait allowing an item to be selected by [ids]
@@ -529,6 +535,84 @@ impl SelectIds for EpisodeSelector {
     }
 }
 
+/// Trait for selecting people
+pub trait SelectPerson: Sized {
+    /// Select a person by [serde_json::Value]
+    ///
+    /// [serde_json::Value]: ../../serde_json/enum.Value.html
+    fn person_value(self, person: Value) -> Self;
+
+    /// Select a person by a [serializable] item.
+    /// Most probably a [Person].
+    ///
+    /// [serializable]: ../../serde/trait.Serialize.html
+    /// [Person]: ../models/people/struct.Person.html
+    fn person_item<T: Serialize>(self, person: T) -> Self {
+        self.person_value(serde_json::to_value(person).unwrap())
+    }
+
+    /// Select a person by a JSON object string
+    ///
+    /// # Panics
+    ///
+    /// Panics if person is not a valid JSON object
+    fn person_json(self, person: &str) -> Self {
+        self.person_value(serde_json::from_str(person).unwrap())
+    }
+
+    /// Select a person by a [PersonSelector]
+    ///
+    /// [PersonSelector]: struct.PersonSelector.html
+    fn person(self, f: impl FnOnce(PersonSelector) -> PersonSelector) -> Self {
+        self.person_value(f(PersonSelector::default()).build())
+    }
+}
+
+/// A struct for specifying a person.
+///
+/// See [Selector] and [SelectIds] for more info
+///
+/// [Selector]: trait.Selector.html
+/// [SelectIds]: trait.SelectIds.html
+pub struct PersonSelector {
+    person: Map<String, Value>,
+}
+
+impl Default for PersonSelector {
+    fn default() -> Self {
+        Self { person: Map::new() }
+    }
+}
+
+impl Selector for PersonSelector {
+    fn build(self) -> Value {
+        Value::Object(self.person)
+    }
+
+    fn insert(mut self, k: String, v: Value) -> Self {
+        self.person.insert(k, v);
+        self
+    }
+
+    fn map(mut self, map: Map<String, Value>) -> Self {
+        for (k, v) in map {
+            self.person.insert(k, v);
+        }
+        self
+    }
+}
+
+impl SelectIds for PersonSelector {
+    fn ids(&mut self) -> &mut Map<String, Value> {
+        if !self.person.contains_key("ids") {
+            self.person
+                .insert("ids".to_owned(), Value::Object(Map::new()));
+        }
+
+        self.person.get_mut("ids").unwrap().as_object_mut().unwrap()
+    }
+}
+
 /// Trait for selecting lists
 pub trait SelectList: Sized {
     /// Select a list by [serde_json::Value]
@@ -604,6 +688,31 @@ impl ListSelector {
     pub fn user(self, f: impl FnOnce(UserSelector) -> UserSelector) -> Self {
         self.insert("user".to_owned(), f(UserSelector::default()).build())
     }
+
+    /// The slug of the user and the trakt id or slug of a personal list, as used in its route
+    ///
+    /// Fails with [Error::NoneError] if the slug of the user or both the trakt id and slug of
+    /// the list are missing
+    ///
+    /// [Error::NoneError]: ../error/enum.Error.html#variant.NoneError
+    pub fn user_and_list_id(&self) -> Result<(String, String), Error> {
+        let user = self
+            .list
+            .get("user")
+            .and_then(|user| user["ids"]["slug"].as_str())
+            .ok_or(Error::NoneError)?;
+
+        let id = match self.list.get("ids") {
+            Some(ids) => match (&ids["trakt"], &ids["slug"]) {
+                (Value::Number(id), _) => id.to_string(),
+                (_, Value::String(slug)) => slug.to_owned(),
+                _ => return Err(Error::NoneError),
+            },
+            None => return Err(Error::NoneError),
+        };
+
+        Ok((user.to_owned(), id))
+    }
 }
 
 impl SelectIds for ListSelector {
@@ -1029,6 +1138,23 @@ mod tests {
         )
     }
 
+    #[test]
+    fn person_selector_notes() {
+        let s = PersonSelector::default()
+            .slug("bryan-cranston")
+            .notes("Walter White");
+
+        assert_eq!(
+            s.build(),
+            json!({
+                "ids": {
+                    "slug": "bryan-cranston"
+                },
+                "notes": "Walter White"
+            })
+        )
+    }
+
     #[test]
     fn user_selector_value() {
         let s = UserSelector::default().value(json!({
@@ -1074,4 +1200,28 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn list_selector_user_and_list_id() {
+        let list = ListSelector::default().user(|user| user.slug("sean"));
+
+        assert!(matches!(
+            list.user_and_list_id(),
+            Err(crate::error::Error::NoneError)
+        ));
+        assert_eq!(
+            list.slug("star-wars").user_and_list_id().unwrap(),
+            ("sean".to_owned(), "star-wars".to_owned())
+        );
+        assert_eq!(
+            ListSelector::default()
+                .user(|user| user.slug("sean"))
+                .slug("star-wars")
+                .id(55)
+                .user_and_list_id()
+                .unwrap(),
+            ("sean".to_owned(), "55".to_owned())
+        );
+        assert!(ListSelector::default().id(55).user_and_list_id().is_err());
+    }
 }
@@ -1,362 +1,210 @@
+pub mod builder;
 pub mod pagination;
-pub mod requests;
+pub mod session;
+pub mod transport;
 
 use crate::{
-    error::Error,
-    models::{Certifications, CertificationsType, Country, Genre, Language, MediaType, Network},
+    cache::Cache,
+    error::{ApiError, DeserializeError, Error},
+    middleware::{Middleware, MiddlewareChain, ResponseInfo},
+    oauth::oauth_url_of,
+    pagination::Page,
+    rate_limit::{RateLimit, RateLimitBucket, RateLimitConfig, RateLimiter},
+    request::{self, ApiRequest},
+    retry::{RetryPolicy, RetryState},
+    sync::transport::{CachingTransport, ReqwestTransport, Transport},
+    transport::{HttpRequest, HttpResponse},
 };
-use reqwest::{Method, Request, RequestBuilder};
 use serde::de::DeserializeOwned;
+use std::{sync::Arc, thread, time::Instant};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
-/// The main struct which contains all requests
+/// The main struct which contains all requests.
+///
+/// It owns its configuration and is cheap to clone, since clones share the [Transport],
+/// the rate limit state and the retry policy.
+///
+/// [Transport]: transport/trait.Transport.html
 #[derive(Debug, Clone)]
-pub struct TraktApi<'a> {
-    base_url: &'a str,
-    client: reqwest::Client,
-    client_id: String,
-    client_secret: Option<String>,
+pub struct TraktApi {
+    base_url: Arc<str>,
+    pub(crate) oauth_url: Arc<str>,
+    transport: Arc<dyn Transport>,
+    pub(crate) client_id: Arc<str>,
+    pub(crate) client_secret: Option<Arc<str>>,
+    rate_limiter: RateLimiter,
+    retry_policy: RetryPolicy,
+    /// The cache the transport is wrapped in, so it can be carried over to the other client
+    cache: Option<Cache>,
+    pub(crate) middleware: MiddlewareChain,
 }
 
 /// Generic functions and simple requests
-impl<'a> TraktApi<'a> {
+impl TraktApi {
     /// Creates a new client. Client ID is needed. Client secret is optional, if you need authorization
-    pub fn new(client_id: String, client_secret: Option<String>) -> TraktApi<'a> {
+    pub fn new(client_id: String, client_secret: Option<String>) -> TraktApi {
         Self::with_url("https://api.trakt.tv", client_id, client_secret)
     }
 
     /// Creates a new client in the staging environment. Client ID is needed. Client secret is optional, if you need authorization
-    pub fn staging(client_id: String, client_secret: Option<String>) -> TraktApi<'a> {
+    pub fn staging(client_id: String, client_secret: Option<String>) -> TraktApi {
         Self::with_url("https://api-staging.trakt.tv", client_id, client_secret)
     }
 
     /// Creates a new client with a specified base url. Client ID is needed. Client secret is optional, if you need authorization
-    pub fn with_url(
-        base_url: &'a str,
-        client_id: String,
-        client_secret: Option<String>,
-    ) -> TraktApi {
+    pub fn with_url(base_url: &str, client_id: String, client_secret: Option<String>) -> TraktApi {
         TraktApi {
-            base_url,
-            client: reqwest::Client::new(),
-            client_id,
-            client_secret,
+            base_url: Arc::from(base_url),
+            oauth_url: Arc::from(oauth_url_of(base_url)),
+            transport: Arc::new(ReqwestTransport::default()),
+            client_id: Arc::from(client_id),
+            client_secret: client_secret.map(Arc::from),
+            rate_limiter: RateLimiter::default(),
+            retry_policy: RetryPolicy::default(),
+            cache: None,
+            middleware: MiddlewareChain::default(),
         }
     }
 
-    /// Generates a [reqwest::RequestBuilder] with the necessary headers
+    /// Sets how requests that hit the [rate limit] are handled
     ///
-    /// [reqwest::RequestBuilder]: ../reqwest/struct.RequestBuilder.html
-    fn builder(&self, method: Method, url: String) -> RequestBuilder {
-        self.client
-            .request(method, &format!("{}{}", self.base_url, url))
-            .header("Content-Type", "application/json")
-            .header("trakt-api-version", "2")
-            .header("trakt-api-key", self.client_id.as_str())
+    /// [rate limit]: https://trakt.docs.apiary.io/#introduction/rate-limiting
+    pub fn with_rate_limit(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limiter = RateLimiter::new(config);
+        self
     }
 
-    /// Executes a [reqwest::Request] and parses the [reqwest::Response]
-    ///
-    /// [reqwest::Request]: ../reqwest/struct.Request.html
-    /// [reqwest::Response]: ../reqwest/struct.Response.html
-    fn execute<T: DeserializeOwned>(&self, request: Request) -> Result<T> {
-        match self.client.execute(request) {
-            Ok(res) => {
-                if res.status().is_success() {
-                    Ok(serde_json::from_reader(res).unwrap())
-                } else {
-                    Err(Error::from(res))
-                }
-            }
-            Err(e) => Err(Error::from(e)),
-        }
+    /// Sets which requests are retried after transient errors.
+    /// By default GET requests are retried up to 3 times.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
     }
 
-    /// A generic function which makes a GET request to the given url and receives a deserialized Object
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
+    /// Sends all requests over the given [Transport] instead of reqwest
     ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
-    ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn get<T: DeserializeOwned>(&self, url: String) -> Result<T> {
-        self._get(&url)
+    /// [Transport]: transport/trait.Transport.html
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self.cache = None;
+        self
     }
 
-    /// A generic function which makes a GET request to the given url and receives a deserialized Object
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
+    /// Caches the responses of read-only endpoints. The cache wraps the current [Transport],
+    /// so set it after [with_transport].
     ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn _get<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
-        match self
-            .client
-            .get(&format!("{}{}", self.base_url, url))
-            .header("Content-Type", "application/json")
-            .header("trakt-api-version", "2")
-            .header("trakt-api-key", self.client_id.as_str())
-            .send()
-        {
-            Ok(res) => {
-                if res.status().is_success() {
-                    Ok(serde_json::from_reader(res).unwrap())
-                } else {
-                    Err(Error::from(res))
-                }
-            }
-            Err(e) => Err(Error::from(e)),
-        }
+    /// [Transport]: transport/trait.Transport.html
+    /// [with_transport]: #method.with_transport
+    pub fn with_cache(mut self, cache: Cache) -> Self {
+        let cache = cache.base_url(&self.base_url);
+        self.transport = Arc::new(CachingTransport::new(self.transport, cache.clone()));
+        self.cache = Some(cache);
+        self
     }
 
-    /// A generic function which makes an authorized GET request to the given url and receives a deserialized Object
-    ///
-    /// # Errors
+    /// Adds a [Middleware] which is called around every request
     ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
-    ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn auth_get<T: DeserializeOwned>(&self, url: String, access_token: &str) -> Result<T> {
-        self._auth_get(&url, access_token)
+    /// [Middleware]: ../middleware/trait.Middleware.html
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
     }
 
-    /// A generic function which makes an authorized GET request to the given url and receives a deserialized Object
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
-    ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn _auth_get<T: DeserializeOwned>(&self, url: &str, access_token: &str) -> Result<T> {
-        match self
-            .client
-            .get(&format!("{}{}", self.base_url, url))
-            .header("Content-Type", "application/json")
-            .header("trakt-api-version", "2")
-            .header("trakt-api-key", self.client_id.as_str())
-            .bearer_auth(access_token)
-            .send()
-        {
-            Ok(res) => {
-                if res.status().is_success() {
-                    Ok(serde_json::from_reader(res).unwrap())
-                } else {
-                    Err(Error::from(res))
-                }
-            }
-            Err(e) => Err(Error::from(e)),
-        }
+    /// Returns the state of a rate limit as sent with the last response counted against it
+    pub fn rate_limit(&self, bucket: RateLimitBucket) -> Option<RateLimit> {
+        self.rate_limiter.get(bucket)
     }
 
-    /// A generic function which makes a POST request to the given url and receives a deserialized Object
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
+    /// Executes an [HttpRequest] and parses the [HttpResponse]
     ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn post<T: DeserializeOwned>(&self, url: String, body: String) -> Result<T> {
-        self._post(&url, body)
+    /// [HttpRequest]: ../transport/struct.HttpRequest.html
+    /// [HttpResponse]: ../transport/struct.HttpResponse.html
+    fn execute<T: DeserializeOwned>(&self, request: HttpRequest) -> Result<T> {
+        let url = request.url.to_string();
+        let res = self.send(request)?;
+
+        DeserializeError::deserialize(&url, &res.body)
     }
 
-    /// A generic function which makes a POST request to the given url and receives a deserialized Object
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
+    /// Executes an [HttpRequest] of a paginated endpoint and parses the [HttpResponse]
+    /// together with its pagination headers
     ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn _post<T: DeserializeOwned>(&self, url: &str, body: String) -> Result<T> {
-        match self
-            .client
-            .post(&format!("{}{}", self.base_url, url))
-            .header("Content-Type", "application/json")
-            .header("trakt-api-version", "2")
-            .header("trakt-api-key", self.client_id.as_str())
-            .body(body)
-            .send()
-        {
-            Ok(res) => {
-                if res.status().is_success() {
-                    Ok(serde_json::from_reader(res).unwrap())
-                } else {
-                    Err(Error::from(res))
-                }
-            }
-            Err(e) => Err(Error::from(e)),
-        }
+    /// [HttpRequest]: ../transport/struct.HttpRequest.html
+    /// [HttpResponse]: ../transport/struct.HttpResponse.html
+    fn execute_page<T: DeserializeOwned>(&self, request: HttpRequest) -> Result<Page<T>> {
+        let url = request.url.to_string();
+        let res = self.send(request)?;
+
+        let items = DeserializeError::deserialize(&url, &res.body)?;
+        Ok(Page::from_headers(items, &res.headers))
     }
 
-    /// A generic function which makes an authorized POST request to the given url and receives a deserialized Object
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
+    /// Executes an [HttpRequest] and ignores the body of the [HttpResponse]
     ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn auth_post<T: DeserializeOwned>(
-        &self,
-        url: String,
-        body: String,
-        access_token: &str,
-    ) -> Result<T> {
-        self._auth_post(&url, body, access_token)
+    /// [HttpRequest]: ../transport/struct.HttpRequest.html
+    /// [HttpResponse]: ../transport/struct.HttpResponse.html
+    fn execute_no_body(&self, request: HttpRequest) -> Result<()> {
+        self.send(request).map(|_| ())
     }
 
-    /// A generic function which makes an authorized POST request to the given url and receives a deserialized Object
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
-    ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn _auth_post<T: DeserializeOwned>(
-        &self,
-        url: &str,
-        body: String,
-        access_token: &str,
-    ) -> Result<T> {
-        match self
-            .client
-            .post(&format!("{}{}", self.base_url, url))
-            .header("Content-Type", "application/json")
-            .header("trakt-api-version", "2")
-            .header("trakt-api-key", self.client_id.as_str())
-            .bearer_auth(access_token)
-            .body(body)
-            .send()
-        {
-            Ok(res) => {
-                if res.status().is_success() {
-                    Ok(serde_json::from_reader(res).unwrap())
-                } else {
-                    Err(Error::from(res))
-                }
+    /// Sends an [HttpRequest] over the [Transport] and turns unsuccessful responses into
+    /// [Error::Response]. Failed requests are retried according to the [RateLimitConfig] and
+    /// [RetryPolicy].
+    ///
+    /// [HttpRequest]: ../transport/struct.HttpRequest.html
+    /// [Transport]: transport/trait.Transport.html
+    /// [Error::Response]: ../error/enum.Error.html#variant.Response
+    /// [RateLimitConfig]: ../rate_limit/struct.RateLimitConfig.html
+    /// [RetryPolicy]: ../retry/struct.RetryPolicy.html
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let mut state = RetryState::new(&request);
+
+        loop {
+            if let Some(wait) = self.rate_limiter.wait_time(state.bucket) {
+                thread::sleep(wait);
             }
-            Err(e) => Err(Error::from(e)),
-        }
-    }
 
-    /// A generic function which makes an authorized POST request to the given url and receives nothing
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
-    ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn auth_post_no_body(&self, url: String, body: String, access_token: &str) -> Result<()> {
-        self._auth_post_no_body(&url, body, access_token)
-    }
+            let mut sent = request.clone();
+            self.middleware.before_send(&mut sent, state.retries());
 
-    /// A generic function which makes an authorized POST request to the given url and receives nothing
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
-    ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn _auth_post_no_body(&self, url: &str, body: String, access_token: &str) -> Result<()> {
-        match self
-            .client
-            .post(&format!("{}{}", self.base_url, url))
-            .header("Content-Type", "application/json")
-            .header("trakt-api-version", "2")
-            .header("trakt-api-key", self.client_id.as_str())
-            .bearer_auth(access_token)
-            .body(body)
-            .send()
-        {
-            Ok(res) => {
-                if res.status().is_success() {
-                    Ok(())
-                } else {
-                    Err(Error::from(res))
+            let start = Instant::now();
+            let mut res = self.transport.send(sent.clone());
+            let info = ResponseInfo {
+                retries: state.retries(),
+                latency: start.elapsed(),
+            };
+            self.middleware.after_receive(&sent, &mut res, &info);
+
+            let e = match res {
+                Ok(res) => {
+                    self.rate_limiter.update(state.bucket, &res.headers);
+
+                    if res.status.is_success() {
+                        return Ok(res);
+                    }
+
+                    Error::from(ApiError::new(res.status, res.headers, &res.body))
                 }
+                Err(e) => e,
+            };
+
+            match state.next_delay(&self.rate_limiter, &self.retry_policy, &e) {
+                Some(delay) => thread::sleep(delay),
+                None => return Err(e),
             }
-            Err(e) => Err(Error::from(e)),
         }
     }
 
-    /// A generic function which makes an authorized DELETE request to the given url and receives nothing
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
+    /// Turns an [ApiRequest] into an [HttpRequest] with the necessary headers
     ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn auth_delete(&self, url: String, access_token: &str) -> Result<()> {
-        self._auth_delete(&url, access_token)
+    /// [ApiRequest]: ../request/struct.ApiRequest.html
+    /// [HttpRequest]: ../transport/struct.HttpRequest.html
+    fn request<T>(&self, request: &ApiRequest<T>) -> Result<HttpRequest> {
+        HttpRequest::new(&self.base_url, &self.client_id, request)
     }
 
-    /// A generic function which makes an authorized DELETE request to the given url and receives nothing
+    /// Executes an [ApiRequest] and receives a deserialized Object
     ///
     /// # Errors
     ///
@@ -364,149 +212,127 @@ impl<'a> TraktApi<'a> {
     ///
     /// Returns [Error::Connection] if the connection failed
     ///
-    /// Returns [Error::Serde] if the response could not be deserialized
+    /// Returns [Error::Deserialize] if the response could not be deserialized
     ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    fn _auth_delete(&self, url: &str, access_token: &str) -> Result<()> {
-        match self
-            .client
-            .delete(&format!("{}{}", self.base_url, url))
-            .header("Content-Type", "application/json")
-            .header("trakt-api-version", "2")
-            .header("trakt-api-key", self.client_id.as_str())
-            .bearer_auth(access_token)
-            .send()
-        {
-            Ok(res) => {
-                if res.status().is_success() {
-                    Ok(())
-                } else {
-                    Err(Error::from(res))
-                }
-            }
-            Err(e) => Err(Error::from(e)),
-        }
+    /// [ApiRequest]: ../request/struct.ApiRequest.html
+    /// [Error::Response]: ../error/enum.Error.html#variant.Response
+    /// [Error::Connection]: ../error/enum.Error.html#variant.Connection
+    /// [Error::Deserialize]: ../error/enum.Error.html#variant.Deserialize
+    pub(crate) fn execute_request<T: DeserializeOwned>(&self, request: ApiRequest<T>) -> Result<T> {
+        self.execute(self.request(&request)?)
     }
 
-    /// Get a Vec of all certifications, including names, slugs, and descriptions. [Trakt API][more]
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
+    /// Executes an [ApiRequest] of a paginated endpoint and receives the deserialized items
+    /// together with the pagination headers
     ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
-    ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    /// [more]: https://trakt.docs.apiary.io/#reference/certifications/list/get-certifications
-    pub fn certifications(&self, ct: CertificationsType) -> Result<Certifications> {
-        self.get(api_url!(("certifications", ct.to_string())))
+    /// [ApiRequest]: ../request/struct.ApiRequest.html
+    pub(crate) fn execute_request_page<T: DeserializeOwned>(
+        &self,
+        request: ApiRequest<Vec<T>>,
+    ) -> Result<Page<T>> {
+        self.execute_page(self.request(&request)?)
     }
 
-    /// Get a Vec of all countries, including names and codes. [Trakt API][more]
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
+    /// Executes an [ApiRequest] and ignores the body of the response
     ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
-    ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    /// [more]: https://trakt.docs.apiary.io/#reference/countries/list/get-countries
-    pub fn countries(&self, media_type: MediaType) -> Result<Vec<Country>> {
-        self.get(api_url!(("countries", media_type.to_string())))
+    /// [ApiRequest]: ../request/struct.ApiRequest.html
+    pub(crate) fn execute_request_no_body(&self, request: ApiRequest<()>) -> Result<()> {
+        self.execute_no_body(self.request(&request)?)
     }
 
-    /// Get a Vec of all genres, including names and slugs. [Trakt API][more]
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
+    /// Converts the client into an async one with the same urls, credentials, rate limit config,
+    /// retry policy, cache and middlewares.
     ///
-    /// Returns [Error::Serde] if the response could not be deserialized
+    /// The async client sends its requests over reqwest. A custom [Transport] can't be carried
+    /// over, set an async one with [with_transport] and the cache again with [with_cache].
     ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    /// [more]: https://trakt.docs.apiary.io/#reference/genres/get-genres
-    pub fn genres(&self, media_type: MediaType) -> Result<Vec<Genre>> {
-        self.get(api_url!(("genres", media_type.to_string())))
-    }
+    /// [Transport]: transport/trait.Transport.html
+    /// [with_transport]: ../asyn/struct.TraktApi.html#method.with_transport
+    /// [with_cache]: ../asyn/struct.TraktApi.html#method.with_cache
+    #[cfg(feature = "async")]
+    pub fn into_async(self) -> crate::asyn::TraktApi {
+        let mut api = crate::asyn::TraktApi::with_url(
+            &self.base_url,
+            self.client_id.to_string(),
+            self.client_secret.as_deref().map(str::to_owned),
+        )
+        .with_rate_limit(self.rate_limiter.config)
+        .with_retry_policy(self.retry_policy);
+
+        if let Some(cache) = self.cache {
+            api = api.with_cache(cache);
+        }
 
-    /// Get a Vec of all languages, including names and codes. [Trakt API][more]
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
-    ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    /// [more]: https://trakt.docs.apiary.io/#reference/genres/list/get-languages
-    pub fn languages(&self, media_type: MediaType) -> Result<Vec<Language>> {
-        self.get(api_url!(("languages", media_type.to_string())))
+        api.oauth_url = self.oauth_url;
+        api.middleware = self.middleware;
+        api
     }
+}
 
-    /// Get a Vec of all TV networks, including the name. [Trakt API][more]
-    ///
-    /// # Errors
-    ///
-    /// Returns [Error::Response] if the response contains an unsuccessful status code
-    ///
-    /// Returns [Error::Connection] if the connection failed
-    ///
-    /// Returns [Error::Serde] if the response could not be deserialized
-    ///
-    /// [Error::Response]: error/enum.Error.html#variant.Response
-    /// [Error::Connection]: error/enum.Error.html#variant.Connection
-    /// [Error::Serde]: error/enum.Error.html#variant.Serde
-    /// [more]: https://trakt.docs.apiary.io/#reference/networks/list/get-networks
-    pub fn networks(&self) -> Result<Vec<Network>> {
-        self.get(api_url!(("networks")))
+impl request::Client for TraktApi {
+    type Output<T> = Result<T>;
+
+    fn run<T: DeserializeOwned + Send + 'static>(&self, request: ApiRequest<T>) -> Result<T> {
+        self.execute_request(request)
     }
 
-    #[cfg(feature = "async")]
-    pub fn into_async(self) -> crate::asyn::TraktApi<'a> {
-        crate::asyn::TraktApi::with_url(self.base_url, self.client_id, self.client_secret)
+    fn run_page<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest<Vec<T>>,
+    ) -> Result<Page<T>> {
+        self.execute_request_page(request)
     }
 }
 
-impl<'a> PartialEq for TraktApi<'a> {
+impl PartialEq for TraktApi {
     fn eq(&self, other: &TraktApi) -> bool {
         self.client_id == other.client_id
             && self.client_secret == other.client_secret
             && self.base_url == other.base_url
+            && self.oauth_url == other.oauth_url
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::mock;
-    use crate::{error::Error, models::*, TraktApi};
+    #[cfg(feature = "async")]
+    use crate::cache::Cache;
+    use crate::tests::{mock, token};
+    use crate::{
+        error::{ApiErrorKind, Error},
+        middleware::{Middleware, MiddlewareChain, ResponseInfo},
+        models::*,
+        rate_limit::{RateLimitBucket, RateLimitConfig, RateLimiter},
+        request::ApiRequest,
+        retry::RetryPolicy,
+        route::Route,
+        sync::transport::ReqwestTransport,
+        transport::{HttpRequest, HttpResponse},
+        TraktApi,
+    };
+    use reqwest::{header::HeaderValue, Method};
+    use std::{
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc, Mutex,
+        },
+        thread,
+        time::Duration,
+    };
 
     #[test]
     fn new_trakt_api() {
         assert_eq!(
             TraktApi {
-                base_url: "https://api.trakt.tv",
-                client: reqwest::Client::new(),
-                client_id: String::from("abc"),
-                client_secret: Some(String::from("def")),
+                base_url: Arc::from("https://api.trakt.tv"),
+                oauth_url: Arc::from("https://trakt.tv"),
+                transport: Arc::new(ReqwestTransport::default()),
+                client_id: Arc::from("abc"),
+                client_secret: Some(Arc::from("def")),
+                rate_limiter: RateLimiter::default(),
+                retry_policy: RetryPolicy::default(),
+                cache: None,
+                middleware: MiddlewareChain::default(),
             },
             TraktApi::new(String::from("abc"), Some(String::from("def")))
         );
@@ -516,10 +342,15 @@ mod tests {
     fn staging_trakt_api() {
         assert_eq!(
             TraktApi {
-                base_url: "https://api-staging.trakt.tv",
-                client: reqwest::Client::new(),
-                client_id: String::from("abc"),
-                client_secret: Some(String::from("def")),
+                base_url: Arc::from("https://api-staging.trakt.tv"),
+                oauth_url: Arc::from("https://staging.trakt.tv"),
+                transport: Arc::new(ReqwestTransport::default()),
+                client_id: Arc::from("abc"),
+                client_secret: Some(Arc::from("def")),
+                rate_limiter: RateLimiter::default(),
+                retry_policy: RetryPolicy::default(),
+                cache: None,
+                middleware: MiddlewareChain::default(),
             },
             TraktApi::staging(String::from("abc"), Some(String::from("def")))
         );
@@ -672,4 +503,255 @@ mod tests {
                 Ok(())
             })
     }
+
+    #[test]
+    fn error_response() {
+        let m = mock("GET", "/genres/shows", "...")
+            .with_status(420)
+            .with_header("X-Account-Limit", "100")
+            .with_body("{\"error\":\"account_limit\"}")
+            .create();
+
+        match TraktApi::with_url(&mockito::server_url(), "...".to_owned(), None)
+            .genres(MediaType::Shows)
+        {
+            Err(Error::Response(e)) => {
+                assert_eq!(e.kind, ApiErrorKind::AccountLimitExceeded);
+                assert_eq!(e.status.as_u16(), 420);
+                assert_eq!(e.account_limit(), Some(100));
+                assert_eq!(e.body.unwrap().error, Some("account_limit".to_owned()));
+            }
+            res => panic!("expected an api error, got {:?}", res),
+        }
+
+        m.assert();
+    }
+
+    #[test]
+    fn malformed_body() {
+        let m = mock("GET", "/languages/shows", "...")
+            .with_status(200)
+            .with_body("[{\"name\":\"English\",\"code\":null}]")
+            .create();
+
+        match TraktApi::with_url(&mockito::server_url(), "...".to_owned(), None)
+            .languages(MediaType::Shows)
+        {
+            Err(Error::Deserialize(e)) => {
+                assert!(e.url.ends_with("/languages/shows"));
+                assert_eq!(e.path, "[0].code");
+                assert_eq!(e.body, "[{\"name\":\"English\",\"code\":null}]");
+            }
+            res => panic!("expected a deserialization error, got {:?}", res),
+        }
+
+        m.assert();
+    }
+
+    #[test]
+    fn rate_limit_retry() {
+        let m = mock("GET", "/countries/shows", "...")
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .with_header(
+                "X-Ratelimit",
+                "{\"name\":\"UNAUTHED_API_GET_LIMIT\",\"period\":300,\"limit\":1000,\"remaining\":0,\"until\":\"2020-10-10T00:24:00Z\"}",
+            )
+            .expect(3)
+            .create();
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(&url, "...".to_owned(), None)
+            .with_rate_limit(RateLimitConfig::new(2, 0));
+
+        match api.countries(MediaType::Shows) {
+            Err(Error::Response(e)) => assert_eq!(e.kind, ApiErrorKind::RateLimited),
+            res => panic!("expected an api error, got {:?}", res),
+        }
+
+        let limit = api.rate_limit(RateLimitBucket::UnauthedGet).unwrap();
+        assert_eq!(limit.name, "UNAUTHED_API_GET_LIMIT");
+        assert_eq!(limit.remaining, 0);
+        assert_eq!(api.rate_limit(RateLimitBucket::AuthedGet), None);
+
+        m.assert();
+    }
+
+    #[test]
+    fn retry_transient_error() {
+        let m = mock("GET", "/networks", "...")
+            .with_status(503)
+            .expect(3)
+            .create();
+
+        let retries = Arc::new(AtomicU32::new(0));
+        let counter = retries.clone();
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(&url, "...".to_owned(), None).with_retry_policy(
+            RetryPolicy::default()
+                .max_retries(2)
+                .initial_backoff(Duration::from_millis(10))
+                .on_retry(move |attempt| {
+                    assert_eq!(attempt.method, Method::GET);
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }),
+        );
+
+        match api.networks() {
+            Err(Error::Response(e)) => assert_eq!(e.kind, ApiErrorKind::ServerError),
+            res => panic!("expected an api error, got {:?}", res),
+        }
+
+        assert_eq!(retries.load(Ordering::SeqCst), 2);
+        m.assert();
+    }
+
+    #[test]
+    fn no_retry_mutating() {
+        let m = mock("DELETE", "/checkin", "...")
+            .with_status(503)
+            .expect(1)
+            .create();
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(&url, "...".to_owned(), None)
+            .with_retry_policy(RetryPolicy::default().initial_backoff(Duration::from_millis(10)));
+
+        assert!(api
+            .session(token(), "urn:ietf:wg:oauth:2.0:oob")
+            .checkout()
+            .is_err());
+        m.assert();
+    }
+
+    #[test]
+    fn retry_idempotent() {
+        let m = mock("DELETE", "/checkin", "...")
+            .with_status(503)
+            .expect(2)
+            .create();
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(&url, "...".to_owned(), None).with_retry_policy(
+            RetryPolicy::default()
+                .max_retries(1)
+                .initial_backoff(Duration::from_millis(10)),
+        );
+
+        assert!(api
+            .execute_request_no_body(ApiRequest::delete(Route::new("/checkin")).idempotent(true))
+            .is_err());
+        m.assert();
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        let m = mock("GET", "/networks", "...")
+            .with_status(200)
+            .with_body_from_file("mock_data/networks.json")
+            .expect(4)
+            .create();
+
+        let api = Arc::new(TraktApi::with_url(
+            &mockito::server_url(),
+            "...".to_owned(),
+            None,
+        ));
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let api = Arc::clone(&api);
+                thread::spawn(move || api.networks().map(|res| res.len()))
+            })
+            .collect();
+
+        for handle in handles {
+            assert!(handle.join().unwrap().unwrap() > 0);
+        }
+        m.assert();
+    }
+
+    #[test]
+    fn middleware() {
+        let m = mock("GET", "/networks", "...")
+            .match_header("X-Request-Id", "abc")
+            .with_status(503)
+            .expect(2)
+            .create();
+
+        let recorder = Arc::new(Recorder::default());
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(&url, "...".to_owned(), None)
+            .with_retry_policy(
+                RetryPolicy::default()
+                    .max_retries(1)
+                    .initial_backoff(Duration::from_millis(10)),
+            )
+            .with_middleware(recorder.clone());
+
+        assert!(api.networks().is_err());
+        assert_eq!(
+            *recorder.responses.lock().unwrap(),
+            vec![(0, 503), (1, 503)]
+        );
+        m.assert();
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn into_async() {
+        let m = mock("GET", "/networks", "...")
+            .match_header("X-Request-Id", "abc")
+            .with_status(200)
+            .with_body_from_file("mock_data/networks.json")
+            .expect(1)
+            .create();
+
+        let recorder = Arc::new(Recorder::default());
+
+        let url = mockito::server_url();
+        let api = TraktApi::with_url(&url, "...".to_owned(), None)
+            .with_cache(Cache::memory(10))
+            .with_middleware(recorder.clone())
+            .into_async();
+
+        // The blocking client of the sync api must not be dropped inside of the runtime
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            assert!(!api.networks().await.unwrap().is_empty());
+            assert!(!api.networks().await.unwrap().is_empty());
+        });
+
+        assert_eq!(
+            *recorder.responses.lock().unwrap(),
+            vec![(0, 200), (0, 200)]
+        );
+        m.assert();
+    }
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        responses: Mutex<Vec<(u32, u16)>>,
+    }
+
+    impl Middleware for Recorder {
+        fn before_send(&self, request: &mut HttpRequest, _retries: u32) {
+            request
+                .headers
+                .insert("X-Request-Id", HeaderValue::from_static("abc"));
+        }
+
+        fn after_receive(
+            &self,
+            _request: &HttpRequest,
+            response: &mut HttpResponse,
+            info: &ResponseInfo,
+        ) {
+            self.responses
+                .lock()
+                .unwrap()
+                .push((info.retries, response.status.as_u16()));
+        }
+    }
 }
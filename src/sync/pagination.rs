@@ -1,36 +1,84 @@
-use crate::{pagination::Pagination, sync::Result, TraktApi};
-use reqwest::RequestBuilder;
+use crate::{
+    error::Error,
+    pagination::{Page, PageCursor, Pagination},
+    request::ApiRequest,
+    sync::Result,
+    transport::HttpRequest,
+    Session, TraktApi,
+};
 use serde::de::DeserializeOwned;
-use std::marker::PhantomData;
+use std::vec;
 
 /// A simple implementation of [Pagination]
 ///
 /// [Pagination]: ../trait.Pagination.html
-pub struct PaginationRequest<'a, T> {
-    client: &'a TraktApi<'a>,
-    request: RequestBuilder,
-    response_type: PhantomData<T>,
+pub struct PaginationRequest<T> {
+    client: PageClient,
+    request: ApiRequest<Vec<T>>,
 }
 
-impl<'a, T: DeserializeOwned> PaginationRequest<'a, T> {
-    pub fn new(client: &'a TraktApi, request: RequestBuilder) -> Self {
+impl<T: DeserializeOwned> PaginationRequest<T> {
+    pub fn new(client: &TraktApi, request: ApiRequest<Vec<T>>) -> Self {
         Self {
-            client,
+            client: PageClient::Api(client.clone()),
             request,
-            response_type: PhantomData,
         }
     }
 
-    fn apply(self, f: impl FnOnce(RequestBuilder) -> RequestBuilder) -> Self {
+    /// Creates a request whose pages are each sent with a valid access token of `session`
+    pub(crate) fn with_session(session: &Session, request: ApiRequest<Vec<T>>) -> Self {
         Self {
-            client: self.client,
-            request: f(self.request),
-            response_type: PhantomData,
+            client: PageClient::Session(session.clone()),
+            request,
         }
     }
 
     pub fn execute(self) -> Result<Vec<T>> {
-        self.client.execute(self.request.build()?)
+        match self.client {
+            PageClient::Api(api) => api.execute_request(self.request),
+            PageClient::Session(session) => session.execute_request(self.request),
+        }
+    }
+
+    /// Executes the request and returns the items together with the pagination headers
+    pub fn execute_page(self) -> Result<Page<T>> {
+        match self.client {
+            PageClient::Api(api) => api.execute_request_page(self.request),
+            PageClient::Session(session) => session.execute_request_page(self.request),
+        }
+    }
+
+    /// Returns an iterator over the items of all pages, starting at the requested page.
+    /// Pages are fetched lazily.
+    pub fn into_iter_all(self) -> PaginationIter<T> {
+        let request = self.client.api().request(&self.request);
+        PaginationIter::new(self.client, request)
+    }
+}
+
+/// The client the pages of a paginated request are sent with
+#[derive(Clone)]
+enum PageClient {
+    Api(TraktApi),
+    /// Every page gets the current access token of the session and is sent once more with a
+    /// refreshed one if it is rejected
+    Session(Session),
+}
+
+impl PageClient {
+    fn api(&self) -> &TraktApi {
+        match self {
+            PageClient::Api(api) => api,
+            PageClient::Session(session) => session.client(),
+        }
+    }
+
+    fn execute_page<T: DeserializeOwned>(&self, request: HttpRequest) -> Result<Page<T>> {
+        match self {
+            PageClient::Api(api) => api.execute_page(request),
+            PageClient::Session(session) => session
+                .call(|api, access_token| api.execute_page(request.clone().auth(access_token)?)),
+        }
     }
 }
 
@@ -38,14 +86,96 @@ impl<'a, T: DeserializeOwned> PaginationRequest<'a, T> {
 ///
 /// [Pagination]: ../trait.Pagination.html
 /// [PaginationFactory]: struct.PaginationFactory.html
-impl<'a, T: DeserializeOwned> Pagination for PaginationRequest<'a, T> {
+impl<T: DeserializeOwned> Pagination for PaginationRequest<T> {
     /// Set requested page
-    fn page(self, page: u32) -> Self {
-        self.apply(|b| b.query(&[("page", page)]))
+    fn page(mut self, page: u32) -> Self {
+        self.request = self.request.query("page", page);
+        self
     }
 
     /// Set number of requested entries
-    fn limit(self, limit: u32) -> Self {
-        self.apply(|b| b.query(&[("limit", limit)]))
+    fn limit(mut self, limit: u32) -> Self {
+        self.request = self.request.query("limit", limit);
+        self
+    }
+}
+
+/// An [Iterator] over the items of all pages of a paginated request.
+/// The next page is only fetched after all items of the previous one have been consumed.
+///
+/// [Iterator]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+pub struct PaginationIter<T> {
+    client: PageClient,
+    request: Option<HttpRequest>,
+    error: Option<Error>,
+    items: vec::IntoIter<T>,
+    cursor: PageCursor,
+}
+
+impl<T: DeserializeOwned> PaginationIter<T> {
+    fn new(client: PageClient, request: Result<HttpRequest>) -> Self {
+        let (request, error, cursor) = match request {
+            Ok(request) => {
+                let cursor = PageCursor::new(&request.url);
+                (Some(request), None, cursor)
+            }
+            Err(e) => (None, Some(e), PageCursor::empty()),
+        };
+
+        Self {
+            client,
+            request,
+            error,
+            items: Vec::new().into_iter(),
+            cursor,
+        }
+    }
+
+    /// Stops after the given number of pages
+    pub fn max_pages(mut self, max_pages: u32) -> Self {
+        self.cursor.max_pages(max_pages);
+        self
+    }
+
+    /// Stops after the given number of items
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.cursor.max_items(max_items);
+        self
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for PaginationIter<T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.error.take() {
+            return Some(Err(e));
+        }
+
+        loop {
+            if let Some(item) = self.items.next() {
+                return if self.cursor.take_item() {
+                    Some(Ok(item))
+                } else {
+                    None
+                };
+            }
+
+            let template = self.request.as_ref()?;
+            let url = self.cursor.next_url(&template.url)?;
+            let mut request = template.clone();
+            request.url = url;
+
+            match self.client.execute_page(request) {
+                Ok(page) => {
+                    self.cursor.fetched(&page);
+                    self.items = page.items.into_iter();
+                }
+                Err(e) => {
+                    self.cursor.failed();
+                    return Some(Err(e));
+                }
+            }
+        }
     }
 }
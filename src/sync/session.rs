@@ -0,0 +1,454 @@
+use crate::{
+    error::{ApiErrorKind, Error},
+    models::AuthenticationTokenResponse,
+    pagination::Page,
+    request::{self, ApiRequest},
+    sync::pagination::PaginationRequest,
+    token_store::TokenStore,
+    Result, TraktApi,
+};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// An authenticated session of a user.
+///
+/// It holds the OAuth tokens of the user and passes the access token to every request.
+/// The access token is refreshed shortly before it expires and once after a request was
+/// rejected with a 401 response. Clones share the tokens.
+/// Refreshed tokens are saved in the [TokenStore] of the session, if it has one.
+///
+/// Endpoints and request builders which require authentication, like [user_settings] or
+/// [checkin], are only methods of the session. Endpoints which accept it optionally, like
+/// [user_profile], are sent with the access token by the session and without one by the
+/// [TraktApi]. Other requests can be sent with [call].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use trakt::{
+///     selectors::{SelectIds, SelectMovie},
+///     TraktApi,
+/// };
+///
+/// let api = TraktApi::new("CLIENT_ID".to_owned(), Some("CLIENT_SECRET".to_owned()));
+/// let redirect_uri = "urn:ietf:wg:oauth:2.0:oob";
+/// let token = api.oauth_get_token("CODE", redirect_uri).unwrap();
+///
+/// let session = api.session(token, redirect_uri);
+/// dbg!(session.user_settings().unwrap());
+/// dbg!(session
+///     .checkin()
+///     .movie(|m| m.slug("warcraft-2016"))
+///     .execute()
+///     .unwrap());
+/// ```
+///
+/// [TokenStore]: ../token_store/trait.TokenStore.html
+/// [user_settings]: #method.user_settings
+/// [checkin]: #method.checkin
+/// [user_profile]: #method.user_profile
+/// [TraktApi]: ../struct.TraktApi.html
+/// [call]: #method.call
+#[derive(Debug, Clone)]
+pub struct Session {
+    client: TraktApi,
+    redirect_uri: Arc<str>,
+    token: Arc<Mutex<AuthenticationTokenResponse>>,
+    store: Option<Arc<dyn TokenStore>>,
+    refresh_margin: Duration,
+}
+
+impl Session {
+    /// Creates a session from a token response. The redirect uri has to be the one the token
+    /// was requested with.
+    pub fn new(client: TraktApi, token: AuthenticationTokenResponse, redirect_uri: &str) -> Self {
+        Self {
+            client,
+            redirect_uri: Arc::from(redirect_uri),
+            token: Arc::new(Mutex::new(token)),
+            store: None,
+            refresh_margin: Duration::from_secs(60 * 60),
+        }
+    }
+
+    /// Creates a session from the token saved in `store`. Returns None if there is none.
+    /// Refreshed tokens are saved in `store`.
+    pub fn from_store(
+        client: TraktApi,
+        store: impl TokenStore + 'static,
+        redirect_uri: &str,
+    ) -> Result<Option<Self>> {
+        Ok(store
+            .load()?
+            .map(|token| Self::new(client, token, redirect_uri).token_store(store)))
+    }
+
+    /// Saves every refreshed token in `store`. The current token is not saved.
+    pub fn token_store(mut self, store: impl TokenStore + 'static) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+
+    /// Sets how long before its expiry the access token is refreshed. Defaults to one hour.
+    pub fn refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    /// The client the requests are sent with
+    pub fn client(&self) -> &TraktApi {
+        &self.client
+    }
+
+    /// The current token
+    pub fn token(&self) -> AuthenticationTokenResponse {
+        self.token.lock().unwrap().clone()
+    }
+
+    /// The time at which the current access token expires
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.token.lock().unwrap().expires_at()
+    }
+
+    /// Returns a valid access token. It is refreshed first if it is about to expire.
+    pub fn access_token(&self) -> Result<String> {
+        let mut token = self.token.lock().unwrap();
+
+        if token.expires_within(self.refresh_margin) {
+            self.refresh_token(&mut token)?;
+        }
+
+        Ok(token.access_token.clone())
+    }
+
+    /// Refreshes the access token
+    pub fn refresh(&self) -> Result<()> {
+        let mut token = self.token.lock().unwrap();
+
+        self.refresh_token(&mut token)
+    }
+
+    /// Revokes the access token and removes it from the token store
+    pub fn revoke(&self) -> Result<()> {
+        let token = self.token.lock().unwrap();
+
+        self.client.oauth_revoke_token(&token.access_token)?;
+
+        match &self.store {
+            Some(store) => store.clear(),
+            None => Ok(()),
+        }
+    }
+
+    /// Replaces `token` with a refreshed one and saves it in the token store
+    fn refresh_token(&self, token: &mut AuthenticationTokenResponse) -> Result<()> {
+        *token = self
+            .client
+            .oauth_refresh_token(&token.refresh_token, &self.redirect_uri)?;
+
+        match &self.store {
+            Some(store) => store.save(token),
+            None => Ok(()),
+        }
+    }
+
+    /// Refreshes the access token after `rejected` was rejected,
+    /// unless another request replaced it in the meantime
+    fn refresh_rejected(&self, rejected: &str) -> Result<String> {
+        let mut token = self.token.lock().unwrap();
+
+        if token.access_token == rejected {
+            self.refresh_token(&mut token)?;
+        }
+
+        Ok(token.access_token.clone())
+    }
+
+    /// Calls `f` with the client and a valid access token.
+    /// If the request is rejected with a 401 response, the token is refreshed and `f` is called
+    /// once more.
+    pub fn call<T>(&self, f: impl Fn(&TraktApi, &str) -> Result<T>) -> Result<T> {
+        let access_token = self.access_token()?;
+
+        match f(&self.client, &access_token) {
+            Err(Error::Response(ref e)) if e.kind == ApiErrorKind::Unauthorized => {
+                let access_token = self.refresh_rejected(&access_token)?;
+                f(&self.client, &access_token)
+            }
+            res => res,
+        }
+    }
+}
+
+/// Executes the requests of the endpoints which need an access token
+impl Session {
+    /// Executes an [ApiRequest] with the access token of the session
+    ///
+    /// [ApiRequest]: ../request/struct.ApiRequest.html
+    pub(crate) fn execute_request<T: DeserializeOwned>(&self, request: ApiRequest<T>) -> Result<T> {
+        self.call(|api, access_token| api.execute_request(request.clone().auth(access_token)))
+    }
+
+    /// Executes an [ApiRequest] of a paginated endpoint with the access token of the session
+    ///
+    /// [ApiRequest]: ../request/struct.ApiRequest.html
+    pub(crate) fn execute_request_page<T: DeserializeOwned>(
+        &self,
+        request: ApiRequest<Vec<T>>,
+    ) -> Result<Page<T>> {
+        self.call(|api, access_token| api.execute_request_page(request.clone().auth(access_token)))
+    }
+
+    /// Executes an [ApiRequest] with the access token of the session and ignores the body of
+    /// the response
+    ///
+    /// [ApiRequest]: ../request/struct.ApiRequest.html
+    pub(crate) fn execute_request_no_body(&self, request: ApiRequest<()>) -> Result<()> {
+        self.call(|api, access_token| {
+            api.execute_request_no_body(request.clone().auth(access_token))
+        })
+    }
+
+    /// Creates a [PaginationRequest] whose pages are each sent with a valid access token
+    /// of the session
+    ///
+    /// [PaginationRequest]: ../pagination/struct.PaginationRequest.html
+    pub(crate) fn paginate<T: DeserializeOwned>(
+        &self,
+        request: ApiRequest<Vec<T>>,
+    ) -> Result<PaginationRequest<T>> {
+        Ok(PaginationRequest::with_session(self, request))
+    }
+}
+
+impl request::Client for Session {
+    type Output<T> = Result<T>;
+
+    fn run<T: DeserializeOwned + Send + 'static>(&self, request: ApiRequest<T>) -> Result<T> {
+        self.execute_request(request)
+    }
+
+    fn run_page<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest<Vec<T>>,
+    ) -> Result<Page<T>> {
+        self.execute_request_page(request)
+    }
+}
+
+impl TraktApi {
+    /// Creates a [Session] which passes the access token of `token` to the requests
+    /// and refreshes it when needed
+    ///
+    /// [Session]: struct.Session.html
+    pub fn session(&self, token: AuthenticationTokenResponse, redirect_uri: &str) -> Session {
+        Session::new(self.clone(), token, redirect_uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{ApiErrorKind, Error},
+        models::AuthenticationTokenResponse,
+        pagination::Pagination,
+        tests::mock,
+        token_store::{MemoryTokenStore, TokenStore},
+        Session, TraktApi,
+    };
+    use mockito::{server_url, Matcher};
+    use std::{
+        fs,
+        sync::Arc,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    const NEW_TOKEN: &str = "dbaf9757982a9e738f05d249b7b5b4a266b3a139049317c4909f2f263572c781";
+
+    fn token(created_at: u64) -> AuthenticationTokenResponse {
+        AuthenticationTokenResponse {
+            access_token: "ACCESS_TOKEN".to_owned(),
+            token_type: "bearer".to_owned(),
+            expires_in: 7200,
+            refresh_token: "REFRESH_TOKEN".to_owned(),
+            scope: "public".to_owned(),
+            created_at,
+        }
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    fn refresh_mock() -> mockito::Mock {
+        mock("POST", "/oauth/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::JsonString(
+                fs::read_to_string("mock_data/oauth_refresh_token_req.json").unwrap(),
+            ))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+    }
+
+    fn api(url: &str) -> TraktApi {
+        TraktApi::with_url(
+            url,
+            "CLIENT_ID".to_owned(),
+            Some("CLIENT_SECRET".to_owned()),
+        )
+    }
+
+    #[test]
+    fn token_expiry() {
+        let token = token(now());
+
+        assert!(!token.expires_within(Duration::from_secs(60 * 60)));
+        assert!(token.expires_within(Duration::from_secs(3 * 60 * 60)));
+        assert_eq!(
+            token.expires_at().timestamp() as u64,
+            token.created_at + 7200
+        );
+    }
+
+    #[test]
+    fn refresh_before_expiry() {
+        let refresh = refresh_mock().expect(1).create();
+        let m = mock("GET", "/recommendations/movies", "CLIENT_ID")
+            .match_header("Authorization", format!("Bearer {}", NEW_TOKEN).as_str())
+            .with_status(200)
+            .with_body_from_file("mock_data/movies.json")
+            .create();
+
+        let url = server_url();
+        let session = api(&url).session(token(now() - 7000), "http://localhost:8080/auth");
+
+        assert!(!session.recommendations_movie().unwrap().is_empty());
+        refresh.assert();
+        m.assert();
+    }
+
+    #[test]
+    fn refresh_without_client_secret() {
+        let refresh = mock("POST", "/oauth/token", "CLIENT_ID")
+            .with_status(200)
+            .match_body(Matcher::Json(json!({
+                "refresh_token": "REFRESH_TOKEN",
+                "client_id": "CLIENT_ID",
+                "redirect_uri": "http://localhost:8080/auth",
+                "grant_type": "refresh_token"
+            })))
+            .with_body_from_file("mock_data/oauth_get_token.json")
+            .expect(1)
+            .create();
+
+        let url = server_url();
+        let session = TraktApi::with_url(&url, "CLIENT_ID".to_owned(), None)
+            .session(token(now() - 7000), "http://localhost:8080/auth");
+
+        assert_eq!(session.access_token().unwrap(), NEW_TOKEN);
+        refresh.assert();
+    }
+
+    #[test]
+    fn refresh_after_unauthorized() {
+        let refresh = refresh_mock().expect(1).create();
+        let rejected = mock("GET", "/sync/last_activities", "CLIENT_ID")
+            .match_header("Authorization", "Bearer ACCESS_TOKEN")
+            .with_status(401)
+            .expect(1)
+            .create();
+        let m = mock("GET", "/sync/last_activities", "CLIENT_ID")
+            .match_header("Authorization", format!("Bearer {}", NEW_TOKEN).as_str())
+            .with_status(401)
+            .expect(1)
+            .create();
+
+        let url = server_url();
+        let session = api(&url).session(token(now()), "http://localhost:8080/auth");
+
+        match session.sync_last_activities() {
+            Err(Error::Response(e)) => assert_eq!(e.kind, ApiErrorKind::Unauthorized),
+            res => panic!("expected an api error, got {:?}", res),
+        }
+
+        refresh.assert();
+        rejected.assert();
+        m.assert();
+    }
+
+    #[test]
+    fn refresh_between_pages() {
+        let refresh = refresh_mock().expect(1).create();
+        let page = |page: u32, access_token: &str| {
+            mock(
+                "GET",
+                &format!("/lists/123/items?limit=5&page={}", page),
+                "CLIENT_ID",
+            )
+            .match_header("Authorization", format!("Bearer {}", access_token).as_str())
+            .with_header("X-Pagination-Page", &page.to_string())
+            .with_header("X-Pagination-Limit", "5")
+            .with_header("X-Pagination-Page-Count", "2")
+            .with_header("X-Pagination-Item-Count", "10")
+        };
+        let first = page(1, "ACCESS_TOKEN")
+            .with_status(200)
+            .with_body_from_file("mock_data/user_list_items.json")
+            .expect(1)
+            .create();
+        let rejected = page(2, "ACCESS_TOKEN").with_status(401).expect(1).create();
+        let second = page(2, NEW_TOKEN)
+            .with_status(200)
+            .with_body_from_file("mock_data/user_list_items.json")
+            .expect(1)
+            .create();
+
+        let url = server_url();
+        let session = api(&url).session(token(now()), "http://localhost:8080/auth");
+
+        let items = session
+            .list_items(123, None)
+            .unwrap()
+            .limit(5)
+            .into_iter_all()
+            .collect::<Result<Vec<_>, Error>>()
+            .unwrap();
+
+        assert!(!items.is_empty());
+        assert_eq!(session.token().access_token, NEW_TOKEN);
+        refresh.assert();
+        first.assert();
+        rejected.assert();
+        second.assert();
+    }
+
+    #[test]
+    fn save_refreshed_token() {
+        let refresh = refresh_mock().expect(1).create();
+        let m = mock("GET", "/recommendations/movies", "CLIENT_ID")
+            .match_header("Authorization", format!("Bearer {}", NEW_TOKEN).as_str())
+            .with_status(200)
+            .with_body_from_file("mock_data/movies.json")
+            .create();
+
+        let url = server_url();
+        let store = Arc::new(MemoryTokenStore::new(Some(token(now() - 7000))));
+        let session =
+            Session::from_store(api(&url), Arc::clone(&store), "http://localhost:8080/auth")
+                .unwrap()
+                .unwrap();
+
+        session.recommendations_movie().unwrap();
+
+        assert_eq!(store.load().unwrap(), Some(session.token()));
+        assert_eq!(session.token().access_token, NEW_TOKEN);
+        refresh.assert();
+        m.assert();
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{
+    cache::{Cache, Lookup},
+    error::Error,
+    sync::Result,
+    transport::{HttpRequest, HttpResponse},
+};
+use reqwest::blocking::Client;
+use std::{fmt, sync::Arc};
+
+/// Sends the requests of a [TraktApi].
+///
+/// Implement it to run the client over another HTTP stack or to answer requests without a
+/// network, e.g. in tests. Return [Error::Connection] or [Error::Io] if the request couldn't
+/// be sent, timeouts and refused connections are retried then.
+///
+/// # Example
+///
+/// ```rust
+/// use reqwest::StatusCode;
+/// use trakt::{
+///     sync::transport::Transport,
+///     transport::{HttpRequest, HttpResponse},
+///     Result, TraktApi,
+/// };
+///
+/// #[derive(Debug)]
+/// struct Logging<T>(T);
+///
+/// impl<T: Transport> Transport for Logging<T> {
+///     fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+///         println!("{} {}", request.method, request.url);
+///         self.0.send(request)
+///     }
+/// }
+///
+/// #[derive(Debug)]
+/// struct Fake;
+///
+/// impl Transport for Fake {
+///     fn send(&self, _request: HttpRequest) -> Result<HttpResponse> {
+///         Ok(HttpResponse::new(StatusCode::OK, b"[]".to_vec()))
+///     }
+/// }
+///
+/// let api = TraktApi::new("...".to_owned(), None).with_transport(Logging(Fake));
+/// ```
+///
+/// [TraktApi]: ../struct.TraktApi.html
+/// [Error::Connection]: ../../error/enum.Error.html#variant.Connection
+/// [Error::Io]: ../../error/enum.Error.html#variant.Io
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Sends the request and returns the response, whatever its status code
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
+
+/// The default [Transport] using a [reqwest::blocking::Client]
+///
+/// [Transport]: trait.Transport.html
+/// [reqwest::blocking::Client]: ../../reqwest/blocking/struct.Client.html
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let mut builder = self
+            .client
+            .request(request.method, request.url)
+            .headers(request.headers);
+
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let mut res = builder.send()?;
+        let mut body = Vec::new();
+
+        if let Err(e) = res.copy_to(&mut body) {
+            // the body of an error is only used for details, it should not hide the status code
+            if res.status().is_success() {
+                return Err(Error::from(e));
+            }
+        }
+
+        Ok(HttpResponse {
+            status: res.status(),
+            headers: res.headers().clone(),
+            body,
+        })
+    }
+}
+
+/// A [Transport] answering requests from a [Cache] before sending them over another one
+///
+/// [Transport]: trait.Transport.html
+/// [Cache]: ../../cache/struct.Cache.html
+#[derive(Debug)]
+pub struct CachingTransport<T> {
+    transport: T,
+    cache: Cache,
+}
+
+impl<T> CachingTransport<T> {
+    pub fn new(transport: T, cache: Cache) -> Self {
+        Self { transport, cache }
+    }
+}
+
+impl<T: Transport> Transport for CachingTransport<T> {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        match self.cache.lookup(request) {
+            Lookup::Hit(res) => Ok(res),
+            Lookup::Miss(request, pending) => {
+                let res = self.transport.send(request)?;
+                Ok(self.cache.update(pending, res))
+            }
+            Lookup::Bypass(request) => self.transport.send(request),
+        }
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        (**self).send(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Transport;
+    use crate::{
+        cache::Cache,
+        error::Error,
+        models::{Genre, MediaType},
+        transport::{HttpRequest, HttpResponse},
+        Result, TraktApi,
+    };
+    use reqwest::{Method, StatusCode};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Default)]
+    struct Fake {
+        requests: Arc<Mutex<Vec<HttpRequest>>>,
+    }
+
+    impl Transport for Fake {
+        fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+            self.requests.lock().unwrap().push(request);
+
+            Ok(HttpResponse::new(
+                StatusCode::OK,
+                br#"[{"name": "Action", "slug": "action"}]"#.to_vec(),
+            ))
+        }
+    }
+
+    #[derive(Debug)]
+    struct Status<T>(StatusCode, T);
+
+    impl<T: Transport> Transport for Status<T> {
+        fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+            let mut res = self.1.send(request)?;
+            res.status = self.0;
+            Ok(res)
+        }
+    }
+
+    #[test]
+    fn fake_transport() -> std::result::Result<(), Error> {
+        let fake = Fake::default();
+        let requests = fake.requests.clone();
+
+        let genres = TraktApi::new("CLIENT_ID".to_owned(), None)
+            .with_transport(fake)
+            .genres(MediaType::Movies)?;
+
+        assert_eq!(
+            genres,
+            vec![Genre {
+                name: "Action".to_owned(),
+                slug: "action".to_owned()
+            }]
+        );
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::GET);
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://api.trakt.tv/genres/movies"
+        );
+        assert_eq!(requests[0].headers["trakt-api-key"], "CLIENT_ID");
+        assert_eq!(requests[0].body, None);
+        Ok(())
+    }
+
+    #[test]
+    fn layered_transport() {
+        let fake = Fake::default();
+        let requests = fake.requests.clone();
+
+        let res = TraktApi::new("CLIENT_ID".to_owned(), None)
+            .with_transport(Status(StatusCode::NOT_FOUND, fake))
+            .genres(MediaType::Movies);
+
+        match res {
+            Err(Error::Response(e)) => assert_eq!(e.status, StatusCode::NOT_FOUND),
+            res => panic!("expected a response error, got {:?}", res),
+        }
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn caching_transport() -> std::result::Result<(), Error> {
+        let fake = Fake::default();
+        let requests = fake.requests.clone();
+
+        let api = TraktApi::new("CLIENT_ID".to_owned(), None)
+            .with_transport(fake)
+            .with_cache(Cache::memory(10));
+
+        assert_eq!(
+            api.genres(MediaType::Movies)?,
+            api.genres(MediaType::Movies)?
+        );
+        api.genres(MediaType::Shows)?;
+
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        Ok(())
+    }
+}
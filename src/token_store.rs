@@ -0,0 +1,246 @@
+//! Persistence of the OAuth tokens of a [Session]
+//!
+//! [Session]: ../struct.Session.html
+use crate::{error::Error, models::AuthenticationTokenResponse, oauth::random_hex};
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+/// A place to keep the OAuth tokens between runs.
+///
+/// A session saves every refreshed token in its store, since the old refresh token
+/// can't be used anymore.
+pub trait TokenStore: fmt::Debug + Send + Sync {
+    /// Returns the saved token or None if there is none
+    fn load(&self) -> Result<Option<AuthenticationTokenResponse>, Error>;
+
+    /// Saves the token, replacing the saved one
+    fn save(&self, token: &AuthenticationTokenResponse) -> Result<(), Error>;
+
+    /// Removes the saved token
+    fn clear(&self) -> Result<(), Error>;
+}
+
+/// Saves the token as JSON in a file.
+///
+/// The file is replaced atomically and on unix only readable by its owner.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use trakt::{token_store::{FileTokenStore, TokenStore}, TraktApi};
+///
+/// let api = TraktApi::new("CLIENT_ID".to_owned(), Some("CLIENT_SECRET".to_owned()));
+/// let store = FileTokenStore::new("token.json");
+///
+/// let session = match store.load().unwrap() {
+///     Some(token) => api.session(token, "urn:ietf:wg:oauth:2.0:oob"),
+///     None => {
+///         let token = api
+///             .oauth_get_token("CODE", "urn:ietf:wg:oauth:2.0:oob")
+///             .unwrap();
+///         store.save(&token).unwrap();
+///         api.session(token, "urn:ietf:wg:oauth:2.0:oob")
+///     }
+/// }
+/// .token_store(store);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileTokenStore {
+    path: PathBuf,
+}
+
+impl FileTokenStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// The file the token is saved in
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// A new file the token is written to before it replaces the saved one. The name is
+    /// unique, so concurrent saves and files left by crashed ones don't get in the way.
+    fn temp_path(&self) -> PathBuf {
+        let mut name = self.path.file_name().unwrap_or_default().to_owned();
+        name.push(format!(".{}.tmp", random_hex(16)));
+        self.path.with_file_name(name)
+    }
+
+    /// Writes the token to `temp_path` and replaces the saved one with it
+    fn replace(&self, temp_path: &Path, token: &AuthenticationTokenResponse) -> Result<(), Error> {
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(temp_path)?;
+        file.write_all(&serde_json::to_vec_pretty(token)?)?;
+
+        // The mode of a new file is masked by the umask, so set it explicitly
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(fs::Permissions::from_mode(0o600))?;
+        }
+
+        file.sync_all()?;
+        fs::rename(temp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self) -> Result<Option<AuthenticationTokenResponse>, Error> {
+        match fs::read(&self.path) {
+            Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    fn save(&self, token: &AuthenticationTokenResponse) -> Result<(), Error> {
+        let temp_path = self.temp_path();
+
+        self.replace(&temp_path, token).inspect_err(|_| {
+            let _ = fs::remove_file(&temp_path);
+        })
+    }
+
+    fn clear(&self) -> Result<(), Error> {
+        match fs::remove_file(&self.path) {
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            res => res.map_err(Error::from),
+        }
+    }
+}
+
+/// Keeps the token in memory. Useful for tests.
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    token: Mutex<Option<AuthenticationTokenResponse>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new(token: Option<AuthenticationTokenResponse>) -> Self {
+        Self {
+            token: Mutex::new(token),
+        }
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self) -> Result<Option<AuthenticationTokenResponse>, Error> {
+        Ok(self.token.lock().unwrap().clone())
+    }
+
+    fn save(&self, token: &AuthenticationTokenResponse) -> Result<(), Error> {
+        *self.token.lock().unwrap() = Some(token.clone());
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(), Error> {
+        *self.token.lock().unwrap() = None;
+        Ok(())
+    }
+}
+
+impl<T: TokenStore + ?Sized> TokenStore for Arc<T> {
+    fn load(&self) -> Result<Option<AuthenticationTokenResponse>, Error> {
+        (**self).load()
+    }
+
+    fn save(&self, token: &AuthenticationTokenResponse) -> Result<(), Error> {
+        (**self).save(token)
+    }
+
+    fn clear(&self) -> Result<(), Error> {
+        (**self).clear()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        models::AuthenticationTokenResponse,
+        token_store::{FileTokenStore, MemoryTokenStore, TokenStore},
+    };
+    use std::{env, fs, process};
+
+    fn token() -> AuthenticationTokenResponse {
+        serde_json::from_str(&fs::read_to_string("mock_data/oauth_get_token.json").unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn token_round_trip() {
+        let token = token();
+        let json = serde_json::to_string(&token).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<AuthenticationTokenResponse>(&json).unwrap(),
+            token
+        );
+    }
+
+    #[test]
+    fn file_token_store() {
+        let path = env::temp_dir().join(format!("trakt-token-{}.json", process::id()));
+        let store = FileTokenStore::new(&path);
+
+        assert_eq!(store.load().unwrap(), None);
+
+        store.save(&token()).unwrap();
+        assert_eq!(store.load().unwrap(), Some(token()));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+
+            // A readable file left by a crashed save isn't reused
+            let stale = path.with_extension("json.tmp");
+            fs::write(&stale, "{}").unwrap();
+            fs::set_permissions(&stale, fs::Permissions::from_mode(0o644)).unwrap();
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+            store.save(&token()).unwrap();
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+            fs::remove_file(&stale).unwrap();
+        }
+
+        let prefix = path.file_name().unwrap().to_str().unwrap().to_owned();
+        let temp_files = fs::read_dir(env::temp_dir())
+            .unwrap()
+            .filter_map(|entry| entry.unwrap().file_name().into_string().ok())
+            .filter(|name| name.starts_with(&prefix) && name.ends_with(".tmp"))
+            .count();
+        assert_eq!(temp_files, 0);
+
+        store.clear().unwrap();
+        assert!(!path.exists());
+        store.clear().unwrap();
+    }
+
+    #[test]
+    fn memory_token_store() {
+        let store = MemoryTokenStore::default();
+
+        assert_eq!(store.load().unwrap(), None);
+        store.save(&token()).unwrap();
+        assert_eq!(store.load().unwrap(), Some(token()));
+        store.clear().unwrap();
+        assert_eq!(store.load().unwrap(), None);
+    }
+}
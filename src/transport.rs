@@ -0,0 +1,149 @@
+//! The HTTP layer below the clients
+//!
+//! The clients turn every [ApiRequest] into an [HttpRequest] and hand it to a transport, which
+//! sends it and returns the [HttpResponse]. The transport traits are [sync::Transport] and
+//! [asyn::Transport]. By default reqwest is used.
+//!
+//! Since a transport sees the complete request, it can also wrap another transport, e.g. to
+//! log requests or to answer them from a cache.
+//!
+//! [ApiRequest]: ../request/struct.ApiRequest.html
+//! [HttpRequest]: struct.HttpRequest.html
+//! [HttpResponse]: struct.HttpResponse.html
+//! [sync::Transport]: ../sync/transport/trait.Transport.html
+//! [asyn::Transport]: ../asyn/transport/trait.Transport.html
+use crate::{error::Error, middleware::RedactedHeaders, request::ApiRequest};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+    Method, StatusCode, Url,
+};
+use std::fmt;
+
+/// A request with everything needed to send it.
+///
+/// Its `Debug` output doesn't contain the access token and client id.
+#[derive(Clone, PartialEq)]
+pub struct HttpRequest {
+    pub method: Method,
+    /// The absolute url including the query
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+    /// Whether the request is retried even if it isn't a GET request
+    pub idempotent: bool,
+}
+
+impl HttpRequest {
+    /// Builds the request to send for an [ApiRequest], adding the headers trakt expects
+    ///
+    /// [ApiRequest]: ../request/struct.ApiRequest.html
+    pub(crate) fn new<T>(
+        base_url: &str,
+        client_id: &str,
+        request: &ApiRequest<T>,
+    ) -> Result<Self, Error> {
+        if request.is_incomplete() {
+            return Err(Error::NoneError);
+        }
+
+        let mut url = Url::parse(&format!("{}{}", base_url, request.route()))?;
+
+        if !request.query_pairs().is_empty() {
+            url.query_pairs_mut().extend_pairs(request.query_pairs());
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert("trakt-api-version", HeaderValue::from_static("2"));
+        headers.insert("trakt-api-key", HeaderValue::from_str(client_id)?);
+
+        if let Some(access_token) = request.access_token() {
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", access_token))?,
+            );
+        }
+
+        Ok(Self {
+            method: request.method().clone(),
+            url,
+            headers,
+            body: request.body().map(|body| body.as_bytes().to_vec()),
+            idempotent: request.is_idempotent(),
+        })
+    }
+
+    /// Replaces the access token the request is sent with
+    pub(crate) fn auth(mut self, access_token: &str) -> Result<Self, Error> {
+        self.headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {}", access_token))?,
+        );
+        Ok(self)
+    }
+}
+
+impl fmt::Debug for HttpRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HttpRequest")
+            .field("method", &self.method)
+            .field("url", &self.url)
+            .field("headers", &RedactedHeaders(&self.headers))
+            .field(
+                "body",
+                &self.body.as_ref().map(|body| String::from_utf8_lossy(body)),
+            )
+            .field("idempotent", &self.idempotent)
+            .finish()
+    }
+}
+
+/// The response to an [HttpRequest]. Unsuccessful status codes are not an error at this level.
+///
+/// [HttpRequest]: struct.HttpRequest.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Creates a response without headers
+    pub fn new(status: StatusCode, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HttpRequest;
+    use crate::{request::ApiRequest, route::Route};
+    use reqwest::Method;
+
+    #[test]
+    fn http_request() {
+        let req: ApiRequest<()> =
+            ApiRequest::post(Route::new("/shows").segment("fairy tail"), "{}".to_owned())
+                .query("extended", "full")
+                .query("query", "a&b")
+                .auth("ACCESS_TOKEN");
+        let req = HttpRequest::new("https://api.trakt.tv", "CLIENT_ID", &req).unwrap();
+
+        assert_eq!(req.method, Method::POST);
+        assert_eq!(
+            req.url.as_str(),
+            "https://api.trakt.tv/shows/fairy%20tail?extended=full&query=a%26b"
+        );
+        assert_eq!(req.headers["Content-Type"], "application/json");
+        assert_eq!(req.headers["trakt-api-version"], "2");
+        assert_eq!(req.headers["trakt-api-key"], "CLIENT_ID");
+        assert_eq!(req.headers["Authorization"], "Bearer ACCESS_TOKEN");
+        assert_eq!(req.body, Some(b"{}".to_vec()));
+        assert!(!format!("{:?}", req).contains("ACCESS_TOKEN"));
+    }
+}